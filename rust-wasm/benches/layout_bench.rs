@@ -2,8 +2,8 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use webvowl_wasm::{
-    graph::{builder::GraphBuilder, NodeBuilder, NodeType, VowlGraph},
-    layout::{simulation::ForceSimulation, LayoutAlgorithm},
+    graph::builder::GraphBuilder,
+    layout::{simulation::ForceSimulation, LayoutAlgorithm, LayoutConfig},
     ontology::{
         ClassAttributes, ClassNode, OntologyData, OntologyMetadata, Property,
         PropertyCharacteristics, PropertyType,
@@ -16,7 +16,9 @@ fn create_test_ontology(num_classes: usize, num_properties: usize) -> OntologyDa
         classes.push(ClassNode {
             id: format!("class{}", i),
             iri: format!("http://test.org/Class{}", i),
+            curie: None,
             label: format!("Class {}", i),
+            labels: Default::default(),
             class_type: "owl:Class".to_string(),
             equivalent: vec![],
             attributes: ClassAttributes::default(),
@@ -31,11 +33,16 @@ fn create_test_ontology(num_classes: usize, num_properties: usize) -> OntologyDa
         properties.push(Property {
             id: format!("prop{}", i),
             iri: format!("http://test.org/prop{}", i),
+            curie: None,
             label: format!("Property {}", i),
+            labels: Default::default(),
             property_type: PropertyType::ObjectProperty,
             domain: format!("class{}", domain_idx),
             range: format!("class{}", range_idx),
             characteristics: PropertyCharacteristics::default(),
+            chain: vec![],
+            inverse_of: None,
+            sub_property_of: vec![],
         });
     }
 
@@ -49,6 +56,7 @@ fn create_test_ontology(num_classes: usize, num_properties: usize) -> OntologyDa
         classes,
         properties,
         namespaces: vec![],
+        skipped_entries: vec![],
     }
 }
 
@@ -78,7 +86,7 @@ fn bench_force_simulation(c: &mut Criterion) {
 
     for size in [10, 25, 50].iter() {
         let ontology = create_test_ontology(*size, *size - 5);
-        let mut graph = GraphBuilder::from_ontology(&ontology).unwrap();
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
 
         group.bench_with_input(
             BenchmarkId::from_parameter(size),
@@ -124,6 +132,39 @@ fn bench_single_tick(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_barnes_hut(c: &mut Criterion) {
+    let mut group = c.benchmark_group("barnes_hut_tick");
+
+    for size in [500, 1000].iter() {
+        let ontology = create_test_ontology(*size, *size - 5);
+        let mut graph = GraphBuilder::from_ontology(&ontology).unwrap();
+        ForceSimulation::new().initialize(&mut graph).unwrap();
+
+        // theta = 0 is the exact all-pairs baseline
+        for theta in [0.0, 0.9] {
+            let config = LayoutConfig {
+                theta,
+                ..Default::default()
+            };
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("theta_{}", theta), size),
+                size,
+                |b, _| {
+                    b.iter(|| {
+                        let mut test_graph = graph.clone();
+                        let mut sim = ForceSimulation::with_config(config.clone());
+                        sim.tick(black_box(&mut test_graph)).unwrap();
+                        black_box(test_graph);
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
 fn bench_graph_queries(c: &mut Criterion) {
     let mut group = c.benchmark_group("graph_queries");
 
@@ -162,6 +203,7 @@ criterion_group!(
     bench_graph_construction,
     bench_force_simulation,
     bench_single_tick,
+    bench_barnes_hut,
     bench_graph_queries
 );
 criterion_main!(benches);
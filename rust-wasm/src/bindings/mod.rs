@@ -1,18 +1,37 @@
 //! WASM bindings for JavaScript interop
 
 use crate::{
-    graph::{builder::GraphBuilder, VowlGraph},
-    layout::{simulation::ForceSimulation, LayoutAlgorithm},
-    ontology::{parser::StandardParser, OntologyParser},
+    graph::{
+        builder::GraphBuilder,
+        filter::{FilterOptions, DIM_OPACITY},
+        search::SearchOptions,
+        traversal::{default_edge_cost, Direction},
+        Node, VowlGraph,
+    },
+    layout::{
+        simulation::{ForceSimulation, LayoutSnapshot},
+        LayoutAlgorithm,
+    },
+    ontology::{parser::StandardParser, ClassNode, OntologyData, OntologyParser, Property},
+    render::{canvas::CanvasRenderer, theme::VowlTheme, Renderer, SvgRenderer},
+    VowlError,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
 /// Main WebVOWL WASM interface
 #[wasm_bindgen]
 pub struct WebVowl {
     graph: Option<VowlGraph>,
+    ontology: Option<OntologyData>,
+    validation_warnings: Vec<String>,
     simulation: ForceSimulation,
+    last_positions: HashMap<String, (f64, f64)>,
+    tick_callback: Option<js_sys::Function>,
+    filter: FilterOptions,
+    /// Full graph while a filter is active; `graph` then holds the view
+    unfiltered: Option<VowlGraph>,
 }
 
 #[wasm_bindgen]
@@ -22,7 +41,13 @@ impl WebVowl {
     pub fn new() -> Self {
         Self {
             graph: None,
+            ontology: None,
+            validation_warnings: Vec::new(),
             simulation: ForceSimulation::new(),
+            last_positions: HashMap::new(),
+            tick_callback: None,
+            filter: FilterOptions::default(),
+            unfiltered: None,
         }
     }
 
@@ -32,30 +57,219 @@ impl WebVowl {
         let parser = StandardParser::new();
         let ontology_data = parser
             .parse(json)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+            .map_err(JsValue::from)?;
 
-        parser
+        let report = parser
             .validate(&ontology_data)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+            .map_err(JsValue::from)?;
 
         let graph = GraphBuilder::from_ontology(&ontology_data)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+            .map_err(JsValue::from)?;
+
+        let mut warnings = report.warnings;
+        for cycle in graph.find_subclass_cycles() {
+            warnings.push(format!(
+                "Subclass cycle: {} -> {}",
+                cycle.join(" -> "),
+                cycle[0]
+            ));
+        }
 
         self.graph = Some(graph);
+        self.unfiltered = None;
+        self.ontology = Some(ontology_data);
+        self.validation_warnings = warnings;
+        self.last_positions.clear();
+        self.apply_filter();
+        Ok(())
+    }
+
+    /// Add a single class to the loaded graph
+    ///
+    /// Takes one class object in the same format as the `class` array of
+    /// `loadOntology`. Existing positions and simulation state are kept.
+    #[wasm_bindgen(js_name = addClass)]
+    pub fn add_class(&mut self, json: &str) -> std::result::Result<(), JsValue> {
+        let class = StandardParser::new()
+            .parse_class_fragment(json)
+            .map_err(JsValue::from)?;
+
+        self.unfilter();
+        let result = self.insert_class(class);
+        self.apply_filter();
+        result
+    }
+
+    /// Add a parsed class to the current graph
+    fn insert_class(&mut self, class: ClassNode) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+        let previous_count = graph.node_count();
+
+        graph
+            .add_node(GraphBuilder::class_node(&class))
+            .map_err(JsValue::from)?;
+
+        if let Some(ontology) = self.ontology.as_mut() {
+            ontology.classes.push(class);
+        }
+        self.place_new_nodes(previous_count);
+        Ok(())
+    }
+
+    /// Add a single property to the loaded graph
+    ///
+    /// Takes one property object in the same format as the `property` array
+    /// of `loadOntology`. Its domain and range classes must already exist.
+    #[wasm_bindgen(js_name = addProperty)]
+    pub fn add_property(&mut self, json: &str) -> std::result::Result<(), JsValue> {
+        let property = StandardParser::new()
+            .parse_property_fragment(json)
+            .map_err(JsValue::from)?;
+
+        self.unfilter();
+        let result = self.insert_property(property);
+        self.apply_filter();
+        result
+    }
+
+    /// Add a parsed property to the current graph
+    fn insert_property(&mut self, property: Property) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+        let previous_count = graph.node_count();
+
+        GraphBuilder::add_property(graph, &property)
+            .map_err(JsValue::from)?;
+
+        if let Some(ontology) = self.ontology.as_mut() {
+            ontology.properties.push(property);
+        }
+        self.place_new_nodes(previous_count);
         Ok(())
     }
 
+    /// Hide kinds of nodes and edges from rendering and simulation
+    ///
+    /// Takes `{hideDatatypes, hideSubclass, hideExternal, hideAnnotations,
+    /// hideSameAs}`; omitted flags are off, and `undefined` shows everything
+    /// again. The filter stays in effect for graphs loaded later. Hidden nodes
+    /// keep their last positions.
+    #[wasm_bindgen(js_name = setFilter)]
+    pub fn set_filter(&mut self, options: JsValue) -> std::result::Result<(), JsValue> {
+        let filter: FilterOptions = if options.is_undefined() || options.is_null() {
+            FilterOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options)
+                .map_err(binding_error)?
+        };
+
+        self.set_filter_options(filter);
+        Ok(())
+    }
+
+    /// Replace the active filter and rebuild the filtered view
+    fn set_filter_options(&mut self, filter: FilterOptions) {
+        self.unfilter();
+        self.filter = filter;
+        self.apply_filter();
+    }
+
+    /// Make the full graph active again, keeping the view's visual state
+    fn unfilter(&mut self) {
+        let Some(mut full) = self.unfiltered.take() else {
+            return;
+        };
+
+        if let Some(view) = self.graph.take() {
+            full.copy_view_state(&view);
+        }
+        self.graph = Some(full);
+    }
+
+    /// Replace the full graph by its filtered view if a filter is enabled
+    fn apply_filter(&mut self) {
+        if !self.filter.is_active() {
+            return;
+        }
+
+        if let Some(full) = self.graph.take() {
+            self.graph = Some(full.filtered_view(self.filter));
+            self.unfiltered = Some(full);
+        }
+    }
+
+    /// Spread nodes added after the first `previous_count` around the simulation center
+    fn place_new_nodes(&mut self, previous_count: usize) {
+        let Some(graph) = self.graph.as_mut() else {
+            return;
+        };
+
+        let golden_angle = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        let (cx, cy) = self.simulation.config().center;
+        let new_ids: Vec<String> = graph
+            .nodes()
+            .into_iter()
+            .skip(previous_count)
+            .map(|n| n.id.clone())
+            .collect();
+
+        for (i, id) in new_ids.iter().enumerate() {
+            let angle = (previous_count + i) as f64 * golden_angle;
+            let _ = graph.set_position(id, cx + 10.0 * angle.cos(), cy + 10.0 * angle.sin());
+        }
+    }
+
+    /// Get the non-fatal validation warnings of the last loaded ontology
+    #[wasm_bindgen(js_name = getValidationWarnings)]
+    pub fn get_validation_warnings(&self) -> Vec<String> {
+        self.validation_warnings.clone()
+    }
+
+    /// Get the IRI, title, version and description of the loaded ontology
+    ///
+    /// Returns `null` for graphs that carry no ontology header.
+    #[wasm_bindgen(js_name = getOntologyMetadata)]
+    pub fn get_ontology_metadata(&self) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        match graph.ontology_metadata() {
+            Some(metadata) => serde_wasm_bindgen::to_value(metadata)
+                .map_err(binding_error),
+            None => Ok(JsValue::NULL),
+        }
+    }
+
+    /// Get declared vs. used namespace prefixes of the loaded ontology as JSON
+    #[wasm_bindgen(js_name = getNamespaceUsage)]
+    pub fn get_namespace_usage(&self) -> std::result::Result<JsValue, JsValue> {
+        let ontology = self
+            .ontology
+            .as_ref()
+            .ok_or_else(|| binding_error("No ontology loaded"))?;
+
+        serde_wasm_bindgen::to_value(&ontology.namespace_usage())
+            .map_err(binding_error)
+    }
+
     /// Initialize the force simulation
     #[wasm_bindgen(js_name = initSimulation)]
     pub fn init_simulation(&mut self) -> std::result::Result<(), JsValue> {
         let graph = self
             .graph
             .as_mut()
-            .ok_or_else(|| JsValue::from_str("No graph loaded"))?;
+            .ok_or_else(no_graph)?;
 
         self.simulation
             .initialize(graph)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+            .map_err(JsValue::from)?;
 
         Ok(())
     }
@@ -63,33 +277,143 @@ impl WebVowl {
     /// Run simulation for n iterations
     #[wasm_bindgen(js_name = runSimulation)]
     pub fn run_simulation(&mut self, iterations: usize) -> std::result::Result<(), JsValue> {
-        let graph = self
-            .graph
-            .as_mut()
-            .ok_or_else(|| JsValue::from_str("No graph loaded"))?;
+        self.init_simulation()?;
 
-        self.simulation
-            .run(graph, iterations)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        for _ in 0..iterations {
+            if self.simulation.is_finished() {
+                break;
+            }
+            self.step()?;
+        }
 
         Ok(())
     }
 
+    /// Continue the simulation for n iterations and return the resulting
+    /// positions as a flat array
+    ///
+    /// Positions are `[x0, y0, x1, y1, ...]` in the same node order as
+    /// `getGraphData`.
+    #[wasm_bindgen(js_name = advance)]
+    pub fn advance(&mut self, iterations: usize) -> std::result::Result<Vec<f64>, JsValue> {
+        if self.graph.is_none() {
+            return Err(no_graph());
+        }
+
+        for _ in 0..iterations {
+            if self.simulation.is_finished() {
+                break;
+            }
+            self.step()?;
+        }
+
+        Ok(self.graph.as_ref().map(|g| g.position_array()).unwrap_or_default())
+    }
+
+    /// Run one animation frame and hand the positions to `on_frame`
+    ///
+    /// Performs up to `ticksPerFrame` ticks, then calls `on_frame` with the
+    /// flat position array. Returns `true` while the layout is still moving, so
+    /// JS can schedule the next frame with `requestAnimationFrame`.
+    #[wasm_bindgen(js_name = animateLayout)]
+    pub fn animate_layout(
+        &mut self,
+        on_frame: &js_sys::Function,
+        options: JsValue,
+    ) -> std::result::Result<bool, JsValue> {
+        let options: AnimationOptions = if options.is_undefined() || options.is_null() {
+            AnimationOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options)
+                .map_err(binding_error)?
+        };
+
+        if self.graph.is_none() {
+            return Err(no_graph());
+        }
+
+        let stopped =
+            |sim: &ForceSimulation| sim.is_finished() || sim.alpha() < options.stop_alpha;
+
+        for _ in 0..options.ticks_per_frame {
+            if stopped(&self.simulation) {
+                break;
+            }
+            self.step()?;
+        }
+
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+        let positions = js_sys::Float64Array::from(graph.position_array().as_slice());
+        on_frame.call1(&JsValue::NULL, &positions)?;
+
+        Ok(!stopped(&self.simulation))
+    }
+
     /// Perform one simulation tick
     #[wasm_bindgen(js_name = tick)]
     pub fn tick(&mut self) -> std::result::Result<(), JsValue> {
+        self.step()
+    }
+
+    /// Set a callback invoked after every simulation tick
+    ///
+    /// The callback receives the alpha after the tick and a flat
+    /// `[index, x, y, ...]` array of the nodes that moved, with indices in
+    /// `getGraphData` node order. Pass `undefined` to remove it.
+    #[wasm_bindgen(js_name = onTick)]
+    pub fn on_tick(&mut self, callback: Option<js_sys::Function>) {
+        self.tick_callback = callback;
+    }
+
+    /// Perform one simulation tick and report it to the tick callback
+    fn step(&mut self) -> std::result::Result<(), JsValue> {
         let graph = self
             .graph
             .as_mut()
-            .ok_or_else(|| JsValue::from_str("No graph loaded"))?;
+            .ok_or_else(no_graph)?;
+
+        // A finished simulation does not move, so there is nothing to report
+        if self.simulation.is_finished() {
+            return Ok(());
+        }
+
+        let before = self.tick_callback.as_ref().map(|_| graph.position_array());
 
         self.simulation
             .tick(graph)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+            .map_err(JsValue::from)?;
+
+        if let (Some(callback), Some(before)) = (&self.tick_callback, before) {
+            let moved = moved_positions(&before, &graph.position_array());
+            callback.call2(
+                &JsValue::NULL,
+                &JsValue::from_f64(self.simulation.alpha()),
+                &js_sys::Float64Array::from(moved.as_slice()),
+            )?;
+        }
 
         Ok(())
     }
 
+    /// Explain the forces currently acting on a node as JSON
+    #[wasm_bindgen(js_name = explainNode)]
+    pub fn explain_node(&self, id: &str) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let explanation = self
+            .simulation
+            .explain_node(graph, id)
+            .map_err(JsValue::from)?;
+
+        serde_wasm_bindgen::to_value(&explanation).map_err(binding_error)
+    }
+
     /// Check if simulation is finished
     #[wasm_bindgen(js_name = isFinished)]
     pub fn is_finished(&self) -> bool {
@@ -120,16 +444,591 @@ impl WebVowl {
         self.simulation.set_charge_strength(strength);
     }
 
-    /// Get graph data as JSON
-    #[wasm_bindgen(js_name = getGraphData)]
-    pub fn get_graph_data(&self) -> std::result::Result<JsValue, JsValue> {
+    /// Set the alpha the simulation decays toward, e.g. 0.3 while dragging
+    /// and 0 on release
+    #[wasm_bindgen(js_name = setAlphaTarget)]
+    pub fn set_alpha_target(&mut self, target: f64) {
+        self.simulation.set_alpha_target(target);
+    }
+
+    /// Restart the simulation at the given alpha without resetting positions
+    #[wasm_bindgen(js_name = reheat)]
+    pub fn reheat(&mut self, alpha: f64) {
+        self.simulation.reheat(alpha);
+    }
+
+    /// Keep nodes in place during layout until `unfreezeAll`
+    ///
+    /// Unlike `setNodeFixed` this does not change the nodes themselves.
+    #[wasm_bindgen(js_name = freezeNodes)]
+    pub fn freeze_nodes(&mut self, ids: Vec<String>) {
+        self.simulation.freeze_nodes(&ids);
+    }
+
+    /// Let all frozen nodes move again
+    #[wasm_bindgen(js_name = unfreezeAll)]
+    pub fn unfreeze_all(&mut self) {
+        self.simulation.unfreeze_all();
+    }
+
+    /// Save node positions, velocities and alpha as JSON, e.g. for undo
+    ///
+    /// Covers the full graph, so nodes hidden by the filter keep their place
+    /// when the snapshot is restored after changing the filter.
+    #[wasm_bindgen(js_name = snapshotLayout)]
+    pub fn snapshot_layout(&self) -> std::result::Result<String, JsValue> {
+        let view = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let snapshot = match &self.unfiltered {
+            Some(full) => {
+                let mut full = full.clone();
+                full.copy_view_state(view);
+                self.simulation.snapshot(&full)
+            }
+            None => self.simulation.snapshot(view),
+        };
+        serde_json::to_string(&snapshot).map_err(binding_error)
+    }
+
+    /// Restore a layout saved with `snapshotLayout`
+    #[wasm_bindgen(js_name = restoreLayout)]
+    pub fn restore_layout(&mut self, json: &str) -> std::result::Result<(), JsValue> {
+        let snapshot: LayoutSnapshot =
+            serde_json::from_str(json).map_err(|e| JsValue::from(VowlError::from(e)))?;
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        self.simulation.restore(graph, &snapshot);
+        if let Some(full) = self.unfiltered.as_mut() {
+            self.simulation.restore(full, &snapshot);
+        }
+        Ok(())
+    }
+
+    /// Translate the layout so a node sits at the simulation center
+    #[wasm_bindgen(js_name = centerOnNode)]
+    pub fn center_on_node(&mut self, id: &str) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .recenter_on(id)
+            .map_err(JsValue::from)?;
+
+        let (cx, cy) = self.simulation.config().center;
+        graph.translate(cx, cy);
+        Ok(())
+    }
+
+    /// Pin or release a node so the simulation leaves it in place
+    #[wasm_bindgen(js_name = setNodeFixed)]
+    pub fn set_node_fixed(&mut self, id: &str, fixed: bool) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .set_fixed(id, fixed)
+            .map_err(JsValue::from)
+    }
+
+    /// Hide the subclass descendants of a node
+    #[wasm_bindgen(js_name = collapseNode)]
+    pub fn collapse_node(&mut self, id: &str) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .collapse(id)
+            .map_err(JsValue::from)
+    }
+
+    /// Show the subclass descendants of a node again
+    #[wasm_bindgen(js_name = expandNode)]
+    pub fn expand_node(&mut self, id: &str) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .expand(id)
+            .map_err(JsValue::from)
+    }
+
+    /// Remove nodes without edges and return how many were removed
+    ///
+    /// With a filter active only the filtered view is pruned, so changing
+    /// the filter brings the nodes back.
+    #[wasm_bindgen(js_name = pruneIsolated)]
+    pub fn prune_isolated(&mut self) -> std::result::Result<usize, JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.prune_isolated())
+    }
+
+    /// Dim everything except a node, its neighbors and its edges
+    #[wasm_bindgen(js_name = setHighlight)]
+    pub fn set_highlight(&mut self, node_id: &str) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph.highlight(node_id, DIM_OPACITY).map_err(JsValue::from)
+    }
+
+    /// Restore full opacity after `setHighlight`
+    #[wasm_bindgen(js_name = clearHighlight)]
+    pub fn clear_highlight(&mut self) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph.clear_highlight();
+        Ok(())
+    }
+
+    /// Get the ID of the node drawn at a point in graph coordinates
+    ///
+    /// Each node is hit within its rendered radius; overlapping nodes
+    /// resolve to the closest center.
+    #[wasm_bindgen(js_name = nodeAtPoint)]
+    pub fn node_at_point(&self, x: f64, y: f64) -> std::result::Result<Option<String>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let theme = VowlTheme::default();
+        Ok(graph
+            .node_at_with(x, y, |node| theme.node_radius(node))
+            .map(str::to_string))
+    }
+
+    /// Move a node to the given position, e.g. while dragging
+    #[wasm_bindgen(js_name = setNodePosition)]
+    pub fn set_node_position(
+        &mut self,
+        id: &str,
+        x: f64,
+        y: f64,
+    ) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .set_position(id, x, y)
+            .map_err(JsValue::from)
+    }
+
+    /// Push apart nodes closer than `distance` without running the simulation
+    #[wasm_bindgen(js_name = enforceMinSeparation)]
+    pub fn enforce_min_separation(&mut self, distance: f64) -> std::result::Result<(), JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        graph.enforce_min_separation(distance, 10);
+        Ok(())
+    }
+
+    /// Get graph data as JSON
+    #[wasm_bindgen(js_name = getGraphData)]
+    pub fn get_graph_data(&self) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let data = GraphData::from_graph(graph);
+        serde_wasm_bindgen::to_value(&data).map_err(binding_error)
+    }
+
+    /// Get node positions as a flat `[x0, y0, x1, y1, ...]` array
+    ///
+    /// Nodes are in `getNodeOrder` order, which only changes when nodes are
+    /// added or removed. Cheaper than `getGraphData` for per-frame updates.
+    #[wasm_bindgen(js_name = getPositions)]
+    pub fn get_positions(&self) -> std::result::Result<Vec<f64>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.position_array())
+    }
+
+    /// Get the node IDs in the order used by `getPositions`
+    #[wasm_bindgen(js_name = getNodeOrder)]
+    pub fn get_node_order(&self) -> std::result::Result<Vec<String>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.nodes().into_iter().map(|n| n.id.clone()).collect())
+    }
+
+    /// Get the nodes within `depth` hops of a node, ignoring edge direction,
+    /// and the edges among them as graph data
+    #[wasm_bindgen(js_name = extractNeighborhood)]
+    pub fn extract_neighborhood(
+        &self,
+        id: &str,
+        depth: usize,
+    ) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let neighborhood = graph
+            .neighborhood(id, depth)
+            .map_err(JsValue::from)?;
+
+        let data = GraphData::from_graph(&neighborhood);
+        serde_wasm_bindgen::to_value(&data).map_err(binding_error)
+    }
+
+    /// Export the graph structure and positions as JSON
+    #[wasm_bindgen(js_name = exportGraph)]
+    pub fn export_graph(&self) -> std::result::Result<String, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        graph.to_json().map_err(JsValue::from)
+    }
+
+    /// Replace the current graph with one restored from `exportGraph` output
+    #[wasm_bindgen(js_name = importGraph)]
+    pub fn import_graph(&mut self, json: &str) -> std::result::Result<(), JsValue> {
+        let graph = VowlGraph::from_json(json).map_err(JsValue::from)?;
+
+        // The exported graph carries no ontology source data
+        self.graph = Some(graph);
+        self.unfiltered = None;
+        self.ontology = None;
+        self.validation_warnings.clear();
+        self.last_positions.clear();
+        self.apply_filter();
+        Ok(())
+    }
+
+    /// Serialize the graph structure and positions to compact bytes
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen(js_name = serializeBinary)]
+    pub fn serialize_binary(&self) -> std::result::Result<Vec<u8>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        graph.to_bytes().map_err(JsValue::from)
+    }
+
+    /// Replace the current graph with one restored from `serializeBinary` output
+    #[cfg(feature = "binary")]
+    #[wasm_bindgen(js_name = restoreBinary)]
+    pub fn restore_binary(&mut self, bytes: &[u8]) -> std::result::Result<(), JsValue> {
+        let graph = VowlGraph::from_bytes(bytes).map_err(JsValue::from)?;
+
+        // The encoded graph carries no ontology source data
+        self.graph = Some(graph);
+        self.unfiltered = None;
+        self.ontology = None;
+        self.validation_warnings.clear();
+        self.last_positions.clear();
+        self.apply_filter();
+        Ok(())
+    }
+
+    /// Get the scale fitting the graph into a viewport of the given size
+    ///
+    /// Uses the same padding as the SVG renderer.
+    #[wasm_bindgen(js_name = getFitScale)]
+    pub fn get_fit_scale(&self, width: f64, height: f64) -> std::result::Result<f64, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.fit_scale(width, height, 20.0))
+    }
+
+    /// Get canvas draw commands for the graph fitted into the given size
+    #[wasm_bindgen(js_name = renderCanvasCommands)]
+    pub fn render_canvas_commands(
+        &self,
+        width: f64,
+        height: f64,
+    ) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let commands = CanvasRenderer::new(width, height).draw_commands(graph);
+        serde_wasm_bindgen::to_value(&commands).map_err(binding_error)
+    }
+
+    /// Render the graph as an SVG document of the given size
+    #[wasm_bindgen(js_name = renderSvg)]
+    pub fn render_svg(&self, width: f64, height: f64) -> std::result::Result<String, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        SvgRenderer::new(width, height)
+            .render(graph)
+            .map_err(JsValue::from)
+    }
+
+    /// Render a minimap of the whole graph with the detail viewport outlined
+    ///
+    /// The viewport is given in graph coordinates.
+    #[wasm_bindgen(js_name = renderOverview)]
+    pub fn render_overview(
+        &self,
+        width: f64,
+        height: f64,
+        viewport_x: f64,
+        viewport_y: f64,
+        viewport_width: f64,
+        viewport_height: f64,
+    ) -> std::result::Result<String, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let renderer = SvgRenderer::new(width, height).with_padding(5.0);
+        Ok(renderer.render_overview(
+            graph,
+            (viewport_x, viewport_y, viewport_width, viewport_height),
+        ))
+    }
+
+    /// Get nodes changed since the previous call as JSON
+    ///
+    /// Only nodes that moved further than `threshold` are reported, along
+    /// with added and removed node IDs.
+    #[wasm_bindgen(js_name = getRenderDelta)]
+    pub fn get_render_delta(&mut self, threshold: f64) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let renderer = SvgRenderer::new(0.0, 0.0).with_delta_threshold(threshold);
+        let delta = renderer.render_delta(graph, &self.last_positions);
+
+        // Only advance the baseline for reported nodes so small drifts accumulate
+        let positions = graph.positions();
+        for id in delta.moved.iter().map(|d| &d.id).chain(&delta.added) {
+            self.last_positions.insert(id.clone(), positions[id]);
+        }
+        for id in &delta.removed {
+            self.last_positions.remove(id);
+        }
+
+        serde_wasm_bindgen::to_value(&delta).map_err(binding_error)
+    }
+
+    /// Export node positions as CSV
+    #[wasm_bindgen(js_name = exportPositionsCsv)]
+    pub fn export_positions_csv(&self) -> std::result::Result<String, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.to_position_csv())
+    }
+
+    /// List classes lacking a label in the given language
+    #[wasm_bindgen(js_name = findUntranslated)]
+    pub fn find_untranslated(&self, lang: &str) -> std::result::Result<Vec<String>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        Ok(graph.missing_label_language(lang))
+    }
+
+    /// Find the shortest hop path between two nodes, ignoring edge direction
+    ///
+    /// Returns `undefined` when the nodes are not connected.
+    #[wasm_bindgen(js_name = findPath)]
+    pub fn find_path(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> std::result::Result<Option<Vec<String>>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .shortest_path(from, to, Direction::Undirected)
+            .map_err(JsValue::from)
+    }
+
+    /// Find the cheapest path between two nodes, ignoring edge direction
+    ///
+    /// Subclass edges are cheap and annotation edges expensive. Returns
+    /// `undefined` when the nodes are not connected.
+    #[wasm_bindgen(js_name = findWeightedPath)]
+    pub fn find_weighted_path(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> std::result::Result<Option<Vec<String>>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        graph
+            .weighted_path(from, to, default_edge_cost)
+            .map_err(JsValue::from)
+    }
+
+    /// Compute a centrality measure and store it as each node's weight
+    ///
+    /// `kind` is `"degree"` or `"betweenness"`. Returns the values keyed by
+    /// node ID.
+    #[wasm_bindgen(js_name = computeCentrality)]
+    pub fn compute_centrality(&mut self, kind: &str) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_mut()
+            .ok_or_else(no_graph)?;
+
+        let centrality = match kind {
+            "degree" => graph.degree_centrality(),
+            "betweenness" => graph.betweenness_centrality(),
+            other => {
+                return Err(binding_error(format!(
+                    "Unknown centrality kind '{}'",
+                    other
+                )))
+            }
+        };
+
+        for (id, value) in &centrality {
+            if let Some(node) = graph.get_node_mut(id) {
+                node.visual.weight = *value;
+            }
+        }
+
+        centrality
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(binding_error)
+    }
+
+    /// Find nodes whose labels (and optionally IRIs) contain a query
+    ///
+    /// Takes `{matchIri, limit}` options; `undefined` searches labels only
+    /// without a limit. Returns matching node IDs.
+    #[wasm_bindgen(js_name = search)]
+    pub fn search(
+        &self,
+        query: &str,
+        options: JsValue,
+    ) -> std::result::Result<Vec<String>, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let opts: SearchOptions = if options.is_undefined() || options.is_null() {
+            SearchOptions::default()
+        } else {
+            serde_wasm_bindgen::from_value(options)
+                .map_err(binding_error)?
+        };
+
+        Ok(graph.search(query, opts))
+    }
+
+    /// Get the IDs of a node's neighbors along outgoing edges
+    #[wasm_bindgen(js_name = getNeighbors)]
+    pub fn get_neighbors(&self, id: &str) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let neighbors: Vec<&str> = graph
+            .neighbors(id)
+            .map_err(JsValue::from)?
+            .into_iter()
+            .map(|node| node.id.as_str())
+            .collect();
+
+        serde_wasm_bindgen::to_value(&neighbors).map_err(binding_error)
+    }
+
+    /// Get a single node's details
+    #[wasm_bindgen(js_name = getNode)]
+    pub fn get_node(&self, id: &str) -> std::result::Result<JsValue, JsValue> {
+        let graph = self
+            .graph
+            .as_ref()
+            .ok_or_else(no_graph)?;
+
+        let node = graph
+            .get_node(id)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+
+        serde_wasm_bindgen::to_value(&NodeDetails::from_node(node))
+            .map_err(binding_error)
+    }
+
+    /// Get neighbors of a node together with the connecting edges as JSON
+    #[wasm_bindgen(js_name = getNeighborsWithEdges)]
+    pub fn get_neighbors_with_edges(&self, id: &str) -> std::result::Result<JsValue, JsValue> {
         let graph = self
             .graph
             .as_ref()
-            .ok_or_else(|| JsValue::from_str("No graph loaded"))?;
+            .ok_or_else(no_graph)?;
 
-        let data = GraphData::from_graph(graph);
-        serde_wasm_bindgen::to_value(&data).map_err(|e| JsValue::from_str(&e.to_string()))
+        let neighbors: Vec<NeighborData> = graph
+            .neighbors_with_edges(id)
+            .map_err(JsValue::from)?
+            .into_iter()
+            .map(|(edge, node)| NeighborData {
+                node_id: node.id.clone(),
+                node_label: node.label.clone(),
+                edge_id: edge.id.clone(),
+                edge_label: edge.label.clone(),
+            })
+            .collect();
+
+        serde_wasm_bindgen::to_value(&neighbors).map_err(binding_error)
     }
 
     /// Get node count
@@ -150,7 +1049,7 @@ impl WebVowl {
         let graph = self
             .graph
             .as_ref()
-            .ok_or_else(|| JsValue::from_str("No graph loaded"))?;
+            .ok_or_else(no_graph)?;
 
         let stats = Statistics {
             node_count: graph.node_count(),
@@ -159,9 +1058,18 @@ impl WebVowl {
             property_count: graph.metadata().property_count,
             max_degree: graph.metadata().max_degree,
             density: graph.metadata().density,
+            component_count: graph.component_count(),
+            // Cached in the graph metadata after the first call
+            diameter: graph.diameter(),
         };
 
-        serde_wasm_bindgen::to_value(&stats).map_err(|e| JsValue::from_str(&e.to_string()))
+        serde_wasm_bindgen::to_value(&stats).map_err(binding_error)
+    }
+}
+
+impl Default for WebVowl {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -174,11 +1082,72 @@ struct GraphData {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct NodeData {
+    id: String,
+    label: String,
+    x: f64,
+    y: f64,
+    /// One of "class", "datatype", "thing" or "special"
+    node_type: String,
+    /// Name of a special node, e.g. "Nothing"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    special_name: Option<String>,
+    /// Hidden inside a collapsed subtree
+    hidden: bool,
+    /// Lowered below 1 when dimmed by a highlight
+    opacity: f64,
+}
+
+impl NodeData {
+    fn from_node(node: &Node) -> Self {
+        NodeData {
+            id: node.id.clone(),
+            label: node.label.clone(),
+            x: node.visual.x,
+            y: node.visual.y,
+            node_type: node.node_type.type_name().to_string(),
+            special_name: node.node_type.special_name().map(str::to_string),
+            hidden: node.visual.hidden,
+            opacity: node.visual.opacity,
+        }
+    }
+}
+
+/// Single node as returned by `getNode`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NodeDetails {
     id: String,
     label: String,
     x: f64,
     y: f64,
     node_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    special_name: Option<String>,
+    hidden: bool,
+    opacity: f64,
+    iri: String,
+    external: bool,
+    /// Individual count, if the ontology provides one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    individuals: Option<usize>,
+}
+
+impl NodeDetails {
+    fn from_node(node: &Node) -> Self {
+        let data = NodeData::from_node(node);
+        NodeDetails {
+            id: data.id,
+            label: data.label,
+            x: data.x,
+            y: data.y,
+            node_type: data.node_type,
+            special_name: data.special_name,
+            hidden: data.hidden,
+            opacity: data.opacity,
+            iri: node.semantic.iri.clone(),
+            external: node.semantic.external,
+            individuals: node.semantic.individuals,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -187,32 +1156,50 @@ struct EdgeData {
     label: String,
     source: String,
     target: String,
+    /// One of "objectProperty", "datatypeProperty", "subClass", "annotation",
+    /// "sameAs" or "special"
     edge_type: String,
+    /// Name of a special edge, e.g. "disjoint"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    special_name: Option<String>,
+    chain: Vec<String>,
+    /// ID of the inverse property
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inverse_of: Option<String>,
+    /// Drawn as a merged inverse pair
+    bidirectional: bool,
+    /// Lowered below 1 when dimmed by a highlight
+    opacity: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct NeighborData {
+    node_id: String,
+    node_label: String,
+    edge_id: String,
+    edge_label: String,
 }
 
 impl GraphData {
     fn from_graph(graph: &VowlGraph) -> Self {
-        let nodes = graph
-            .nodes()
-            .iter()
-            .map(|n| NodeData {
-                id: n.id.clone(),
-                label: n.label.clone(),
-                x: n.visual.x,
-                y: n.visual.y,
-                node_type: format!("{:?}", n.node_type),
-            })
-            .collect();
+        let nodes = graph.nodes().into_iter().map(NodeData::from_node).collect();
 
         let edges = graph
-            .edges()
-            .iter()
-            .map(|e| EdgeData {
-                id: e.id.clone(),
-                label: e.label.clone(),
-                source: String::new(), // Would need proper tracking
-                target: String::new(),
-                edge_type: format!("{:?}", e.edge_type),
+            .edge_endpoints()
+            .into_iter()
+            .map(|(source, e, target)| {
+                EdgeData {
+                    id: e.id.clone(),
+                    label: e.label.clone(),
+                    source: source.to_string(),
+                    target: target.to_string(),
+                    edge_type: e.edge_type.type_name().to_string(),
+                    special_name: e.edge_type.special_name().map(str::to_string),
+                    chain: e.chain.clone(),
+                    inverse_of: e.characteristics.inverse_of.clone(),
+                    bidirectional: e.characteristics.bidirectional,
+                    opacity: e.opacity,
+                }
             })
             .collect();
 
@@ -220,6 +1207,23 @@ impl GraphData {
     }
 }
 
+/// Options for `animateLayout`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct AnimationOptions {
+    ticks_per_frame: usize,
+    stop_alpha: f64,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        Self {
+            ticks_per_frame: 1,
+            stop_alpha: 0.0,
+        }
+    }
+}
+
 /// Statistics data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Statistics {
@@ -229,11 +1233,37 @@ struct Statistics {
     property_count: usize,
     max_degree: usize,
     density: f64,
+    component_count: usize,
+    diameter: Option<usize>,
+}
+
+/// Compare two flat position arrays and list the nodes that moved
+///
+/// Returns `[index, x, y, ...]` triples with the new positions.
+fn moved_positions(before: &[f64], after: &[f64]) -> Vec<f64> {
+    before
+        .chunks_exact(2)
+        .zip(after.chunks_exact(2))
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .flat_map(|(i, (_, new))| [i as f64, new[0], new[1]])
+        .collect()
+}
+
+/// `{ code, message }` error for a binding failure, e.g. a bad argument
+fn binding_error(error: impl std::fmt::Display) -> JsValue {
+    VowlError::BindingError(error.to_string()).into()
+}
+
+/// Error thrown by methods that need a loaded graph
+fn no_graph() -> JsValue {
+    binding_error("No graph loaded")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder, EdgeType, NodeType};
     use wasm_bindgen_test::*;
 
     #[wasm_bindgen_test]
@@ -264,4 +1294,512 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(webvowl.get_node_count(), 1);
     }
+
+    #[test]
+    fn test_graph_data_edge_endpoints() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "person", "label": "Person", "type": "owl:Class"},
+                {"id": "organization", "label": "Organization", "type": "owl:Class"}
+            ],
+            "property": [
+                {
+                    "id": "worksFor",
+                    "label": "works for",
+                    "type": "owl:ObjectProperty",
+                    "domain": "person",
+                    "range": "organization"
+                }
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        let data = GraphData::from_graph(webvowl.graph.as_ref().unwrap());
+
+        assert_eq!(data.edges.len(), 1);
+        assert_eq!(data.edges[0].id, "worksFor");
+        assert_eq!(data.edges[0].source, "person");
+        assert_eq!(data.edges[0].target, "organization");
+    }
+
+    #[test]
+    fn test_add_class_keeps_existing_positions() {
+        let mut webvowl = WebVowl::new();
+        let json = r#"
+        {
+            "class": [
+                {"id": "person", "label": "Person"},
+                {"id": "organization", "label": "Organization"}
+            ],
+            "property": [
+                {"id": "worksFor", "domain": "person", "range": "organization"}
+            ]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+        webvowl.run_simulation(20).unwrap();
+        let before = webvowl.graph.as_ref().unwrap().positions();
+
+        webvowl.add_class(r#"{"id": "pet", "label": "Pet"}"#).unwrap();
+        webvowl
+            .add_property(r#"{"id": "owns", "domain": "person", "range": "pet"}"#)
+            .unwrap();
+
+        let graph = webvowl.graph.as_ref().unwrap();
+        assert_eq!(webvowl.get_node_count(), 3);
+        assert_eq!(webvowl.get_edge_count(), 2);
+        assert_eq!(graph.metadata().class_count, 3);
+        for (id, position) in before {
+            assert_eq!(graph.positions()[&id], position);
+        }
+
+        let pet = &graph.get_node("pet").unwrap().visual;
+        assert!(pet.x != 0.0 || pet.y != 0.0);
+    }
+
+    #[test]
+    fn test_subclass_cycle_warning() {
+        let mut webvowl = WebVowl::new();
+        let json = r#"
+        {
+            "class": [
+                {"id": "a", "label": "A"},
+                {"id": "b", "label": "B"},
+                {"id": "c", "label": "C"}
+            ],
+            "property": [],
+            "subClassOf": [
+                {"from": "a", "to": "b"},
+                {"from": "b", "to": "c"},
+                {"from": "c", "to": "a"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+
+        let warnings = webvowl.get_validation_warnings();
+        assert!(warnings.contains(&"Subclass cycle: a -> b -> c -> a".to_string()));
+    }
+
+    #[test]
+    fn test_graph_data_type_discriminants() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("b")
+                    .node_type(NodeType::Special("Nothing".to_string()))
+                    .build(),
+            )
+            .unwrap();
+        graph
+            .add_edge(
+                "a",
+                "b",
+                EdgeBuilder::new("e").edge_type(EdgeType::ObjectProperty).build(),
+            )
+            .unwrap();
+
+        let json = serde_json::to_value(GraphData::from_graph(&graph)).unwrap();
+
+        assert_eq!(json["edges"][0]["edge_type"], "objectProperty");
+        assert!(json["edges"][0].get("special_name").is_none());
+        assert_eq!(json["nodes"][0]["node_type"], "class");
+        assert_eq!(json["nodes"][1]["node_type"], "special");
+        assert_eq!(json["nodes"][1]["special_name"], "Nothing");
+    }
+
+    #[test]
+    fn test_filter_hides_and_restores_datatypes() {
+        let mut webvowl = WebVowl::new();
+        let json = r#"
+        {
+            "class": [
+                {"id": "person", "label": "Person"},
+                {"id": "organization", "label": "Organization"}
+            ],
+            "property": [
+                {"id": "worksFor", "domain": "person", "range": "organization"},
+                {
+                    "id": "name",
+                    "type": "owl:DatatypeProperty",
+                    "domain": "person",
+                    "range": "xsd:string"
+                }
+            ]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+        assert_eq!((webvowl.get_node_count(), webvowl.get_edge_count()), (3, 2));
+
+        webvowl.set_filter_options(FilterOptions {
+            hide_datatypes: true,
+            ..Default::default()
+        });
+        assert_eq!((webvowl.get_node_count(), webvowl.get_edge_count()), (2, 1));
+
+        // Moves made while filtered survive removing the filter
+        webvowl.set_node_position("person", 12.0, 34.0).unwrap();
+        webvowl.add_class(r#"{"id": "city", "label": "City"}"#).unwrap();
+        assert_eq!(webvowl.get_node_count(), 3);
+
+        webvowl.set_filter_options(FilterOptions::default());
+        let graph = webvowl.graph.as_ref().unwrap();
+        assert_eq!((graph.node_count(), graph.edge_count()), (4, 2));
+        assert_eq!(graph.positions()["person"], (12.0, 34.0));
+    }
+
+    #[test]
+    fn test_moved_positions() {
+        let before = [0.0, 0.0, 1.0, 1.0, 2.0, 2.0];
+        let after = [0.0, 0.0, 1.5, 1.0, 2.0, 2.0];
+
+        assert_eq!(moved_positions(&before, &after), vec![1.0, 1.5, 1.0]);
+        assert!(moved_positions(&before, &before).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_ontology_metadata() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "header": {"iri": "http://example.org/pizza", "title": "Pizza", "version": "2.0"},
+            "class": [{"id": "pizza"}],
+            "property": []
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+
+        let metadata: crate::ontology::OntologyMetadata =
+            serde_wasm_bindgen::from_value(webvowl.get_ontology_metadata().unwrap()).unwrap();
+        assert_eq!(metadata.iri, "http://example.org/pizza");
+        assert_eq!(metadata.title.as_deref(), Some("Pizza"));
+        assert_eq!(metadata.version.as_deref(), Some("2.0"));
+        assert_eq!(metadata.description, None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_node_and_neighbors() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "iri": "ex:Class1", "individuals": 4},
+                {"id": "class2"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+
+        let neighbors: Vec<String> =
+            serde_wasm_bindgen::from_value(webvowl.get_neighbors("class1").unwrap()).unwrap();
+        assert_eq!(neighbors, vec!["class2"]);
+
+        let node: NodeDetails =
+            serde_wasm_bindgen::from_value(webvowl.get_node("class1").unwrap()).unwrap();
+        assert_eq!(node.id, "class1");
+        assert_eq!(node.node_type, "class");
+        assert_eq!(node.iri, "ex:Class1");
+        assert_eq!(node.individuals, Some(4));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_node_missing_is_error() {
+        let mut webvowl = WebVowl::new();
+        assert!(webvowl.get_node("class1").is_err());
+
+        webvowl
+            .load_ontology(r#"{"class": [{"id": "class1"}], "property": []}"#)
+            .unwrap();
+        assert!(webvowl.get_node("missing").is_err());
+        assert!(webvowl.get_neighbors("missing").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_on_tick_fires_per_tick() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+
+        // Mock tick callback recording every alpha it receives
+        let alphas = js_sys::Array::new();
+        let on_tick = js_sys::Function::new_with_args("alpha, moved", "this.push(alpha)")
+            .bind0(&alphas);
+        webvowl.on_tick(Some(on_tick));
+
+        webvowl.run_simulation(7).unwrap();
+        assert_eq!(alphas.length(), 7);
+        assert_eq!(alphas.get(6).as_f64(), Some(webvowl.get_alpha()));
+
+        webvowl.on_tick(None);
+        webvowl.tick().unwrap();
+        assert_eq!(alphas.length(), 7);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_advance_returns_positions() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"},
+                {"id": "class3", "type": "owl:Class"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+        let initial = webvowl.graph.as_ref().unwrap().position_array();
+
+        let positions = webvowl.advance(10).unwrap();
+        assert_eq!(positions.len(), 6);
+        assert_ne!(positions, initial);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_get_positions_matches_node_order() {
+        let mut webvowl = WebVowl::new();
+        let error = webvowl.get_positions().unwrap_err();
+        let code = js_sys::Reflect::get(&error, &"code".into()).unwrap();
+        assert_eq!(code.as_string().as_deref(), Some("binding_error"));
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"},
+                {"id": "class3", "type": "owl:Class"}
+            ],
+            "property": []
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        webvowl.set_node_position("class2", 40.0, -25.0).unwrap();
+
+        let order = webvowl.get_node_order().unwrap();
+        let positions = webvowl.get_positions().unwrap();
+        assert_eq!(positions.len(), 2 * webvowl.get_node_count());
+        assert_eq!(order.len(), webvowl.get_node_count());
+
+        let index = order.iter().position(|id| id == "class2").unwrap();
+        assert_eq!((positions[2 * index], positions[2 * index + 1]), (40.0, -25.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_animate_layout_until_converged() {
+        let mut webvowl = WebVowl::new();
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+
+        // Mock frame callback recording the length of every array it receives
+        let lengths = js_sys::Array::new();
+        let on_frame = js_sys::Function::new_with_args("positions", "this.push(positions.length)")
+            .bind0(&lengths);
+
+        let options = js_sys::Object::new();
+        js_sys::Reflect::set(&options, &"ticksPerFrame".into(), &JsValue::from(20)).unwrap();
+
+        let mut frames = 1;
+        while webvowl.animate_layout(&on_frame, options.clone().into()).unwrap() {
+            frames += 1;
+            assert!(frames < 1_000, "animation did not converge");
+        }
+
+        assert!(webvowl.is_finished());
+        assert_eq!(lengths.length(), frames);
+        assert!(lengths.iter().all(|len| len.as_f64() == Some(4.0)));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_snapshot_and_restore_layout() {
+        let mut webvowl = WebVowl::new();
+        assert!(webvowl.snapshot_layout().is_err());
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+        webvowl.run_simulation(10).unwrap();
+
+        let snapshot = webvowl.snapshot_layout().unwrap();
+        webvowl.run_simulation(10).unwrap();
+        webvowl.restore_layout(&snapshot).unwrap();
+        assert_eq!(webvowl.snapshot_layout().unwrap(), snapshot);
+        assert!(webvowl.restore_layout("not json").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_snapshot_covers_filtered_nodes() {
+        let mut webvowl = WebVowl::new();
+        let json = r#"
+        {
+            "class": [{"id": "person"}],
+            "property": [
+                {
+                    "id": "name",
+                    "type": "owl:DatatypeProperty",
+                    "domain": "person",
+                    "range": "xsd:string"
+                }
+            ]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+        webvowl.set_filter_options(FilterOptions {
+            hide_datatypes: true,
+            ..Default::default()
+        });
+        webvowl.set_node_position("person", 12.0, 34.0).unwrap();
+
+        let json = webvowl.snapshot_layout().unwrap();
+        let snapshot: LayoutSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.nodes.len(), 2);
+        let person = snapshot.nodes.iter().find(|n| n.id == "person").unwrap();
+        assert_eq!(person.position, (12.0, 34.0));
+
+        // Restoring while filtered also reaches the full graph
+        webvowl.set_node_position("person", 0.0, 0.0).unwrap();
+        webvowl.restore_layout(&json).unwrap();
+        webvowl.set_filter_options(FilterOptions::default());
+        assert_eq!(webvowl.snapshot_layout().unwrap(), json);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_render_svg() {
+        let mut webvowl = WebVowl::new();
+        assert!(webvowl.render_svg(800.0, 600.0).is_err());
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "class1", "type": "owl:Class"},
+                {"id": "class2", "type": "owl:Class"}
+            ],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"}
+            ]
+        }
+        "#;
+
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+        webvowl.run_simulation(50).unwrap();
+
+        let svg = webvowl.render_svg(800.0, 600.0).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<circle").count(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_freeze_nodes() {
+        let mut webvowl = WebVowl::new();
+        let json = r#"
+        {
+            "class": [{"id": "class1"}, {"id": "class2"}],
+            "property": [{"id": "prop1", "domain": "class1", "range": "class2"}]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+        webvowl.init_simulation().unwrap();
+
+        webvowl.freeze_nodes(vec!["class1".to_string()]);
+        let before = webvowl.graph.as_ref().unwrap().positions();
+        webvowl.run_simulation(10).unwrap();
+        let after = webvowl.graph.as_ref().unwrap().positions();
+        assert_eq!(after["class1"], before["class1"]);
+        assert_ne!(after["class2"], before["class2"]);
+
+        webvowl.unfreeze_all();
+        webvowl.run_simulation(10).unwrap();
+        let resumed = webvowl.graph.as_ref().unwrap().positions();
+        assert_ne!(resumed["class1"], after["class1"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_set_and_clear_highlight() {
+        let mut webvowl = WebVowl::new();
+        assert!(webvowl.set_highlight("class1").is_err());
+
+        let json = r#"
+        {
+            "class": [{"id": "class1"}, {"id": "class2"}, {"id": "class3"}],
+            "property": [
+                {"id": "prop1", "domain": "class1", "range": "class2"},
+                {"id": "prop2", "domain": "class2", "range": "class3"}
+            ]
+        }
+        "#;
+        webvowl.load_ontology(json).unwrap();
+        webvowl.set_highlight("class1").unwrap();
+
+        let data: GraphData =
+            serde_wasm_bindgen::from_value(webvowl.get_graph_data().unwrap()).unwrap();
+        for node in &data.nodes {
+            let expected = if node.id == "class3" { DIM_OPACITY } else { 1.0 };
+            assert_eq!(node.opacity, expected, "node {}", node.id);
+        }
+        for edge in &data.edges {
+            let expected = if edge.id == "prop1" { 1.0 } else { DIM_OPACITY };
+            assert_eq!(edge.opacity, expected, "edge {}", edge.id);
+        }
+
+        webvowl.clear_highlight().unwrap();
+        let data: GraphData =
+            serde_wasm_bindgen::from_value(webvowl.get_graph_data().unwrap()).unwrap();
+        assert!(data.nodes.iter().all(|n| n.opacity == 1.0));
+        assert!(data.edges.iter().all(|e| e.opacity == 1.0));
+    }
 }
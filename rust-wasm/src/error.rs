@@ -32,11 +32,40 @@ pub enum VowlError {
     /// WASM binding error
     #[error("Binding error: {0}")]
     BindingError(String),
+
+    /// Input larger than a configured limit
+    #[error("Limit {limit} exceeded: {value}")]
+    LimitExceeded {
+        /// Name of the limit, e.g. `max_classes`
+        limit: String,
+
+        /// Size of the input that exceeded it
+        value: usize,
+    },
+}
+
+impl VowlError {
+    /// Stable identifier of the error kind, for matching in JavaScript
+    pub fn code(&self) -> &'static str {
+        match self {
+            VowlError::ParseError(_) => "parse_error",
+            VowlError::InvalidData(_) => "invalid_data",
+            VowlError::GraphError(_) => "graph_error",
+            VowlError::LayoutError(_) => "layout_error",
+            VowlError::RenderError(_) => "render_error",
+            VowlError::BindingError(_) => "binding_error",
+            VowlError::LimitExceeded { .. } => "limit_exceeded",
+        }
+    }
 }
 
+/// Converts to a `{ code, message }` object
 impl From<VowlError> for JsValue {
     fn from(error: VowlError) -> Self {
-        JsValue::from_str(&error.to_string())
+        let object = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&object, &"code".into(), &error.code().into());
+        let _ = js_sys::Reflect::set(&object, &"message".into(), &error.to_string().into());
+        object.into()
     }
 }
 
@@ -1,14 +1,23 @@
 //! Graph builder for converting ontology data to graph structures
 
 use super::{
-    edge::EdgeBuilder, EdgeType, node::NodeBuilder, NodeType, VowlGraph,
+    edge::EdgeBuilder, Edge, EdgeType, node::NodeBuilder, palette, Node, NodeType, VowlGraph,
 };
-use crate::ontology::{OntologyData, PropertyType};
-use crate::Result;
+use crate::ontology::{
+    ClassNode, OntologyData, Property, PropertyType, DATATYPE_NAMESPACES, OWL_DISJOINT_WITH,
+    OWL_NOTHING_IRI, OWL_THING, OWL_THING_IRI, SAME_AS,
+};
+use crate::{Result, VowlError};
+use std::collections::{HashMap, HashSet};
 
 /// Builder for constructing VowlGraph from OntologyData
 pub struct GraphBuilder {
     graph: VowlGraph,
+    merge_equivalent: bool,
+    merge_inverses: bool,
+    degree_weights: bool,
+    individual_nodes: bool,
+    namespace_colors: bool,
 }
 
 impl GraphBuilder {
@@ -16,89 +25,496 @@ impl GraphBuilder {
     pub fn new() -> Self {
         Self {
             graph: VowlGraph::new(),
+            merge_equivalent: false,
+            merge_inverses: false,
+            degree_weights: false,
+            individual_nodes: false,
+            namespace_colors: false,
         }
     }
 
+    /// Merge `owl:equivalentClass` groups into a single node
+    ///
+    /// The first declared class of a group keeps its node and properties of
+    /// the other members are redirected to it. By default equivalent classes
+    /// stay separate and are connected by `equivalent` edges.
+    pub fn with_merge_equivalent(mut self, merge: bool) -> Self {
+        self.merge_equivalent = merge;
+        self
+    }
+
+    /// Draw `owl:inverseOf` pairs as a single double-headed edge
+    ///
+    /// The first declared property of a pair keeps its edge, labelled with
+    /// both names, and the other is not added. Pairs whose domain and range
+    /// are not swapped stay separate.
+    pub fn with_merge_inverses(mut self, merge: bool) -> Self {
+        self.merge_inverses = merge;
+        self
+    }
+
+    /// Size nodes by how many edges touch them
+    ///
+    /// Sets each node's `visual.weight` with
+    /// [`VowlGraph::recompute_weights`] once the graph is built.
+    pub fn with_degree_weights(mut self, enabled: bool) -> Self {
+        self.degree_weights = enabled;
+        self
+    }
+
+    /// Draw the named individuals of classes as nodes of their own
+    ///
+    /// Each individual becomes a `NodeType::Special("individual")` node with
+    /// an `instanceOf` edge to its class. By default only the individual
+    /// count is kept, in `semantic.individuals`.
+    pub fn with_individual_nodes(mut self, enabled: bool) -> Self {
+        self.individual_nodes = enabled;
+        self
+    }
+
+    /// Color classes by the namespace of their IRI
+    ///
+    /// Sets `visual.color` with [`palette::iri_color`], so classes from one
+    /// namespace share a color. Classes in no namespace keep the theme color.
+    pub fn with_namespace_colors(mut self, enabled: bool) -> Self {
+        self.namespace_colors = enabled;
+        self
+    }
+
     /// Build a graph from ontology data
     pub fn from_ontology(data: &OntologyData) -> Result<VowlGraph> {
-        let mut builder = Self::new();
+        Self::new().build_from(data)
+    }
+
+    /// Build a graph from ontology data using this builder's options
+    pub fn build_from(mut self, data: &OntologyData) -> Result<VowlGraph> {
+        let class_index: HashMap<&str, usize> = data
+            .classes
+            .iter()
+            .enumerate()
+            .map(|(index, class)| (class.id.as_str(), index))
+            .collect();
+        let merge = self.merge_equivalent;
+        let groups = Self::equivalence_groups(data, &class_index);
+        let representative = |index: usize| if merge { groups[index] } else { index };
 
         // Add all class nodes
-        for class in &data.classes {
-            let node = NodeBuilder::new(&class.id)
-                .label(&class.label)
-                .node_type(Self::map_node_type(&class.class_type))
-                .iri(&class.iri)
-                .external(class.attributes.external)
-                .build();
+        let mut thing_id = None;
+        for (index, class) in data.classes.iter().enumerate() {
+            if representative(index) != index {
+                continue;
+            }
 
-            builder.graph.add_node(node)?;
+            let mut node = Self::class_node(class);
+            if merge {
+                node.semantic.equivalent = (0..data.classes.len())
+                    .filter(|&other| other != index && groups[other] == index)
+                    .map(|other| data.classes[other].id.clone())
+                    .collect();
+            }
+            if self.namespace_colors {
+                node.visual.color = palette::iri_color(&class.iri, &data.namespaces);
+            }
+            if thing_id.is_none() && Self::is_thing(&node) {
+                thing_id = Some(class.id.clone());
+            }
+
+            self.graph.add_node(node)?;
+        }
+
+        // Unbounded properties point at owl:Thing; add it if no class provides it
+        let references_thing = data
+            .properties
+            .iter()
+            .any(|p| p.domain == OWL_THING || p.range == OWL_THING);
+        let thing_id = match thing_id {
+            Some(id) => id,
+            None if references_thing && self.graph.get_node(OWL_THING).is_none() => {
+                self.graph.add_node(Self::thing_node())?;
+                OWL_THING.to_string()
+            }
+            None => OWL_THING.to_string(),
+        };
+        let resolve = |id: &str| match class_index.get(id) {
+            _ if id == OWL_THING => thing_id.clone(),
+            Some(&index) => data.classes[representative(index)].id.clone(),
+            None => id.to_string(),
+        };
+
+        // Datatype property ranges (e.g. xsd:string) become one datatype node each
+        for property in &data.properties {
+            Self::add_datatype_range(&mut self.graph, property)?;
         }
 
         // Add all property edges
+        let partners = if self.merge_inverses {
+            Self::inverse_partners(data)
+        } else {
+            HashMap::new()
+        };
+        let mut added = HashSet::new();
         for property in &data.properties {
-            let edge = EdgeBuilder::new(&property.id)
-                .label(&property.label)
-                .edge_type(Self::map_edge_type(&property.property_type));
+            let mut edge = Self::property_edge(property);
+            if let Some(partner) = partners.get(property.id.as_str()) {
+                if added.contains(partner.id.as_str()) {
+                    continue;
+                }
+                edge.label = format!("{} / {}", property.label, partner.label);
+                edge.characteristics.inverse_of = Some(partner.id.clone());
+                edge.characteristics.bidirectional = true;
+            }
+            added.insert(property.id.as_str());
 
-            let edge = if property.characteristics.functional {
-                edge.functional()
-            } else {
-                edge
-            };
+            self.graph.add_edge(
+                &resolve(&property.domain),
+                &resolve(&Self::range_id(&self.graph, property)),
+                edge,
+            )?;
+        }
 
-            let edge = if property.characteristics.transitive {
-                edge.transitive()
-            } else {
-                edge
-            };
+        // Connect unmerged equivalent classes, once per pair
+        if !merge {
+            let mut connected = HashSet::new();
+            for class in &data.classes {
+                for other in &class.equivalent {
+                    let pair = if class.id < *other {
+                        (&class.id, other)
+                    } else {
+                        (other, &class.id)
+                    };
+                    if other == &class.id
+                        || !class_index.contains_key(other.as_str())
+                        || !connected.insert(pair)
+                    {
+                        continue;
+                    }
 
-            let edge = if property.characteristics.symmetric {
-                edge.symmetric()
-            } else {
-                edge
-            };
+                    let edge = EdgeBuilder::new(format!("{}-equivalent-{}", pair.0, pair.1))
+                        .label("equivalent")
+                        .edge_type(EdgeType::Special("equivalent".to_string()))
+                        .build();
+                    self.graph.add_edge(&class.id, other, edge)?;
+                }
+            }
+        }
 
-            let edge = if property.characteristics.inverse_functional {
-                edge.inverse_functional()
-            } else {
-                edge
-            };
+        if self.individual_nodes {
+            for (index, class) in data.classes.iter().enumerate() {
+                let class_id = &data.classes[representative(index)].id;
+                for instance in &class.attributes.instances {
+                    Self::add_individual(&mut self.graph, class_id, &instance.id, &instance.label)?;
+                }
+            }
+        }
+
+        if self.degree_weights {
+            self.graph.recompute_weights();
+        }
+
+        // Update metadata
+        self.graph.set_ontology_metadata(Some(data.metadata.clone()));
+        self.graph.update_metadata();
+
+        Ok(self.graph)
+    }
 
-            let edge = if let Some(card) = &property.characteristics.cardinality {
-                edge.cardinality(card.min, card.max)
+    /// Add a single property to an existing graph
+    ///
+    /// Endpoints are resolved as in [`from_ontology`](Self::from_ontology):
+    /// `owl:Thing` maps to the graph's Thing node, which is created if
+    /// missing, and datatype ranges get a datatype node. The domain and any
+    /// class range must already exist. Metadata is not updated.
+    pub fn add_property(graph: &mut VowlGraph, property: &Property) -> Result<()> {
+        // Check endpoints first so a failed insert leaves the graph untouched
+        let datatype_range = property.property_type == PropertyType::DatatypeProperty;
+        let missing = [(&property.domain, false), (&property.range, datatype_range)]
+            .into_iter()
+            .find(|(id, created)| *id != OWL_THING && !created && graph.get_node(id).is_none());
+        if let Some((id, _)) = missing {
+            return Err(VowlError::GraphError(format!("Node '{}' not found", id)));
+        }
+
+        let thing_id = graph
+            .nodes()
+            .into_iter()
+            .find(|n| Self::is_thing(n))
+            .map_or_else(|| OWL_THING.to_string(), |n| n.id.clone());
+        let references_thing = property.domain == OWL_THING || property.range == OWL_THING;
+        if references_thing && graph.get_node(&thing_id).is_none() {
+            graph.add_node(Self::thing_node())?;
+        }
+        let resolve = |id: &str| {
+            if id == OWL_THING {
+                thing_id.clone()
             } else {
-                edge
+                id.to_string()
+            }
+        };
+
+        Self::add_datatype_range(graph, property)?;
+        graph.add_edge(
+            &resolve(&property.domain),
+            &resolve(&Self::range_id(graph, property)),
+            Self::property_edge(property),
+        )
+    }
+
+    /// Build the graph node for a class
+    pub fn class_node(class: &ClassNode) -> Node {
+        let mut node = class
+            .labels
+            .iter()
+            .fold(NodeBuilder::new(&class.id), |node, (lang, label)| {
+                node.localized_label(lang, label)
+            })
+            .label(&class.label)
+            .node_type(Self::map_node_type(class))
+            .iri(&class.iri)
+            .external(class.attributes.external)
+            .deprecated(class.attributes.deprecated)
+            .equivalent(class.equivalent.clone())
+            .build();
+        node.semantic.individuals = class.attributes.individuals;
+        node
+    }
+
+    /// Group classes connected by `equivalent` declarations
+    ///
+    /// Returns, for each class index, the index of the first declared class
+    /// in its group. References to unknown classes are ignored.
+    fn equivalence_groups(data: &OntologyData, class_index: &HashMap<&str, usize>) -> Vec<usize> {
+        let mut parent: Vec<usize> = (0..data.classes.len()).collect();
+        fn find(parent: &[usize], mut index: usize) -> usize {
+            while parent[index] != index {
+                index = parent[index];
+            }
+            index
+        }
+
+        for (index, class) in data.classes.iter().enumerate() {
+            for other in class.equivalent.iter().filter_map(|id| class_index.get(id.as_str())) {
+                let (a, b) = (find(&parent, index), find(&parent, *other));
+                // The earlier declared root wins so the result is order-stable
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+
+        (0..parent.len()).map(|index| find(&parent, index)).collect()
+    }
+
+    /// Pair up properties declared as inverses of each other
+    ///
+    /// Either side may carry the `inverseOf` declaration. A pair only counts
+    /// when one property's domain is the other's range and vice versa.
+    fn inverse_partners(data: &OntologyData) -> HashMap<&str, &Property> {
+        let by_id: HashMap<&str, &Property> =
+            data.properties.iter().map(|p| (p.id.as_str(), p)).collect();
+
+        let mut partners = HashMap::new();
+        for property in &data.properties {
+            let Some(other) = property.inverse_of.as_deref().and_then(|id| by_id.get(id)) else {
+                continue;
             };
+            if other.id == property.id
+                || other.domain != property.range
+                || other.range != property.domain
+            {
+                continue;
+            }
+            partners.insert(property.id.as_str(), *other);
+            partners.insert(other.id.as_str(), property);
+        }
+        partners
+    }
+
+    /// Build the graph edge for a property, without resolving its endpoints
+    pub fn property_edge(property: &Property) -> Edge {
+        let edge = EdgeBuilder::new(&property.id)
+            .label(&property.label)
+            .edge_type(Self::map_edge_type(&property.property_type));
 
-            builder
-                .graph
-                .add_edge(&property.domain, &property.range, edge.build())?;
+        let edge = if property.characteristics.functional {
+            edge.functional()
+        } else {
+            edge
+        };
+
+        let edge = if property.characteristics.transitive {
+            edge.transitive()
+        } else {
+            edge
+        };
+
+        let edge = if property.characteristics.symmetric {
+            edge.symmetric()
+        } else {
+            edge
+        };
+
+        let edge = if property.characteristics.inverse_functional {
+            edge.inverse_functional()
+        } else {
+            edge
+        };
+
+        let edge = if let Some(card) = &property.characteristics.cardinality {
+            // An exact cardinality is both the minimum and the maximum
+            edge.cardinality(card.min.or(card.exact), card.max.or(card.exact))
+        } else {
+            edge
+        };
+
+        edge.inverse_of(property.inverse_of.clone())
+            .sub_property_of(property.sub_property_of.clone())
+            .chain(property.chain.clone())
+            .build()
+    }
+
+    /// Add an individual node, unless present, and its `instanceOf` edge
+    ///
+    /// An individual of several classes is shared; an ID already used by any
+    /// other kind of node is an error.
+    fn add_individual(graph: &mut VowlGraph, class_id: &str, id: &str, label: &str) -> Result<()> {
+        let individual = NodeType::Special("individual".to_string());
+        if let Some(existing) = graph.get_node(id) {
+            if existing.node_type != individual {
+                return Err(VowlError::GraphError(format!(
+                    "Individual '{}' collides with an existing node",
+                    id
+                )));
+            }
+        } else {
+            let node = NodeBuilder::new(id)
+                .label(label)
+                .node_type(individual)
+                .build();
+            graph.add_node(node)?;
         }
 
-        // Update metadata
-        builder.graph.update_metadata();
+        let edge = EdgeBuilder::new(format!("{}-instanceOf-{}", id, class_id))
+            .label("instanceOf")
+            .edge_type(EdgeType::Special("instanceOf".to_string()))
+            .build();
+        graph.add_edge(id, class_id, edge)
+    }
+
+    /// Add the datatype node for a datatype property's range if not present
+    fn add_datatype_range(graph: &mut VowlGraph, property: &Property) -> Result<()> {
+        let range = Self::range_id(graph, property);
+        if property.property_type != PropertyType::DatatypeProperty
+            || range == OWL_THING
+            || graph.get_node(&range).is_some()
+        {
+            return Ok(());
+        }
+
+        let node = NodeBuilder::new(&range)
+            .label(Self::datatype_label(&range))
+            .node_type(NodeType::Datatype)
+            .iri(Self::expanded_datatype(&property.range))
+            .build();
+        graph.add_node(node)?;
+        Ok(())
+    }
+
+    /// Node ID a property's range refers to
+    ///
+    /// Datatype ranges that are not existing nodes use the canonical short
+    /// form, so `xsd:string` and its full IRI share one datatype node.
+    fn range_id(graph: &VowlGraph, property: &Property) -> String {
+        let range = &property.range;
+        if property.property_type != PropertyType::DatatypeProperty
+            || range == OWL_THING
+            || graph.get_node(range).is_some()
+        {
+            return range.clone();
+        }
+        Self::canonical_datatype(range)
+    }
+
+    /// Shorten a datatype IRI in a well-known namespace to `prefix:local`
+    ///
+    /// Other IRIs and already prefixed names are returned unchanged.
+    fn canonical_datatype(datatype: &str) -> String {
+        DATATYPE_NAMESPACES
+            .iter()
+            .find_map(|(prefix, iri)| {
+                datatype
+                    .strip_prefix(iri)
+                    .map(|local| format!("{}:{}", prefix, local))
+            })
+            .unwrap_or_else(|| datatype.to_string())
+    }
+
+    /// Expand a datatype prefixed with a well-known namespace to its full IRI
+    ///
+    /// The inverse of [`canonical_datatype`](Self::canonical_datatype), so a
+    /// shared datatype node has the same IRI whichever form it was created from.
+    fn expanded_datatype(datatype: &str) -> String {
+        DATATYPE_NAMESPACES
+            .iter()
+            .find_map(|(prefix, iri)| {
+                datatype
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_prefix(':'))
+                    .map(|local| format!("{}{}", iri, local))
+            })
+            .unwrap_or_else(|| datatype.to_string())
+    }
+
+    /// Synthesized owl:Thing node for unbounded properties
+    fn thing_node() -> Node {
+        NodeBuilder::new(OWL_THING)
+            .label("Thing")
+            .node_type(NodeType::Special("Thing".to_string()))
+            .iri(OWL_THING_IRI)
+            .build()
+    }
 
-        Ok(builder.graph)
+    fn is_thing(node: &Node) -> bool {
+        matches!(&node.node_type, NodeType::Special(name) if name == "Thing")
     }
 
-    /// Map ontology class type to graph node type
-    fn map_node_type(class_type: &str) -> NodeType {
-        match class_type {
+    /// Map ontology class to graph node type
+    fn map_node_type(class: &ClassNode) -> NodeType {
+        if class.iri == OWL_THING_IRI {
+            return NodeType::Special("Thing".to_string());
+        }
+        if class.iri == OWL_NOTHING_IRI {
+            return NodeType::Special("Nothing".to_string());
+        }
+
+        match class.class_type.as_str() {
             "owl:Class" | "rdfs:Class" => NodeType::Class,
+            "owl:Thing" => NodeType::Special("Thing".to_string()),
+            "owl:Nothing" => NodeType::Special("Nothing".to_string()),
             "rdfs:Datatype" | "xsd:*" => NodeType::Datatype,
             other => NodeType::Special(other.to_string()),
         }
     }
 
+    /// Short display name of a datatype, e.g. `string` for `xsd:string`
+    ///
+    /// Falls back to the local name after the last `#`, `/` or `:`.
+    fn datatype_label(datatype: &str) -> &str {
+        datatype
+            .rsplit([':', '#', '/'])
+            .find(|part| !part.is_empty())
+            .unwrap_or(datatype)
+    }
+
     /// Map ontology property type to graph edge type
     fn map_edge_type(property_type: &PropertyType) -> EdgeType {
         match property_type {
             PropertyType::ObjectProperty => EdgeType::ObjectProperty,
             PropertyType::DatatypeProperty => EdgeType::DatatypeProperty,
-            PropertyType::AnnotationProperty => EdgeType::Special("annotation".to_string()),
+            PropertyType::AnnotationProperty => EdgeType::Annotation,
+            PropertyType::SpecialProperty(name) if name == OWL_DISJOINT_WITH => {
+                EdgeType::Special("disjoint".to_string())
+            }
+            PropertyType::SpecialProperty(name) if name == SAME_AS => EdgeType::SameAs,
             PropertyType::SpecialProperty(name) => {
-                if name.contains("subclass") {
+                if name.to_lowercase().contains("subclass") {
                     EdgeType::SubClass
                 } else {
                     EdgeType::Special(name.clone())
@@ -138,7 +554,9 @@ mod tests {
                 ClassNode {
                     id: "class1".to_string(),
                     iri: "http://test.org/Class1".to_string(),
+                    curie: None,
                     label: "Class 1".to_string(),
+                    labels: Default::default(),
                     class_type: "owl:Class".to_string(),
                     equivalent: vec![],
                     attributes: ClassAttributes::default(),
@@ -146,7 +564,9 @@ mod tests {
                 ClassNode {
                     id: "class2".to_string(),
                     iri: "http://test.org/Class2".to_string(),
+                    curie: None,
                     label: "Class 2".to_string(),
+                    labels: Default::default(),
                     class_type: "owl:Class".to_string(),
                     equivalent: vec![],
                     attributes: ClassAttributes::default(),
@@ -155,7 +575,9 @@ mod tests {
             properties: vec![Property {
                 id: "prop1".to_string(),
                 iri: "http://test.org/prop1".to_string(),
+                curie: None,
                 label: "Property 1".to_string(),
+                labels: Default::default(),
                 property_type: PropertyType::ObjectProperty,
                 domain: "class1".to_string(),
                 range: "class2".to_string(),
@@ -163,8 +585,12 @@ mod tests {
                     functional: true,
                     ..Default::default()
                 },
+                chain: vec![],
+                inverse_of: None,
+                sub_property_of: vec![],
             }],
             namespaces: vec![],
+            skipped_entries: vec![],
         }
     }
 
@@ -190,6 +616,20 @@ mod tests {
         assert_eq!(node.unwrap().label, "Class 1");
     }
 
+    #[test]
+    fn test_language_labels_propagate_to_nodes() {
+        let mut ontology = create_test_ontology();
+        ontology.classes[0]
+            .labels
+            .insert("fr".to_string(), "Classe 1".to_string());
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let node = graph.get_node("class1").unwrap();
+        assert_eq!(node.label, "Class 1");
+        assert_eq!(node.semantic.labels["fr"], "Classe 1");
+    }
+
     #[test]
     fn test_edge_characteristics() {
         let ontology = create_test_ontology();
@@ -200,6 +640,433 @@ mod tests {
         assert!(edges[0].characteristics.functional);
     }
 
+    #[test]
+    fn test_annotation_property_edge_type() {
+        let mut ontology = create_test_ontology();
+        ontology.properties[0].property_type = PropertyType::AnnotationProperty;
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.edges()[0].edge_type, EdgeType::Annotation);
+    }
+
+    #[test]
+    fn test_explicit_thing_and_nothing() {
+        let mut ontology = create_test_ontology();
+        ontology.classes[0].class_type = "owl:Thing".to_string();
+        ontology.classes[1].iri = OWL_NOTHING_IRI.to_string();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(
+            graph.get_node("class1").unwrap().node_type,
+            NodeType::Special("Thing".to_string())
+        );
+        assert_eq!(
+            graph.get_node("class2").unwrap().node_type,
+            NodeType::Special("Nothing".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unbounded_property_creates_thing() {
+        let mut ontology = create_test_ontology();
+        ontology.properties[0].range = OWL_THING.to_string();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let thing = graph.get_node(OWL_THING).unwrap();
+        assert_eq!(thing.node_type, NodeType::Special("Thing".to_string()));
+        assert_eq!(graph.node_count(), 3);
+
+        let (from, _, to) = graph.edge_endpoints()[0];
+        assert_eq!((from, to), ("class1", OWL_THING));
+    }
+
+    #[test]
+    fn test_unbounded_property_reuses_explicit_thing() {
+        let mut ontology = create_test_ontology();
+        ontology.classes[1].iri = OWL_THING_IRI.to_string();
+        ontology.properties[0].range = OWL_THING.to_string();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.node_count(), 2);
+        let (_, _, to) = graph.edge_endpoints()[0];
+        assert_eq!(to, "class2");
+    }
+
+    #[test]
+    fn test_datatype_property_creates_datatype_node() {
+        let mut ontology = create_test_ontology();
+        ontology.properties.push(Property {
+            id: "age".to_string(),
+            iri: "http://test.org/age".to_string(),
+            curie: None,
+            label: "age".to_string(),
+            labels: Default::default(),
+            property_type: PropertyType::DatatypeProperty,
+            domain: "class1".to_string(),
+            range: "xsd:integer".to_string(),
+            characteristics: PropertyCharacteristics::default(),
+            chain: vec![],
+            inverse_of: None,
+            sub_property_of: vec![],
+        });
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        let datatype = graph.get_node("xsd:integer").unwrap();
+        assert_eq!(datatype.node_type, NodeType::Datatype);
+        assert_eq!(datatype.label, "integer");
+
+        let (from, edge, to) = graph.edge_endpoints()[1];
+        assert_eq!((from, to), ("class1", "xsd:integer"));
+        assert_eq!(edge.edge_type, EdgeType::DatatypeProperty);
+    }
+
+    #[test]
+    fn test_datatype_labels_and_short_forms() {
+        let mut ontology = create_test_ontology();
+        let ranges = [
+            "http://www.w3.org/2001/XMLSchema#dateTime",
+            "xsd:dateTime",
+            "http://example.org/types/Currency",
+        ];
+        for (i, range) in ranges.iter().enumerate() {
+            ontology.properties.push(Property {
+                id: format!("data{}", i),
+                iri: format!("http://test.org/data{}", i),
+                curie: None,
+                label: String::new(),
+                labels: Default::default(),
+                property_type: PropertyType::DatatypeProperty,
+                domain: "class1".to_string(),
+                range: range.to_string(),
+                characteristics: PropertyCharacteristics::default(),
+                chain: vec![],
+                inverse_of: None,
+                sub_property_of: vec![],
+            });
+        }
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        // The full IRI and the prefixed name share one node
+        assert_eq!(graph.node_count(), 4);
+        let date_time = graph.get_node("xsd:dateTime").unwrap();
+        assert_eq!(date_time.label, "dateTime");
+        assert_eq!(date_time.semantic.iri, ranges[0]);
+        let targets: Vec<&str> = graph.edge_endpoints()[1..].iter().map(|e| e.2).collect();
+        assert_eq!(targets, vec!["xsd:dateTime", "xsd:dateTime", ranges[2]]);
+
+        // Unknown namespaces keep their IRI and fall back to the local name
+        assert_eq!(graph.get_node(ranges[2]).unwrap().label, "Currency");
+    }
+
+    #[test]
+    fn test_datatype_nodes_are_shared_per_range() {
+        let mut ontology = create_test_ontology();
+        for (id, domain) in [("name", "class1"), ("title", "class2")] {
+            ontology.properties.push(Property {
+                id: id.to_string(),
+                iri: format!("http://test.org/{}", id),
+                curie: None,
+                label: id.to_string(),
+                labels: Default::default(),
+                property_type: PropertyType::DatatypeProperty,
+                domain: domain.to_string(),
+                range: "xsd:string".to_string(),
+                characteristics: PropertyCharacteristics::default(),
+                chain: vec![],
+                inverse_of: None,
+                sub_property_of: vec![],
+            });
+        }
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+        // Created from the prefixed name, but the IRI is stored expanded
+        assert_eq!(
+            graph.get_node("xsd:string").unwrap().semantic.iri,
+            "http://www.w3.org/2001/XMLSchema#string"
+        );
+    }
+
+    #[test]
+    fn test_add_property_to_existing_graph() {
+        let ontology = create_test_ontology();
+        let mut graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let mut property = ontology.properties[0].clone();
+        property.id = "knows".to_string();
+        property.range = OWL_THING.to_string();
+        GraphBuilder::add_property(&mut graph, &property).unwrap();
+
+        assert_eq!(graph.edge_count(), 2);
+        assert!(graph.get_node(OWL_THING).is_some());
+
+        property.id = "dangling".to_string();
+        property.domain = "missing".to_string();
+        assert!(GraphBuilder::add_property(&mut graph, &property).is_err());
+        assert_eq!(graph.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_equivalent_classes_get_edge() {
+        let mut ontology = create_test_ontology();
+        ontology.classes[0].equivalent = vec!["class2".to_string()];
+        ontology.classes[1].equivalent = vec!["class1".to_string()];
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.node_count(), 2);
+        let equivalent: Vec<_> = graph
+            .edge_endpoints()
+            .into_iter()
+            .filter(|(_, e, _)| e.edge_type == EdgeType::Special("equivalent".to_string()))
+            .map(|(from, _, to)| (from, to))
+            .collect();
+        assert_eq!(equivalent, vec![("class1", "class2")]);
+        assert_eq!(graph.get_node("class1").unwrap().semantic.equivalent, vec!["class2"]);
+        assert_eq!(graph.get_node("class2").unwrap().semantic.equivalent, vec!["class1"]);
+    }
+
+    #[test]
+    fn test_merge_equivalent_classes() {
+        let mut ontology = create_test_ontology();
+        ontology.classes[1].equivalent = vec!["class1".to_string()];
+
+        let graph = GraphBuilder::new()
+            .with_merge_equivalent(true)
+            .build_from(&ontology)
+            .unwrap();
+
+        assert_eq!(graph.node_count(), 1);
+        assert!(graph.get_node("class2").is_none());
+        assert_eq!(graph.get_node("class1").unwrap().semantic.equivalent, vec!["class2"]);
+
+        // prop1 (class1 -> class2) now loops on the merged node
+        let (from, _, to) = graph.edge_endpoints()[0];
+        assert_eq!((from, to), ("class1", "class1"));
+    }
+
+    #[test]
+    fn test_inverse_of_propagates_to_edge() {
+        let mut ontology = create_test_ontology();
+        ontology.properties[0].inverse_of = Some("prop2".to_string());
+        ontology.properties[0].sub_property_of = vec!["related".to_string()];
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let edge = graph.edge_endpoints()[0].1;
+        assert_eq!(edge.characteristics.inverse_of.as_deref(), Some("prop2"));
+        assert_eq!(edge.characteristics.sub_property_of, vec!["related"]);
+        assert!(!edge.characteristics.bidirectional);
+    }
+
+    #[test]
+    fn test_merge_inverse_properties() {
+        let mut ontology = create_test_ontology();
+        let mut inverse = ontology.properties[0].clone();
+        inverse.id = "prop2".to_string();
+        inverse.label = "Property 2".to_string();
+        std::mem::swap(&mut inverse.domain, &mut inverse.range);
+        inverse.inverse_of = Some("prop1".to_string());
+        ontology.properties.push(inverse);
+
+        let separate = GraphBuilder::from_ontology(&ontology).unwrap();
+        assert_eq!(separate.edge_count(), 2);
+
+        let graph = GraphBuilder::new()
+            .with_merge_inverses(true)
+            .build_from(&ontology)
+            .unwrap();
+
+        assert_eq!(graph.edge_count(), 1);
+        let (from, edge, to) = graph.edge_endpoints()[0];
+        assert_eq!((from, to), ("class1", "class2"));
+        assert_eq!(edge.id, "prop1");
+        assert_eq!(edge.label, "Property 1 / Property 2");
+        assert_eq!(edge.characteristics.inverse_of.as_deref(), Some("prop2"));
+        assert!(edge.characteristics.bidirectional);
+    }
+
+    #[test]
+    fn test_degree_weights() {
+        let mut ontology = create_test_ontology();
+        let template = ontology.properties[0].clone();
+        ontology.properties = (0..5)
+            .map(|i| Property {
+                id: format!("ref{}", i),
+                domain: "class1".to_string(),
+                range: "class2".to_string(),
+                ..template.clone()
+            })
+            .collect();
+
+        let unweighted = GraphBuilder::from_ontology(&ontology).unwrap();
+        assert_eq!(unweighted.get_node("class2").unwrap().visual.weight, 0.0);
+
+        let mut leaf = ontology.classes[0].clone();
+        leaf.id = "leaf".to_string();
+        ontology.classes.push(leaf);
+        ontology.properties[0].domain = "leaf".to_string();
+
+        let graph = GraphBuilder::new()
+            .with_degree_weights(true)
+            .build_from(&ontology)
+            .unwrap();
+
+        let weight = |id: &str| graph.get_node(id).unwrap().visual.weight;
+        assert_eq!(weight("class2"), 1.0);
+        assert!(weight("class2") > weight("leaf"));
+        assert!(weight("leaf") > 0.0);
+    }
+
+    #[test]
+    fn test_subclass_disjoint_and_same_as_edge_types() {
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "class": [{"id": "animal"}, {"id": "dog"}, {"id": "cat"}],
+            "property": [],
+            "subClassOf": [{"from": "dog", "to": "animal"}],
+            "disjoint": [{"from": "dog", "to": "cat"}],
+            "sameAs": [{"from": "dog", "to": "cat"}]
+        }
+        "#;
+        let ontology = StandardParser::new().parse(json).unwrap();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let edges = graph.edge_endpoints();
+        assert_eq!((edges[0].0, edges[0].2), ("dog", "animal"));
+        assert_eq!(edges[0].1.edge_type, EdgeType::SubClass);
+        assert_eq!(edges[1].1.edge_type, EdgeType::Special("disjoint".to_string()));
+        assert_eq!(edges[2].1.edge_type, EdgeType::SameAs);
+    }
+
+    #[test]
+    fn test_union_operator_node_and_membership_edges() {
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "class": [{"id": "cat"}, {"id": "dog"}],
+            "property": [],
+            "setOperators": [{"id": "pet", "operator": "union", "members": ["cat", "dog"]}]
+        }
+        "#;
+        let ontology = StandardParser::new().parse(json).unwrap();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        let pet = graph.get_node("pet").unwrap();
+        assert_eq!(pet.node_type, NodeType::Special("union".to_string()));
+        let edges = graph.edge_endpoints();
+        assert_eq!(edges.len(), 2);
+        for ((from, edge, to), member) in edges.into_iter().zip(["cat", "dog"]) {
+            assert_eq!((from, to), ("pet", member));
+            assert_eq!(edge.edge_type, EdgeType::Special("union".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_namespace_colors() {
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "namespace": {"foaf": "http://xmlns.com/foaf/0.1/", "ex": "http://example.org/"},
+            "class": [
+                {"id": "person", "iri": "foaf:Person"},
+                {"id": "agent", "iri": "http://xmlns.com/foaf/0.1/Agent"},
+                {"id": "student", "iri": "ex:Student"}
+            ],
+            "property": []
+        }
+        "#;
+        let ontology = StandardParser::new().parse(json).unwrap();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+        assert!(graph.nodes().iter().all(|n| n.visual.color.is_none()));
+
+        let graph = GraphBuilder::new()
+            .with_namespace_colors(true)
+            .build_from(&ontology)
+            .unwrap();
+        let color = |id: &str| graph.get_node(id).unwrap().visual.color.clone().unwrap();
+        assert_eq!(color("person"), color("agent"));
+        assert_ne!(color("person"), color("student"));
+    }
+
+    #[test]
+    fn test_individual_nodes() {
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "pizza", "individuals": [
+                    {"id": "margherita", "label": "Margherita"},
+                    {"id": "marinara"}
+                ]},
+                {"id": "topping"}
+            ],
+            "property": []
+        }
+        "#;
+        let ontology = StandardParser::new().parse(json).unwrap();
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.get_node("pizza").unwrap().semantic.individuals, Some(2));
+
+        let graph = GraphBuilder::new()
+            .with_individual_nodes(true)
+            .build_from(&ontology)
+            .unwrap();
+        assert_eq!(graph.node_count(), 4);
+        let margherita = graph.get_node("margherita").unwrap();
+        assert_eq!(margherita.label, "Margherita");
+        assert_eq!(margherita.node_type, NodeType::Special("individual".to_string()));
+        assert_eq!(graph.get_node("marinara").unwrap().label, "marinara");
+
+        let edges = graph.edge_endpoints();
+        assert_eq!(edges.len(), 2);
+        for ((from, edge, to), individual) in edges.into_iter().zip(["margherita", "marinara"]) {
+            assert_eq!((from, to), (individual, "pizza"));
+            assert_eq!(edge.edge_type, EdgeType::Special("instanceOf".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_individual_colliding_with_class_is_rejected() {
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "pizza", "individuals": [{"id": "topping"}]},
+                {"id": "topping"}
+            ],
+            "property": []
+        }
+        "#;
+        let ontology = StandardParser::new().parse(json).unwrap();
+
+        let result = GraphBuilder::new()
+            .with_individual_nodes(true)
+            .build_from(&ontology);
+        assert!(matches!(result, Err(VowlError::GraphError(_))));
+    }
+
     #[test]
     fn test_metadata_update() {
         let ontology = create_test_ontology();
@@ -208,4 +1075,17 @@ mod tests {
         assert_eq!(graph.metadata().class_count, 2);
         assert_eq!(graph.metadata().property_count, 1);
     }
+
+    #[test]
+    fn test_ontology_metadata_is_kept() {
+        let mut ontology = create_test_ontology();
+        ontology.metadata.title = Some("Test".to_string());
+        ontology.metadata.version = Some("1.2".to_string());
+
+        let graph = GraphBuilder::from_ontology(&ontology).unwrap();
+
+        assert_eq!(graph.ontology_metadata(), Some(&ontology.metadata));
+        let view = graph.filtered_view(Default::default());
+        assert_eq!(view.ontology_metadata(), Some(&ontology.metadata));
+    }
 }
@@ -0,0 +1,191 @@
+//! Node importance measures
+
+use super::VowlGraph;
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
+use std::collections::{HashMap, VecDeque};
+
+impl VowlGraph {
+    /// Get the degree centrality of every node
+    ///
+    /// Counts incoming and outgoing edges, normalized by `n - 1` so a node
+    /// linked once to every other node scores `1.0`.
+    pub fn degree_centrality(&self) -> HashMap<String, f64> {
+        let scale = (self.node_count().max(2) - 1) as f64;
+
+        self.graph
+            .node_indices()
+            .map(|idx| {
+                let degree = self.graph.neighbors_undirected(idx).count();
+                (self.graph[idx].id.clone(), degree as f64 / scale)
+            })
+            .collect()
+    }
+
+    /// Set every node's `visual.weight` from its degree
+    ///
+    /// The weight is the node's incoming plus outgoing edge count divided by
+    /// the largest degree in the graph, so it lies in `[0, 1]` like the other
+    /// centrality measures. Without edges every weight is `0.0`.
+    pub fn recompute_weights(&mut self) {
+        let degrees: Vec<(NodeIndex, usize)> = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                let outgoing = self.graph.edges_directed(idx, Direction::Outgoing).count();
+                let incoming = self.graph.edges_directed(idx, Direction::Incoming).count();
+                (idx, outgoing + incoming)
+            })
+            .collect();
+        let max = degrees.iter().map(|&(_, degree)| degree).max().unwrap_or(0).max(1) as f64;
+
+        for (idx, degree) in degrees {
+            self.graph[idx].visual.weight = degree as f64 / max;
+        }
+    }
+
+    /// Get the betweenness centrality of every node
+    ///
+    /// Uses Brandes' algorithm over the undirected view, normalized by the
+    /// number of node pairs not involving the node, so values lie in `[0, 1]`.
+    pub fn betweenness_centrality(&self) -> HashMap<String, f64> {
+        let indices: Vec<NodeIndex> = self.graph.node_indices().collect();
+        let position: HashMap<NodeIndex, usize> =
+            indices.iter().enumerate().map(|(i, &idx)| (idx, i)).collect();
+
+        // Deduplicated neighbor lists so parallel edges do not count twice
+        let adjacency: Vec<Vec<usize>> = indices
+            .iter()
+            .map(|&idx| {
+                let mut neighbors: Vec<usize> = self
+                    .graph
+                    .neighbors_undirected(idx)
+                    .filter(|&n| n != idx)
+                    .map(|n| position[&n])
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect();
+
+        let n = indices.len();
+        let mut betweenness = vec![0.0; n];
+        for source in 0..n {
+            let mut stack = Vec::with_capacity(n);
+            let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut paths = vec![0.0; n];
+            let mut distance: Vec<Option<usize>> = vec![None; n];
+            paths[source] = 1.0;
+            distance[source] = Some(0);
+
+            let mut queue = VecDeque::from([source]);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                let next = distance[v].map(|d| d + 1);
+                for &w in &adjacency[v] {
+                    if distance[w].is_none() {
+                        distance[w] = next;
+                        queue.push_back(w);
+                    }
+                    if distance[w] == next {
+                        paths[w] += paths[v];
+                        predecessors[w].push(v);
+                    }
+                }
+            }
+
+            let mut dependency = vec![0.0; n];
+            while let Some(w) = stack.pop() {
+                for &v in &predecessors[w] {
+                    dependency[v] += paths[v] / paths[w] * (1.0 + dependency[w]);
+                }
+                if w != source {
+                    betweenness[w] += dependency[w];
+                }
+            }
+        }
+
+        // Each undirected pair was counted from both ends
+        let pairs = if n > 2 { ((n - 1) * (n - 2)) as f64 } else { 1.0 };
+        indices
+            .iter()
+            .zip(betweenness)
+            .map(|(&idx, value)| (self.graph[idx].id.clone(), value / pairs))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    /// Star with "hub" linked to four leaves
+    fn create_star() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("hub").build()).unwrap();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+            let edge = EdgeBuilder::new(format!("hub-{}", id)).build();
+            graph.add_edge("hub", id, edge).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_star_hub_has_highest_degree_centrality() {
+        let centrality = create_star().degree_centrality();
+
+        assert_eq!(centrality["hub"], 1.0);
+        assert_eq!(centrality["a"], 0.25);
+        let max = centrality.values().cloned().fold(f64::MIN, f64::max);
+        assert_eq!(max, centrality["hub"]);
+    }
+
+    #[test]
+    fn test_recompute_weights() {
+        let mut graph = create_star();
+        graph.recompute_weights();
+
+        assert_eq!(graph.get_node("hub").unwrap().visual.weight, 1.0);
+        assert_eq!(graph.get_node("a").unwrap().visual.weight, 0.25);
+
+        let mut empty = VowlGraph::new();
+        empty.add_node(NodeBuilder::new("lonely").build()).unwrap();
+        empty.recompute_weights();
+        assert_eq!(empty.get_node("lonely").unwrap().visual.weight, 0.0);
+    }
+
+    #[test]
+    fn test_star_betweenness() {
+        let centrality = create_star().betweenness_centrality();
+
+        // Every leaf-to-leaf path runs through the hub
+        assert!((centrality["hub"] - 1.0).abs() < 1e-9);
+        assert_eq!(centrality["a"], 0.0);
+    }
+
+    #[test]
+    fn test_path_betweenness() {
+        let mut graph = VowlGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.add_edge("c", "b", EdgeBuilder::new("cb").build()).unwrap();
+        // A parallel edge must not change the result
+        graph.add_edge("b", "a", EdgeBuilder::new("ba").build()).unwrap();
+
+        let centrality = graph.betweenness_centrality();
+        assert!((centrality["b"] - 1.0).abs() < 1e-9);
+        assert_eq!(centrality["a"], 0.0);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = VowlGraph::new();
+        assert!(graph.degree_centrality().is_empty());
+        assert!(graph.betweenness_centrality().is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+//! Collapsing subclass hierarchies
+
+use super::{EdgeType, VowlGraph};
+use crate::{Result, VowlError};
+use petgraph::visit::EdgeRef;
+use std::collections::HashSet;
+
+impl VowlGraph {
+    /// Hide the subclass descendants of a node
+    ///
+    /// The node itself stays visible and acts as the handle for the hidden
+    /// subtree.
+    pub fn collapse(&mut self, id: &str) -> Result<()> {
+        self.set_descendants_hidden(id, true)
+    }
+
+    /// Show the subclass descendants of a node again
+    ///
+    /// This reveals the whole subtree, including parts collapsed separately
+    /// below `id`.
+    pub fn expand(&mut self, id: &str) -> Result<()> {
+        self.set_descendants_hidden(id, false)
+    }
+
+    /// Get the IDs of all direct and indirect subclasses of a node
+    pub fn subclass_descendants(&self, id: &str) -> Result<Vec<String>> {
+        let root = *self
+            .node_map
+            .get(id)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+
+        // Subclass edges point from the subclass to its superclass
+        let mut seen = HashSet::from([root]);
+        let mut stack = vec![root];
+        let mut descendants = Vec::new();
+        while let Some(idx) = stack.pop() {
+            for edge in self.graph.edges_directed(idx, petgraph::Direction::Incoming) {
+                if edge.weight().edge_type == EdgeType::SubClass && seen.insert(edge.source()) {
+                    descendants.push(self.graph[edge.source()].id.clone());
+                    stack.push(edge.source());
+                }
+            }
+        }
+
+        Ok(descendants)
+    }
+
+    /// Check if any node is hidden
+    pub fn has_hidden(&self) -> bool {
+        self.graph.node_weights().any(|n| n.visual.hidden)
+    }
+
+    /// Get a copy of the graph without hidden nodes and their edges
+    pub fn without_hidden(&self) -> VowlGraph {
+        let mut view = self.retain_view(|node| !node.visual.hidden, |_| true);
+        view.update_metadata();
+        view
+    }
+
+    fn set_descendants_hidden(&mut self, id: &str, hidden: bool) -> Result<()> {
+        for descendant in self.subclass_descendants(id)? {
+            if let Some(node) = self.get_node_mut(&descendant) {
+                node.visual.hidden = hidden;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    /// animal <- mammal <- {dog, cat}, animal <- bird, plus an unrelated plant
+    fn create_hierarchy() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for id in ["animal", "mammal", "dog", "cat", "bird", "plant"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        let subclasses = [
+            ("mammal", "animal"),
+            ("dog", "mammal"),
+            ("cat", "mammal"),
+            ("bird", "animal"),
+        ];
+        for (child, parent) in subclasses {
+            let edge = EdgeBuilder::new(format!("{}-{}", child, parent))
+                .edge_type(EdgeType::SubClass)
+                .build();
+            graph.add_edge(child, parent, edge).unwrap();
+        }
+        // Non-subclass edges do not make a node a descendant
+        graph
+            .add_edge("plant", "animal", EdgeBuilder::new("feeds").build())
+            .unwrap();
+        graph
+    }
+
+    fn hidden_ids(graph: &VowlGraph) -> Vec<&str> {
+        let mut ids: Vec<&str> = graph
+            .nodes()
+            .into_iter()
+            .filter(|n| n.visual.hidden)
+            .map(|n| n.id.as_str())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_collapse_hides_transitive_subclasses() {
+        let mut graph = create_hierarchy();
+
+        graph.collapse("mammal").unwrap();
+        assert_eq!(hidden_ids(&graph), vec!["cat", "dog"]);
+
+        graph.collapse("animal").unwrap();
+        assert_eq!(hidden_ids(&graph), vec!["bird", "cat", "dog", "mammal"]);
+        assert!(graph.has_hidden());
+    }
+
+    #[test]
+    fn test_expand_restores_subtree() {
+        let mut graph = create_hierarchy();
+
+        graph.collapse("animal").unwrap();
+        graph.expand("animal").unwrap();
+
+        assert!(hidden_ids(&graph).is_empty());
+        assert!(!graph.has_hidden());
+    }
+
+    #[test]
+    fn test_without_hidden_drops_incident_edges() {
+        let mut graph = create_hierarchy();
+        graph.collapse("mammal").unwrap();
+
+        let view = graph.without_hidden();
+        assert_eq!(view.node_count(), 4);
+        assert_eq!(view.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_collapse_missing_node() {
+        assert!(create_hierarchy().collapse("missing").is_err());
+    }
+}
@@ -8,6 +8,7 @@ pub struct EdgeBuilder {
     label: Option<String>,
     edge_type: EdgeType,
     characteristics: EdgeCharacteristics,
+    chain: Vec<String>,
 }
 
 impl EdgeBuilder {
@@ -19,6 +20,7 @@ impl EdgeBuilder {
             label: Some(id),
             edge_type: EdgeType::ObjectProperty,
             characteristics: EdgeCharacteristics::default(),
+            chain: Vec::new(),
         }
     }
 
@@ -64,6 +66,30 @@ impl EdgeBuilder {
         self
     }
 
+    /// Set the inverse property
+    pub fn inverse_of(mut self, inverse: Option<String>) -> Self {
+        self.characteristics.inverse_of = inverse;
+        self
+    }
+
+    /// Set the super-properties
+    pub fn sub_property_of(mut self, parents: Vec<String>) -> Self {
+        self.characteristics.sub_property_of = parents;
+        self
+    }
+
+    /// Mark as standing for both directions of an inverse pair
+    pub fn bidirectional(mut self) -> Self {
+        self.characteristics.bidirectional = true;
+        self
+    }
+
+    /// Set the property chain
+    pub fn chain(mut self, chain: Vec<String>) -> Self {
+        self.chain = chain;
+        self
+    }
+
     /// Build the edge
     pub fn build(self) -> Edge {
         Edge {
@@ -71,6 +97,8 @@ impl EdgeBuilder {
             label: self.label.unwrap_or_default(),
             edge_type: self.edge_type,
             characteristics: self.characteristics,
+            chain: self.chain,
+            opacity: 1.0,
         }
     }
 }
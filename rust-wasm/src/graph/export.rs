@@ -0,0 +1,92 @@
+//! Text export formats for graphs
+
+use super::VowlGraph;
+
+/// Quote a CSV field if it contains separators, quotes or line breaks
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl VowlGraph {
+    /// Export node positions as CSV
+    ///
+    /// Emits a header row followed by one `id,label,x,y,type,degree` row
+    /// per node. The type is [`NodeType::type_name`](super::NodeType::type_name),
+    /// followed by the special name for special nodes, e.g. `special:Nothing`,
+    /// so the column carries what the JSON graph data does.
+    pub fn to_position_csv(&self) -> String {
+        let mut csv = String::from("id,label,x,y,type,degree\n");
+
+        for node in self.graph.node_weights() {
+            let degree = self.degree(&node.id).unwrap_or(0);
+            let node_type = match node.node_type.special_name() {
+                Some(name) => format!("{}:{}", node.node_type.type_name(), name),
+                None => node.node_type.type_name().to_string(),
+            };
+
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                csv_field(&node.id),
+                csv_field(&node.label),
+                node.visual.x,
+                node.visual.y,
+                csv_field(&node_type),
+                degree
+            ));
+        }
+
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder, NodeType};
+
+    #[test]
+    fn test_csv_quotes_labels() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(
+                NodeBuilder::new("place")
+                    .label("Place, Location")
+                    .position(1.5, 2.0)
+                    .build(),
+            )
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("quote").label("The \"Thing\"").build())
+            .unwrap();
+        graph
+            .add_edge("place", "quote", EdgeBuilder::new("e").build())
+            .unwrap();
+
+        let csv = graph.to_position_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "id,label,x,y,type,degree");
+        assert_eq!(lines[1], "place,\"Place, Location\",1.5,2,class,1");
+        assert_eq!(lines[2], "quote,\"The \"\"Thing\"\"\",0,0,class,1");
+    }
+
+    #[test]
+    fn test_csv_type_names() {
+        let mut graph = VowlGraph::new();
+        for (id, name) in [("thing", "Thing"), ("nothing", "Nothing"), ("ind", "individual")] {
+            let node_type = NodeType::Special(name.to_string());
+            graph.add_node(NodeBuilder::new(id).node_type(node_type).build()).unwrap();
+        }
+
+        let csv = graph.to_position_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[1], "thing,thing,0,0,thing,0");
+        assert_eq!(lines[2], "nothing,nothing,0,0,special:Nothing,0");
+        assert_eq!(lines[3], "ind,ind,0,0,special:individual,0");
+    }
+}
@@ -0,0 +1,356 @@
+//! Visibility filters for VOWL display toggles
+
+use super::{Edge, EdgeType, Node, NodeType, VowlGraph};
+use crate::Result;
+use petgraph::visit::EdgeRef;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Opacity of nodes and edges outside a highlight
+pub const DIM_OPACITY: f64 = 0.2;
+
+/// Which kinds of nodes and edges to hide
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FilterOptions {
+    /// Hide datatype nodes and datatype property edges
+    pub hide_datatypes: bool,
+
+    /// Hide subclass edges
+    pub hide_subclass: bool,
+
+    /// Hide external classes
+    pub hide_external: bool,
+
+    /// Hide annotation property edges
+    pub hide_annotations: bool,
+
+    /// Hide `sameAs` equivalence edges
+    pub hide_same_as: bool,
+}
+
+impl FilterOptions {
+    /// Check if any filter is enabled
+    pub fn is_active(&self) -> bool {
+        self.hide_datatypes
+            || self.hide_subclass
+            || self.hide_external
+            || self.hide_annotations
+            || self.hide_same_as
+    }
+}
+
+impl VowlGraph {
+    /// Get a copy of the graph without the hidden nodes and edges
+    ///
+    /// Edges incident to a hidden node are dropped as well. Kept nodes retain
+    /// their positions, and metadata is recomputed for the view.
+    pub fn filtered_view(&self, opts: FilterOptions) -> VowlGraph {
+        let mut view = self.retain_view(
+            |node| {
+                !((opts.hide_datatypes && node.node_type == NodeType::Datatype)
+                    || (opts.hide_external && node.semantic.external))
+            },
+            |edge| {
+                !((opts.hide_datatypes && edge.edge_type == EdgeType::DatatypeProperty)
+                    || (opts.hide_subclass && edge.edge_type == EdgeType::SubClass)
+                    || (opts.hide_annotations && edge.edge_type == EdgeType::Annotation)
+                    || (opts.hide_same_as && edge.edge_type == EdgeType::SameAs))
+            },
+        );
+        view.update_metadata();
+        view
+    }
+
+    /// Dim everything except a node, its neighbors and its edges
+    ///
+    /// Highlighted elements get opacity 1 and all others `dim`.
+    pub fn highlight(&mut self, id: &str, dim: f64) -> Result<()> {
+        let focus = self.index_of(id)?;
+        let lit: HashSet<_> = self
+            .graph
+            .neighbors_undirected(focus)
+            .chain([focus])
+            .collect();
+
+        for idx in self.graph.node_indices() {
+            self.graph[idx].visual.opacity = if lit.contains(&idx) { 1.0 } else { dim };
+        }
+        let touching: HashSet<_> = self
+            .graph
+            .edges_directed(focus, petgraph::Direction::Outgoing)
+            .chain(self.graph.edges_directed(focus, petgraph::Direction::Incoming))
+            .map(|edge| edge.id())
+            .collect();
+        for idx in self.graph.edge_indices() {
+            self.graph[idx].opacity = if touching.contains(&idx) { 1.0 } else { dim };
+        }
+        Ok(())
+    }
+
+    /// Reset every node and edge to full opacity
+    pub fn clear_highlight(&mut self) {
+        for node in self.graph.node_weights_mut() {
+            node.visual.opacity = 1.0;
+        }
+        for edge in self.graph.edge_weights_mut() {
+            edge.opacity = 1.0;
+        }
+    }
+
+    /// Copy the view-only state of a filtered view back onto this graph
+    ///
+    /// Node visuals and edge opacity are matched by ID; nodes and edges the
+    /// view does not contain keep their state.
+    pub fn copy_view_state(&mut self, view: &VowlGraph) {
+        for node in view.graph.node_weights() {
+            if let Some(target) = self.get_node_mut(&node.id) {
+                target.visual = node.visual.clone();
+            }
+        }
+
+        let opacities: HashMap<&str, f64> = view
+            .graph
+            .edge_weights()
+            .map(|edge| (edge.id.as_str(), edge.opacity))
+            .collect();
+        for edge in self.graph.edge_weights_mut() {
+            if let Some(&opacity) = opacities.get(edge.id.as_str()) {
+                edge.opacity = opacity;
+            }
+        }
+    }
+
+    /// Get the IDs of nodes without any incoming or outgoing edge
+    pub fn isolated_nodes(&self) -> Vec<String> {
+        self.graph
+            .node_indices()
+            .filter(|&idx| self.graph.neighbors_undirected(idx).next().is_none())
+            .map(|idx| self.graph[idx].id.clone())
+            .collect()
+    }
+
+    /// Remove all isolated nodes and return how many were removed
+    pub fn prune_isolated(&mut self) -> usize {
+        let isolated = self.isolated_nodes();
+        for id in &isolated {
+            // IDs come from the graph, and `remove_node` keeps the ID map in
+            // step with petgraph's index swaps
+            let _ = self.remove_node(id);
+        }
+        isolated.len()
+    }
+
+    /// Copy the nodes and edges passing the predicates into a new graph
+    ///
+    /// Edges whose endpoints are not kept are dropped. Only the degree index
+    /// is rebuilt, so that later edits keep working; callers returning the
+    /// view recompute its metadata.
+    pub(super) fn retain_view(
+        &self,
+        keep_node: impl Fn(&Node) -> bool,
+        keep_edge: impl Fn(&Edge) -> bool,
+    ) -> VowlGraph {
+        let graph = self.graph.filter_map(
+            |_, node| keep_node(node).then(|| node.clone()),
+            |_, edge| keep_edge(edge).then(|| edge.clone()),
+        );
+
+        let mut view = VowlGraph::new();
+        view.node_map = graph
+            .node_indices()
+            .map(|idx| (graph[idx].id.clone(), idx))
+            .collect();
+        view.graph = graph;
+        view.ontology_metadata = self.ontology_metadata.clone();
+        view.recount_degrees();
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder, traversal::Direction};
+
+    /// Person with a datatype property, a subclass and an external class
+    fn create_graph() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("person").build()).unwrap();
+        graph.add_node(NodeBuilder::new("student").build()).unwrap();
+        graph
+            .add_node(NodeBuilder::new("agent").external(true).build())
+            .unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("xsd:string")
+                    .node_type(NodeType::Datatype)
+                    .build(),
+            )
+            .unwrap();
+
+        let edges = [
+            ("person", "xsd:string", "name", EdgeType::DatatypeProperty),
+            ("student", "person", "subclass", EdgeType::SubClass),
+            ("person", "agent", "actsAs", EdgeType::ObjectProperty),
+        ];
+        for (from, to, id, edge_type) in edges {
+            let edge = EdgeBuilder::new(id).edge_type(edge_type).build();
+            graph.add_edge(from, to, edge).unwrap();
+        }
+        graph
+    }
+
+    fn edge_ids(graph: &VowlGraph) -> Vec<&str> {
+        graph
+            .edge_endpoints()
+            .into_iter()
+            .map(|(_, edge, _)| edge.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_hide_datatypes() {
+        let graph = create_graph();
+        let view = graph.filtered_view(FilterOptions {
+            hide_datatypes: true,
+            ..Default::default()
+        });
+
+        assert!(view.get_node("xsd:string").is_none());
+        assert_eq!(view.node_count(), 3);
+        assert_eq!(edge_ids(&view), vec!["subclass", "actsAs"]);
+        assert_eq!(view.get_node("person"), graph.get_node("person"));
+        // Node lookups must follow the re-indexed view
+        let neighbors = view.neighbors_directed("person", Direction::Undirected).unwrap();
+        assert_eq!(neighbors.len(), 2);
+    }
+
+    #[test]
+    fn test_hide_subclass_and_external() {
+        let view = create_graph().filtered_view(FilterOptions {
+            hide_subclass: true,
+            hide_external: true,
+            ..Default::default()
+        });
+
+        assert!(view.get_node("agent").is_none());
+        assert_eq!(edge_ids(&view), vec!["name"]);
+    }
+
+    #[test]
+    fn test_hide_annotations() {
+        let mut graph = create_graph();
+        let comment = EdgeBuilder::new("seeAlso").edge_type(EdgeType::Annotation).build();
+        graph.add_edge("person", "agent", comment).unwrap();
+
+        let opts = FilterOptions {
+            hide_annotations: true,
+            ..Default::default()
+        };
+        assert!(opts.is_active());
+        let view = graph.filtered_view(opts);
+
+        assert_eq!(view.node_count(), graph.node_count());
+        assert_eq!(edge_ids(&view), vec!["name", "subclass", "actsAs"]);
+    }
+
+    #[test]
+    fn test_hide_same_as() {
+        let mut graph = create_graph();
+        let same = EdgeBuilder::new("sameAs").edge_type(EdgeType::SameAs).build();
+        graph.add_edge("person", "agent", same).unwrap();
+
+        assert_eq!(graph.filtered_view(FilterOptions::default()).edge_count(), 4);
+
+        let opts = FilterOptions {
+            hide_same_as: true,
+            ..Default::default()
+        };
+        assert!(opts.is_active());
+        assert_eq!(edge_ids(&graph.filtered_view(opts)), vec!["name", "subclass", "actsAs"]);
+    }
+
+    #[test]
+    fn test_copy_view_state() {
+        let mut graph = create_graph();
+        let mut view = graph.filtered_view(FilterOptions {
+            hide_subclass: true,
+            ..Default::default()
+        });
+        view.highlight("agent", 0.5).unwrap();
+        view.set_position("agent", 7.0, 8.0).unwrap();
+
+        graph.copy_view_state(&view);
+
+        let agent = graph.get_node("agent").unwrap();
+        assert_eq!((agent.visual.x, agent.visual.y, agent.visual.opacity), (7.0, 8.0, 1.0));
+        assert_eq!(graph.get_node("student").unwrap().visual.opacity, 0.5);
+        let opacities: Vec<(&str, f64)> = graph
+            .edge_endpoints()
+            .into_iter()
+            .map(|(_, edge, _)| (edge.id.as_str(), edge.opacity))
+            .collect();
+        // The hidden subclass edge was not in the view and keeps its state
+        assert_eq!(opacities, vec![("name", 0.5), ("subclass", 1.0), ("actsAs", 1.0)]);
+    }
+
+    #[test]
+    fn test_highlight_dims_unrelated() {
+        let mut graph = create_graph();
+        graph.add_node(NodeBuilder::new("lonely").build()).unwrap();
+
+        graph.highlight("student", DIM_OPACITY).unwrap();
+
+        let opacity = |graph: &VowlGraph, id: &str| graph.get_node(id).unwrap().visual.opacity;
+        assert_eq!(opacity(&graph, "student"), 1.0);
+        for neighbor in graph.neighbors("student").unwrap() {
+            assert_eq!(neighbor.visual.opacity, 1.0);
+        }
+        assert_eq!(opacity(&graph, "agent"), DIM_OPACITY);
+        assert_eq!(opacity(&graph, "lonely"), DIM_OPACITY);
+        for (from, edge, to) in graph.edge_endpoints() {
+            let expected = if from == "student" || to == "student" { 1.0 } else { DIM_OPACITY };
+            assert_eq!(edge.opacity, expected, "edge {}", edge.id);
+        }
+
+        assert!(graph.highlight("missing", DIM_OPACITY).is_err());
+        graph.clear_highlight();
+        assert!(graph.nodes().iter().all(|n| n.visual.opacity == 1.0));
+        assert!(graph.edge_endpoints().iter().all(|(_, e, _)| e.opacity == 1.0));
+    }
+
+    #[test]
+    fn test_prune_isolated() {
+        let mut graph = create_graph();
+        for id in ["lonely", "orphan"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        let connected: Vec<String> = graph
+            .nodes()
+            .iter()
+            .map(|n| n.id.clone())
+            .filter(|id| id != "lonely" && id != "orphan")
+            .collect();
+
+        assert_eq!(graph.isolated_nodes(), vec!["lonely", "orphan"]);
+        assert_eq!(graph.prune_isolated(), 2);
+
+        assert!(graph.isolated_nodes().is_empty());
+        assert_eq!(graph.node_count(), connected.len());
+        for id in &connected {
+            assert_eq!(&graph.get_node(id).unwrap().id, id);
+        }
+        assert_eq!(graph.prune_isolated(), 0);
+    }
+
+    #[test]
+    fn test_no_filter_keeps_everything() {
+        let graph = create_graph();
+        let view = graph.filtered_view(FilterOptions::default());
+
+        assert!(!FilterOptions::default().is_active());
+        assert_eq!(view.node_count(), graph.node_count());
+        assert_eq!(view.edge_count(), graph.edge_count());
+    }
+}
@@ -0,0 +1,330 @@
+//! Spatial operations on node positions
+
+use super::{Node, VowlGraph};
+use crate::{Result, VowlError};
+
+impl VowlGraph {
+    /// Get the axis-aligned bounding box of all node positions
+    ///
+    /// Returns `(min_x, min_y, max_x, max_y)`, or `None` for an empty graph.
+    pub fn bounding_box(&self) -> Option<(f64, f64, f64, f64)> {
+        self.graph.node_weights().fold(None, |bbox, node| {
+            let (x, y) = (node.visual.x, node.visual.y);
+            Some(match bbox {
+                None => (x, y, x, y),
+                Some((min_x, min_y, max_x, max_y)) => {
+                    (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+                }
+            })
+        })
+    }
+
+    /// Get the uniform scale fitting the bounding box into a padded viewport
+    ///
+    /// Extents smaller than one unit are treated as one so that a single node
+    /// or a line of nodes does not produce an infinite scale. Returns `1.0` for
+    /// an empty graph.
+    pub fn fit_scale(&self, width: f64, height: f64, padding: f64) -> f64 {
+        let Some((min_x, min_y, max_x, max_y)) = self.bounding_box() else {
+            return 1.0;
+        };
+
+        let scale_x = (width - 2.0 * padding) / (max_x - min_x).max(1.0);
+        let scale_y = (height - 2.0 * padding) / (max_y - min_y).max(1.0);
+
+        scale_x.min(scale_y)
+    }
+
+    /// Get the ID of the node nearest to a point, if within `radius` of it
+    ///
+    /// Hidden nodes are ignored. When several nodes cover the point, the one
+    /// with the closest center wins.
+    pub fn node_at(&self, x: f64, y: f64, radius: f64) -> Option<&str> {
+        self.node_at_with(x, y, |_| radius)
+    }
+
+    /// Like [`node_at`](Self::node_at), with a hit radius per node
+    pub fn node_at_with(&self, x: f64, y: f64, radius: impl Fn(&Node) -> f64) -> Option<&str> {
+        self.graph
+            .node_weights()
+            .filter(|node| !node.visual.hidden)
+            .map(|node| {
+                let distance = (node.visual.x - x).hypot(node.visual.y - y);
+                (node, distance)
+            })
+            .filter(|(node, distance)| *distance <= radius(node))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(node, _)| node.id.as_str())
+    }
+
+    /// Pin or release a node
+    ///
+    /// Pinned nodes keep their position during simulation ticks; pinning
+    /// also clears any remaining velocity.
+    pub fn set_fixed(&mut self, id: &str, fixed: bool) -> Result<()> {
+        let node = self
+            .get_node_mut(id)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+
+        node.visual.fixed = fixed;
+        if fixed {
+            node.visual.vx = 0.0;
+            node.visual.vy = 0.0;
+        }
+        Ok(())
+    }
+
+    /// Move a single node to the given position and clear its velocity
+    pub fn set_position(&mut self, id: &str, x: f64, y: f64) -> Result<()> {
+        let node = self
+            .get_node_mut(id)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+
+        node.visual.x = x;
+        node.visual.y = y;
+        node.visual.vx = 0.0;
+        node.visual.vy = 0.0;
+        Ok(())
+    }
+
+    /// Move every node by the given offset
+    pub fn translate(&mut self, dx: f64, dy: f64) {
+        for node in self.graph.node_weights_mut() {
+            node.visual.x += dx;
+            node.visual.y += dy;
+        }
+    }
+
+    /// Translate the layout so the given node sits at the origin
+    ///
+    /// Relative positions are preserved. This is an immediate viewport
+    /// adjustment, independent of the simulation's center force.
+    pub fn recenter_on(&mut self, id: &str) -> Result<()> {
+        let node = self
+            .get_node(id)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+        let (dx, dy) = (-node.visual.x, -node.visual.y);
+
+        self.translate(dx, dy);
+        Ok(())
+    }
+
+    /// Push apart any pair of nodes closer than `min_dist`
+    ///
+    /// Runs up to `iterations` relaxation passes, stopping early once no
+    /// overlaps remain. Fixed nodes are never moved; when only one node of a
+    /// pair is fixed, the other is moved the full distance.
+    pub fn enforce_min_separation(&mut self, min_dist: f64, iterations: usize) {
+        let indices: Vec<_> = self.graph.node_indices().collect();
+        let mut positions: Vec<(f64, f64, bool)> = indices
+            .iter()
+            .map(|&i| {
+                let v = &self.graph[i].visual;
+                (v.x, v.y, v.fixed)
+            })
+            .collect();
+
+        for _ in 0..iterations {
+            let mut overlapping = false;
+
+            for i in 0..positions.len() {
+                for j in (i + 1)..positions.len() {
+                    let (x1, y1, fixed1) = positions[i];
+                    let (x2, y2, fixed2) = positions[j];
+                    if fixed1 && fixed2 {
+                        continue;
+                    }
+
+                    let (mut dx, mut dy) = (x2 - x1, y2 - y1);
+                    let mut distance = (dx * dx + dy * dy).sqrt();
+                    if distance >= min_dist {
+                        continue;
+                    }
+                    overlapping = true;
+
+                    if distance < 1e-9 {
+                        // Coincident nodes: separate along a deterministic direction
+                        let angle = (i * 31 + j * 17) as f64;
+                        dx = angle.cos();
+                        dy = angle.sin();
+                        distance = 1.0;
+                    }
+
+                    let push = min_dist - distance.min(min_dist);
+                    let (ux, uy) = (dx / distance, dy / distance);
+                    let (share1, share2) = match (fixed1, fixed2) {
+                        (true, false) => (0.0, 1.0),
+                        (false, true) => (1.0, 0.0),
+                        _ => (0.5, 0.5),
+                    };
+
+                    positions[i].0 -= ux * push * share1;
+                    positions[i].1 -= uy * push * share1;
+                    positions[j].0 += ux * push * share2;
+                    positions[j].1 += uy * push * share2;
+                }
+            }
+
+            if !overlapping {
+                break;
+            }
+        }
+
+        for (idx, (x, y, _)) in indices.into_iter().zip(positions) {
+            let visual = &mut self.graph[idx].visual;
+            visual.x = x;
+            visual.y = y;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::node::NodeBuilder;
+
+    fn create_positioned(positions: &[(&str, f64, f64)]) -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for &(id, x, y) in positions {
+            let mut node = NodeBuilder::new(id).build();
+            node.visual.x = x;
+            node.visual.y = y;
+            graph.add_node(node).unwrap();
+        }
+        graph
+    }
+
+    fn distance(graph: &VowlGraph, a: &str, b: &str) -> f64 {
+        let a = &graph.get_node(a).unwrap().visual;
+        let b = &graph.get_node(b).unwrap().visual;
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let mut graph = VowlGraph::new();
+        assert_eq!(graph.bounding_box(), None);
+
+        graph
+            .add_node(NodeBuilder::new("a").position(-5.0, 10.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(20.0, -3.0).build())
+            .unwrap();
+
+        assert_eq!(graph.bounding_box(), Some((-5.0, -3.0, 20.0, 10.0)));
+    }
+
+    #[test]
+    fn test_fit_scale() {
+        let mut graph = VowlGraph::new();
+        assert_eq!(graph.fit_scale(800.0, 600.0, 20.0), 1.0);
+
+        graph
+            .add_node(NodeBuilder::new("a").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(200.0, 100.0).build())
+            .unwrap();
+
+        // x fits at 760 / 200 = 3.8, y at 560 / 100 = 5.6; the tighter axis wins
+        assert_eq!(graph.fit_scale(800.0, 600.0, 20.0), 3.8);
+    }
+
+    #[test]
+    fn test_node_at() {
+        let graph = create_positioned(&[("a", 0.0, 0.0), ("b", 100.0, 0.0)]);
+
+        assert_eq!(graph.node_at(3.0, 4.0, 10.0), Some("a"));
+        assert_eq!(graph.node_at(100.0, 0.0, 10.0), Some("b"));
+
+        // Near miss just outside the radius
+        assert_eq!(graph.node_at(6.0, 8.0, 9.9), None);
+        assert_eq!(graph.node_at(50.0, 0.0, 10.0), None);
+    }
+
+    #[test]
+    fn test_node_at_overlapping_picks_closest() {
+        let graph = create_positioned(&[("a", 0.0, 0.0), ("b", 10.0, 0.0)]);
+
+        assert_eq!(graph.node_at(4.0, 0.0, 20.0), Some("a"));
+        assert_eq!(graph.node_at(6.0, 0.0, 20.0), Some("b"));
+
+        // A larger node still loses to a closer center
+        let radius = |node: &Node| if node.id == "a" { 50.0 } else { 5.0 };
+        assert_eq!(graph.node_at_with(8.0, 0.0, radius), Some("b"));
+    }
+
+    #[test]
+    fn test_set_fixed_and_position() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+        graph.get_node_mut("a").unwrap().visual.vx = 5.0;
+
+        graph.set_position("a", 30.0, -40.0).unwrap();
+        graph.set_fixed("a", true).unwrap();
+
+        let a = &graph.get_node("a").unwrap().visual;
+        assert_eq!((a.x, a.y, a.vx, a.vy), (30.0, -40.0, 0.0, 0.0));
+        assert!(a.fixed);
+        assert!(graph.set_fixed("missing", true).is_err());
+        assert!(graph.set_position("missing", 0.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_recenter_on_preserves_distances() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(40.0, -10.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(70.0, 30.0).build())
+            .unwrap();
+        let before = distance(&graph, "a", "b");
+
+        graph.recenter_on("b").unwrap();
+
+        let b = &graph.get_node("b").unwrap().visual;
+        assert_eq!((b.x, b.y), (0.0, 0.0));
+        assert!((distance(&graph, "a", "b") - before).abs() < 1e-9);
+        assert!(graph.recenter_on("missing").is_err());
+    }
+
+    #[test]
+    fn test_enforce_min_separation_overlapping() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(10.0, 10.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(12.0, 10.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("c").position(10.0, 10.0).build())
+            .unwrap();
+
+        graph.enforce_min_separation(30.0, 50);
+
+        assert!(distance(&graph, "a", "b") >= 30.0 - 1e-6);
+        assert!(distance(&graph, "a", "c") >= 30.0 - 1e-6);
+        assert!(distance(&graph, "b", "c") >= 30.0 - 1e-6);
+    }
+
+    #[test]
+    fn test_enforce_min_separation_respects_fixed() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(5.0, 0.0).build())
+            .unwrap();
+        graph.get_node_mut("a").unwrap().visual.fixed = true;
+
+        graph.enforce_min_separation(20.0, 1);
+
+        let a = &graph.get_node("a").unwrap().visual;
+        assert_eq!((a.x, a.y), (0.0, 0.0));
+        assert!((distance(&graph, "a", "b") - 20.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,264 @@
+//! Combining graphs built from separate ontologies
+
+use super::VowlGraph;
+use crate::{Result, VowlError};
+use petgraph::visit::EdgeRef;
+use std::collections::{HashMap, HashSet};
+
+/// What to do when a merged node or edge ID is already taken
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum CollisionPolicy {
+    /// Fail the merge without changing the graph
+    #[default]
+    Error,
+
+    /// Keep the existing node or edge; edges of the other graph attach to
+    /// the existing node
+    Skip,
+
+    /// Rename the incoming ID to `prefix:id`
+    Prefix(String),
+}
+
+/// Options for `VowlGraph::merge`
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MergeOptions {
+    /// Handling of node and edge ID collisions
+    pub on_collision: CollisionPolicy,
+
+    /// Treat nodes with the same non-empty IRI as one node
+    pub unify_by_iri: bool,
+}
+
+impl VowlGraph {
+    /// Add the nodes and edges of another graph
+    ///
+    /// All collisions are resolved before anything is added, so a failed
+    /// merge leaves the graph unchanged. Unified nodes keep the attributes
+    /// of the node already in this graph, or of the first one in `other`
+    /// when `other` repeats an IRI.
+    pub fn merge(&mut self, other: &VowlGraph, opts: MergeOptions) -> Result<()> {
+        let mut by_iri: HashMap<&str, String> = if opts.unify_by_iri {
+            self.graph
+                .node_weights()
+                .filter(|node| !node.semantic.iri.is_empty())
+                .map(|node| (node.semantic.iri.as_str(), node.id.clone()))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // Map every incoming node to its ID in the merged graph; renamed IDs
+        // may collide with later incoming ones, so those count as taken too
+        let mut node_ids = HashMap::new();
+        let mut new_nodes = Vec::new();
+        let mut assigned = HashSet::new();
+        for node in other.graph.node_weights() {
+            if let Some(existing) = by_iri.get(node.semantic.iri.as_str()) {
+                node_ids.insert(node.id.as_str(), existing.clone());
+                continue;
+            }
+
+            let taken = |id: &str| self.node_map.contains_key(id) || assigned.contains(id);
+            let id = match resolve_id("Node", &node.id, taken, &opts.on_collision)? {
+                Some(id) => {
+                    assigned.insert(id.clone());
+                    new_nodes.push((id.clone(), node));
+                    id
+                }
+                None => node.id.clone(),
+            };
+            // Later nodes of `other` with this IRI unify with this one
+            if opts.unify_by_iri && !node.semantic.iri.is_empty() {
+                by_iri.insert(node.semantic.iri.as_str(), id.clone());
+            }
+            node_ids.insert(node.id.as_str(), id);
+        }
+
+        let mut edge_ids: HashSet<String> =
+            self.graph.edge_weights().map(|e| e.id.clone()).collect();
+        let mut new_edges = Vec::new();
+        for edge in other.graph.edge_references() {
+            let taken = |id: &str| edge_ids.contains(id);
+            let Some(id) = resolve_id("Edge", &edge.weight().id, taken, &opts.on_collision)?
+            else {
+                continue;
+            };
+            edge_ids.insert(id.clone());
+            let from = node_ids[other.graph[edge.source()].id.as_str()].clone();
+            let to = node_ids[other.graph[edge.target()].id.as_str()].clone();
+            new_edges.push((id, from, to, edge.weight()));
+        }
+
+        for (id, node) in new_nodes {
+            let mut node = node.clone();
+            node.id = id;
+            self.add_node(node)?;
+        }
+        for (id, from, to, edge) in new_edges {
+            let mut edge = edge.clone();
+            edge.id = id;
+            self.add_edge(&from, &to, edge)?;
+        }
+        Ok(())
+    }
+}
+
+/// Pick the ID an incoming node or edge is added under
+///
+/// Returns `None` if it should be skipped.
+fn resolve_id(
+    kind: &str,
+    id: &str,
+    taken: impl Fn(&str) -> bool,
+    policy: &CollisionPolicy,
+) -> Result<Option<String>> {
+    if !taken(id) {
+        return Ok(Some(id.to_string()));
+    }
+
+    match policy {
+        CollisionPolicy::Skip => Ok(None),
+        CollisionPolicy::Prefix(prefix) => {
+            let renamed = format!("{}:{}", prefix, id);
+            if taken(&renamed) {
+                return Err(VowlError::GraphError(format!(
+                    "{} '{}' already exists",
+                    kind, renamed
+                )));
+            }
+            Ok(Some(renamed))
+        }
+        CollisionPolicy::Error => Err(VowlError::GraphError(format!(
+            "{} '{}' already exists",
+            kind, id
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    /// Two classes with the given IDs and IRIs joined by one edge
+    fn create_pair(ids: [&str; 2], iris: [&str; 2], edge: &str) -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for (id, iri) in ids.iter().zip(iris) {
+            graph.add_node(NodeBuilder::new(*id).iri(iri).build()).unwrap();
+        }
+        graph.add_edge(ids[0], ids[1], EdgeBuilder::new(edge).build()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_clean_merge() {
+        let mut graph = create_pair(["person", "city"], ["ex:Person", "ex:City"], "livesIn");
+        let other = create_pair(["car", "brand"], ["ex:Car", "ex:Brand"], "madeBy");
+
+        graph.merge(&other, MergeOptions::default()).unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.metadata().class_count, 4);
+        let endpoints = graph.edge_endpoints();
+        assert!(endpoints.iter().any(|&(from, e, to)| {
+            (from, e.id.as_str(), to) == ("car", "madeBy", "brand")
+        }));
+    }
+
+    #[test]
+    fn test_collision_policies() {
+        let graph = create_pair(["person", "city"], ["ex:Person", "ex:City"], "livesIn");
+        let other = create_pair(["person", "car"], ["other:Person", "ex:Car"], "owns");
+
+        // Errors leave the graph untouched
+        let mut failed = graph.clone();
+        assert!(failed.merge(&other, MergeOptions::default()).is_err());
+        assert_eq!(failed.node_count(), 2);
+
+        let mut skipped = graph.clone();
+        let skip = MergeOptions {
+            on_collision: CollisionPolicy::Skip,
+            ..Default::default()
+        };
+        skipped.merge(&other, skip).unwrap();
+        assert_eq!(skipped.node_count(), 3);
+        assert_eq!(skipped.get_node("person").unwrap().semantic.iri, "ex:Person");
+        assert!(skipped.edge_endpoints().iter().any(|&(from, e, to)| {
+            (from, e.id.as_str(), to) == ("person", "owns", "car")
+        }));
+
+        let mut prefixed = graph.clone();
+        let prefix = MergeOptions {
+            on_collision: CollisionPolicy::Prefix("other".to_string()),
+            ..Default::default()
+        };
+        prefixed.merge(&other, prefix).unwrap();
+        assert_eq!(prefixed.node_count(), 4);
+        assert_eq!(prefixed.get_node("other:person").unwrap().semantic.iri, "other:Person");
+    }
+
+    #[test]
+    fn test_unify_by_iri() {
+        let mut graph = create_pair(["person", "city"], ["ex:Person", "ex:City"], "livesIn");
+        let other = create_pair(["human", "car"], ["ex:Person", "ex:Car"], "owns");
+
+        let opts = MergeOptions {
+            unify_by_iri: true,
+            ..Default::default()
+        };
+        graph.merge(&other, opts).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.get_node("human").is_none());
+        assert!(graph.edge_endpoints().iter().any(|&(from, e, to)| {
+            (from, e.id.as_str(), to) == ("person", "owns", "car")
+        }));
+    }
+
+    #[test]
+    fn test_unify_by_iri_within_other() {
+        let mut graph = create_pair(["person", "city"], ["ex:Person", "ex:City"], "livesIn");
+        let other = create_pair(["car", "auto"], ["ex:Car", "ex:Car"], "sameCar");
+
+        let opts = MergeOptions {
+            unify_by_iri: true,
+            ..Default::default()
+        };
+        graph.merge(&other, opts).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.get_node("auto").is_none());
+        let cars = graph.nodes().iter().filter(|n| n.semantic.iri == "ex:Car").count();
+        assert_eq!(cars, 1);
+        assert!(graph.edge_endpoints().iter().any(|&(from, e, to)| {
+            (from, e.id.as_str(), to) == ("car", "sameCar", "car")
+        }));
+    }
+
+    #[test]
+    fn test_prefixed_ids_do_not_collide_within_other() {
+        let mut graph = create_pair(["a", "b"], ["ex:A", "ex:B"], "e");
+        let mut other = create_pair(["a", "x:a"], ["ex:A", "ex:XA"], "e");
+        other.add_edge("x:a", "a", EdgeBuilder::new("x:e").build()).unwrap();
+
+        let prefix = MergeOptions {
+            on_collision: CollisionPolicy::Prefix("x".to_string()),
+            ..Default::default()
+        };
+        graph.merge(&other, prefix.clone()).unwrap();
+
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.get_node("x:a").unwrap().semantic.iri, "ex:A");
+        assert_eq!(graph.get_node("x:x:a").unwrap().semantic.iri, "ex:XA");
+        let mut edges: Vec<&str> = graph.edges().iter().map(|e| e.id.as_str()).collect();
+        edges.sort();
+        assert_eq!(edges, vec!["e", "x:e", "x:x:e"]);
+
+        // A collision the prefix cannot resolve fails before adding anything
+        let mut graph = create_pair(["a", "x:x:a"], ["ex:A", "ex:B"], "e");
+        assert!(graph.merge(&other, prefix).is_err());
+        assert_eq!(graph.node_count(), 2);
+    }
+}
@@ -6,9 +6,23 @@
 pub mod node;
 pub mod edge;
 pub mod builder;
-
+pub mod centrality;
+pub mod collapse;
+pub mod filter;
+pub mod merge;
+pub mod traversal;
+pub mod search;
+pub mod export;
+pub mod geometry;
+pub mod palette;
+pub mod serialize;
+
+use crate::ontology::OntologyMetadata;
 use crate::{Result, VowlError};
 use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::EdgeRef;
+use serde::{Deserialize, Serialize};
+use std::cell::OnceCell;
 use std::collections::HashMap;
 
 /// Main graph structure for ontology visualization
@@ -22,6 +36,77 @@ pub struct VowlGraph {
 
     /// Graph metadata
     metadata: GraphMetadata,
+
+    /// Header of the ontology the graph was built from
+    ontology_metadata: Option<OntologyMetadata>,
+
+    /// Node degrees backing the incrementally maintained `max_degree`
+    degrees: DegreeIndex,
+}
+
+/// Degree of every node plus a histogram of degrees
+///
+/// Lets `max_degree` be maintained on every mutation: it only grows on edge
+/// insertion, and on removal it steps down past empty histogram buckets,
+/// which is amortized O(1).
+#[derive(Debug, Clone, Default)]
+struct DegreeIndex {
+    /// Degree of each node, by node index
+    degrees: Vec<usize>,
+
+    /// Number of nodes having each degree
+    histogram: Vec<usize>,
+
+    /// Largest degree with a non-empty bucket
+    max: usize,
+}
+
+impl DegreeIndex {
+    /// Track a new node without edges
+    fn push(&mut self) {
+        self.degrees.push(0);
+        self.bucket(0, 1);
+    }
+
+    /// Add one to a node's degree
+    fn increment(&mut self, index: usize) {
+        let degree = self.degrees[index];
+        self.degrees[index] = degree + 1;
+        self.histogram[degree] -= 1;
+        self.bucket(degree + 1, 1);
+        self.max = self.max.max(degree + 1);
+    }
+
+    /// Subtract one from a node's degree
+    fn decrement(&mut self, index: usize) {
+        let degree = self.degrees[index];
+        self.degrees[index] = degree - 1;
+        self.histogram[degree] -= 1;
+        self.histogram[degree - 1] += 1;
+        self.shrink_max();
+    }
+
+    /// Stop tracking a node, moving the last one into its slot as petgraph does
+    fn swap_remove(&mut self, index: usize) {
+        let degree = self.degrees.swap_remove(index);
+        self.histogram[degree] -= 1;
+        self.shrink_max();
+    }
+
+    /// Add `count` nodes to a degree's bucket, growing the histogram if needed
+    fn bucket(&mut self, degree: usize, count: usize) {
+        if self.histogram.len() <= degree {
+            self.histogram.resize(degree + 1, 0);
+        }
+        self.histogram[degree] += count;
+    }
+
+    /// Lower `max` to the largest non-empty bucket
+    fn shrink_max(&mut self) {
+        while self.max > 0 && self.histogram[self.max] == 0 {
+            self.max -= 1;
+        }
+    }
 }
 
 /// Graph metadata and statistics
@@ -38,10 +123,15 @@ pub struct GraphMetadata {
 
     /// Graph density
     pub density: f64,
+
+    /// Diameter of the largest weakly connected component, filled in by the
+    /// first call to [`VowlGraph::diameter`] and cleared when nodes or edges
+    /// change
+    diameter: OnceCell<Option<usize>>,
 }
 
 /// Graph node representing a class or datatype
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Node {
     /// Unique identifier
     pub id: String,
@@ -60,7 +150,7 @@ pub struct Node {
 }
 
 /// Type of graph node
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NodeType {
     /// OWL Class
     Class,
@@ -72,8 +162,28 @@ pub enum NodeType {
     Special(String),
 }
 
+impl NodeType {
+    /// Stable name used by exports: "class", "datatype", "thing" or "special"
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            NodeType::Class => "class",
+            NodeType::Datatype => "datatype",
+            NodeType::Special(name) if name == "Thing" => "thing",
+            NodeType::Special(_) => "special",
+        }
+    }
+
+    /// Name of a "special" node type, e.g. "Nothing"
+    pub fn special_name(&self) -> Option<&str> {
+        match self {
+            NodeType::Special(name) if name != "Thing" => Some(name),
+            _ => None,
+        }
+    }
+}
+
 /// Visual attributes for rendering
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VisualAttributes {
     /// X coordinate
     pub x: f64,
@@ -90,15 +200,49 @@ pub struct VisualAttributes {
     /// Is this node fixed?
     pub fixed: bool,
 
+    /// Is this node hidden inside a collapsed subtree?
+    #[serde(default)]
+    pub hidden: bool,
+
     /// Visual weight/size
     pub weight: f64,
 
+    /// Charge strength overriding the layout's global charge
+    #[serde(default)]
+    pub charge: Option<f64>,
+
     /// Color (hex)
     pub color: Option<String>,
+
+    /// Opacity from 0 (invisible) to 1, lowered to dim the node
+    #[serde(default = "full_opacity")]
+    pub opacity: f64,
+}
+
+impl Default for VisualAttributes {
+    fn default() -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            vx: 0.0,
+            vy: 0.0,
+            fixed: false,
+            hidden: false,
+            weight: 0.0,
+            charge: None,
+            color: None,
+            opacity: 1.0,
+        }
+    }
+}
+
+/// Serde default for opacities missing from older snapshots
+fn full_opacity() -> f64 {
+    1.0
 }
 
 /// Semantic attributes
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct SemanticAttributes {
     /// IRI
     pub iri: String,
@@ -106,15 +250,22 @@ pub struct SemanticAttributes {
     /// Is external?
     pub external: bool,
 
+    /// Is deprecated?
+    #[serde(default)]
+    pub deprecated: bool,
+
     /// Equivalent classes
     pub equivalent: Vec<String>,
 
     /// Individual count
     pub individuals: Option<usize>,
+
+    /// Labels keyed by language tag (e.g. "en", "de")
+    pub labels: HashMap<String, String>,
 }
 
 /// Graph edge representing a property
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Edge {
     /// Property identifier
     pub id: String,
@@ -127,10 +278,18 @@ pub struct Edge {
 
     /// Property characteristics
     pub characteristics: EdgeCharacteristics,
+
+    /// IDs of the properties this edge's property chain is composed of
+    #[serde(default)]
+    pub chain: Vec<String>,
+
+    /// Opacity from 0 (invisible) to 1, lowered to dim the edge
+    #[serde(default = "full_opacity")]
+    pub opacity: f64,
 }
 
 /// Type of graph edge
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EdgeType {
     /// Object property
     ObjectProperty,
@@ -143,10 +302,39 @@ pub enum EdgeType {
 
     /// Special relation
     Special(String),
+
+    /// Annotation property
+    Annotation,
+
+    /// Equivalence of entities across vocabularies, e.g. `owl:sameAs`
+    SameAs,
+}
+
+impl EdgeType {
+    /// Stable name used by exports: "objectProperty", "datatypeProperty",
+    /// "subClass", "annotation", "sameAs" or "special"
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            EdgeType::ObjectProperty => "objectProperty",
+            EdgeType::DatatypeProperty => "datatypeProperty",
+            EdgeType::SubClass => "subClass",
+            EdgeType::Annotation => "annotation",
+            EdgeType::SameAs => "sameAs",
+            EdgeType::Special(_) => "special",
+        }
+    }
+
+    /// Name of a "special" edge type, e.g. "disjoint"
+    pub fn special_name(&self) -> Option<&str> {
+        match self {
+            EdgeType::Special(name) => Some(name),
+            _ => None,
+        }
+    }
 }
 
 /// Edge characteristics
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct EdgeCharacteristics {
     /// Is functional
     pub functional: bool,
@@ -162,6 +350,18 @@ pub struct EdgeCharacteristics {
 
     /// Cardinality
     pub cardinality: Option<(Option<u32>, Option<u32>)>,
+
+    /// ID of the inverse property
+    #[serde(default)]
+    pub inverse_of: Option<String>,
+
+    /// IDs of the super-properties
+    #[serde(default)]
+    pub sub_property_of: Vec<String>,
+
+    /// Edge stands for an inverse pair and is drawn with arrowheads at both ends
+    #[serde(default)]
+    pub bidirectional: bool,
 }
 
 impl VowlGraph {
@@ -171,6 +371,8 @@ impl VowlGraph {
             graph: DiGraph::new(),
             node_map: HashMap::new(),
             metadata: GraphMetadata::default(),
+            ontology_metadata: None,
+            degrees: DegreeIndex::default(),
         }
     }
 
@@ -184,9 +386,16 @@ impl VowlGraph {
         }
 
         let id = node.id.clone();
+        if node.node_type == NodeType::Class {
+            self.metadata.class_count += 1;
+        }
         let index = self.graph.add_node(node);
         self.node_map.insert(id, index);
 
+        self.degrees.push();
+        self.metadata.density = self.density();
+        self.metadata.diameter.take();
+
         Ok(index)
     }
 
@@ -202,11 +411,66 @@ impl VowlGraph {
             .get(to)
             .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", to)))?;
 
-        self.graph.add_edge(*from_idx, *to_idx, edge);
+        let (from_idx, to_idx) = (*from_idx, *to_idx);
+        self.graph.add_edge(from_idx, to_idx, edge);
+
+        self.degrees.increment(from_idx.index());
+        self.degrees.increment(to_idx.index());
+        self.metadata.property_count = self.edge_count();
+        self.metadata.max_degree = self.degrees.max;
+        self.metadata.density = self.density();
+        self.metadata.diameter.take();
 
         Ok(())
     }
 
+    /// Remove a node and all of its incident edges
+    ///
+    /// Returns the removed node.
+    pub fn remove_node(&mut self, id: &str) -> Result<Node> {
+        let index = self.index_of(id)?;
+
+        // Incident edges go with the node, so their other ends lose degree
+        let others: Vec<NodeIndex> = self
+            .graph
+            .neighbors_undirected(index)
+            .filter(|&other| other != index)
+            .collect();
+
+        let node = self
+            .graph
+            .remove_node(index)
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+        self.node_map.remove(id);
+
+        // petgraph moves the last node into the freed slot
+        if let Some(moved) = self.graph.node_weight(index) {
+            self.node_map.insert(moved.id.clone(), index);
+        }
+        for other in others {
+            self.degrees.decrement(other.index());
+        }
+        self.degrees.swap_remove(index.index());
+
+        if node.node_type == NodeType::Class {
+            self.metadata.class_count -= 1;
+        }
+        self.metadata.property_count = self.edge_count();
+        self.metadata.max_degree = self.degrees.max;
+        self.metadata.density = self.density();
+        self.metadata.diameter.take();
+
+        Ok(node)
+    }
+
+    /// Resolve a node ID to its graph index
+    fn index_of(&self, id: &str) -> Result<NodeIndex> {
+        self.node_map
+            .get(id)
+            .copied()
+            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))
+    }
+
     /// Get a node by ID
     pub fn get_node(&self, id: &str) -> Option<&Node> {
         self.node_map.get(id).and_then(|idx| self.graph.node_weight(*idx))
@@ -230,6 +494,38 @@ impl VowlGraph {
         self.graph.edge_weights().collect()
     }
 
+    /// Get current node positions keyed by node ID
+    pub fn positions(&self) -> HashMap<String, (f64, f64)> {
+        self.graph
+            .node_weights()
+            .map(|n| (n.id.clone(), (n.visual.x, n.visual.y)))
+            .collect()
+    }
+
+    /// Get node positions as a flat `[x0, y0, x1, y1, ...]` array
+    ///
+    /// Nodes appear in the same order as [`VowlGraph::nodes`].
+    pub fn position_array(&self) -> Vec<f64> {
+        self.graph
+            .node_weights()
+            .flat_map(|n| [n.visual.x, n.visual.y])
+            .collect()
+    }
+
+    /// Get all edges together with their source and target node IDs
+    pub fn edge_endpoints(&self) -> Vec<(&str, &Edge, &str)> {
+        self.graph
+            .edge_references()
+            .map(|e| {
+                (
+                    self.graph[e.source()].id.as_str(),
+                    e.weight(),
+                    self.graph[e.target()].id.as_str(),
+                )
+            })
+            .collect()
+    }
+
     /// Get node count
     pub fn node_count(&self) -> usize {
         self.graph.node_count()
@@ -256,44 +552,92 @@ impl VowlGraph {
         Ok(neighbors)
     }
 
+    /// Get neighbors of a node paired with the edge leading to each
+    pub fn neighbors_with_edges(&self, id: &str) -> Result<Vec<(&Edge, &Node)>> {
+        let idx = self.index_of(id)?;
+
+        Ok(self
+            .graph
+            .edges(idx)
+            .map(|e| (e.weight(), &self.graph[e.target()]))
+            .collect())
+    }
+
+    /// Get neighbors of a node following edges in either direction
+    pub fn undirected_neighbors(&self, id: &str) -> Result<Vec<&Node>> {
+        self.neighbors_directed(id, traversal::Direction::Undirected)
+    }
+
     /// Calculate node degree
+    ///
+    /// Counts incoming and outgoing edges, including parallel edges. A
+    /// self-loop counts twice.
     pub fn degree(&self, id: &str) -> Result<usize> {
-        let idx = self
-            .node_map
-            .get(id)
-            .ok_or_else(|| VowlError::GraphError(format!("Node '{}' not found", id)))?;
+        let idx = self.index_of(id)?;
+
+        Ok(self.graph.edges_directed(idx, petgraph::Direction::Outgoing).count()
+            + self.graph.edges_directed(idx, petgraph::Direction::Incoming).count())
+    }
+
+    /// Edges per possible directed edge between distinct nodes
+    ///
+    /// 0 for graphs with fewer than two nodes.
+    fn density(&self) -> f64 {
+        let node_count = self.node_count();
+        if node_count > 1 {
+            self.edge_count() as f64 / (node_count * (node_count - 1)) as f64
+        } else {
+            0.0
+        }
+    }
 
-        Ok(self.graph.neighbors(*idx).count())
+    /// Rebuild the degree index from the underlying graph
+    fn recount_degrees(&mut self) {
+        let mut degrees = DegreeIndex::default();
+        for index in self.graph.node_indices() {
+            let degree = self.graph.edges_directed(index, petgraph::Direction::Outgoing).count()
+                + self.graph.edges_directed(index, petgraph::Direction::Incoming).count();
+            degrees.degrees.push(degree);
+            degrees.bucket(degree, 1);
+            degrees.max = degrees.max.max(degree);
+        }
+        self.degrees = degrees;
     }
 
-    /// Update graph metadata
+    /// Recompute all graph metadata from scratch
+    ///
+    /// `add_node`, `add_edge` and `remove_node` keep the counts, maximum
+    /// degree and density up to date on their own; this linear pass is the
+    /// fallback after other changes, e.g. to a node's type. The diameter is
+    /// only cleared, to be recomputed on the next [`VowlGraph::diameter`].
     pub fn update_metadata(&mut self) {
+        self.recount_degrees();
+
         self.metadata.class_count = self
-            .nodes()
-            .iter()
+            .graph
+            .node_weights()
             .filter(|n| matches!(n.node_type, NodeType::Class))
             .count();
-
         self.metadata.property_count = self.edge_count();
-
-        self.metadata.max_degree = self
-            .node_map
-            .keys()
-            .filter_map(|id| self.degree(id).ok())
-            .max()
-            .unwrap_or(0);
-
-        let node_count = self.node_count();
-        if node_count > 1 {
-            let max_edges = node_count * (node_count - 1);
-            self.metadata.density = self.edge_count() as f64 / max_edges as f64;
-        }
+        self.metadata.max_degree = self.degrees.max;
+        self.metadata.density = self.density();
+        self.metadata.diameter.take();
     }
 
     /// Get graph metadata
     pub fn metadata(&self) -> &GraphMetadata {
         &self.metadata
     }
+
+    /// Get the IRI, title, version and description of the source ontology
+    pub fn ontology_metadata(&self) -> Option<&OntologyMetadata> {
+        self.ontology_metadata.as_ref()
+    }
+
+    /// Set the source ontology's metadata
+    pub fn set_ontology_metadata(&mut self, metadata: Option<OntologyMetadata>) {
+        self.ontology_metadata = metadata;
+    }
 }
 
 impl Default for VowlGraph {
@@ -322,6 +666,8 @@ mod tests {
             label: label.to_string(),
             edge_type: EdgeType::ObjectProperty,
             characteristics: EdgeCharacteristics::default(),
+            chain: vec![],
+            opacity: 1.0,
         }
     }
 
@@ -378,6 +724,103 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_remove_middle_node() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(create_test_node("a", "A")).unwrap();
+        graph.add_node(create_test_node("b", "B")).unwrap();
+        graph.add_node(create_test_node("c", "C")).unwrap();
+        graph.add_edge("a", "b", create_test_edge("ab", "a-b")).unwrap();
+        graph.add_edge("b", "c", create_test_edge("bc", "b-c")).unwrap();
+        graph.add_edge("c", "a", create_test_edge("ca", "c-a")).unwrap();
+
+        let removed = graph.remove_node("b").unwrap();
+        assert_eq!(removed.id, "b");
+
+        assert_eq!(graph.node_count(), 2);
+        assert!(graph.get_node("b").is_none());
+        assert_eq!(graph.get_node("a").unwrap().label, "A");
+        assert_eq!(graph.get_node("c").unwrap().label, "C");
+
+        let edges: Vec<_> = graph
+            .edge_endpoints()
+            .into_iter()
+            .map(|(from, edge, to)| (from, edge.id.as_str(), to))
+            .collect();
+        assert_eq!(edges, vec![("c", "ca", "a")]);
+        assert_eq!(graph.degree("c").unwrap(), 1);
+
+        // The freed slot is reusable without ID collisions
+        graph.add_node(create_test_node("d", "D")).unwrap();
+        graph.add_edge("d", "c", create_test_edge("dc", "d-c")).unwrap();
+        assert_eq!(graph.neighbors("d").unwrap()[0].id, "c");
+    }
+
+    #[test]
+    fn test_incremental_metadata_matches_full_recompute() {
+        fn snapshot(graph: &VowlGraph) -> (usize, usize, usize, f64) {
+            let metadata = graph.metadata();
+            (
+                metadata.class_count,
+                metadata.property_count,
+                metadata.max_degree,
+                metadata.density,
+            )
+        }
+        fn assert_matches_recompute(graph: &VowlGraph) {
+            let mut recomputed = graph.clone();
+            recomputed.update_metadata();
+            assert_eq!(snapshot(graph), snapshot(&recomputed));
+        }
+
+        let mut graph = VowlGraph::new();
+        assert_matches_recompute(&graph);
+
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(create_test_node(id, id)).unwrap();
+        }
+        let mut datatype = create_test_node("xsd:string", "string");
+        datatype.node_type = NodeType::Datatype;
+        graph.add_node(datatype).unwrap();
+        assert_matches_recompute(&graph);
+
+        let edges = [
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("c", "c"),
+            ("d", "xsd:string"),
+        ];
+        for (i, (from, to)) in edges.into_iter().enumerate() {
+            graph.add_edge(from, to, create_test_edge(&format!("e{}", i), "")).unwrap();
+            assert_matches_recompute(&graph);
+        }
+        assert_eq!(graph.metadata().max_degree, 4);
+
+        // Removing the hub lowers the maximum degree; "c" keeps its self-loop
+        graph.remove_node("a").unwrap();
+        assert_matches_recompute(&graph);
+        assert_eq!(graph.metadata().max_degree, 3);
+
+        graph.remove_node("c").unwrap();
+        assert_matches_recompute(&graph);
+        graph.add_edge("d", "b", create_test_edge("db", "")).unwrap();
+        assert_matches_recompute(&graph);
+
+        for id in ["b", "d", "xsd:string"] {
+            graph.remove_node(id).unwrap();
+            assert_matches_recompute(&graph);
+        }
+        assert_eq!(snapshot(&graph), (0, 0, 0, 0.0));
+    }
+
+    #[test]
+    fn test_remove_missing_node() {
+        let mut graph = VowlGraph::new();
+        assert!(graph.remove_node("missing").is_err());
+    }
+
     #[test]
     fn test_get_node() {
         let mut graph = VowlGraph::new();
@@ -390,6 +833,22 @@ mod tests {
         assert_eq!(retrieved.unwrap().label, "Node 1");
     }
 
+    #[test]
+    fn test_position_array() {
+        let mut graph = VowlGraph::new();
+        let mut a = create_test_node("a", "A");
+        a.visual.x = 1.0;
+        a.visual.y = 2.0;
+        let mut b = create_test_node("b", "B");
+        b.visual.x = 3.0;
+        b.visual.y = 4.0;
+
+        graph.add_node(a).unwrap();
+        graph.add_node(b).unwrap();
+
+        assert_eq!(graph.position_array(), vec![1.0, 2.0, 3.0, 4.0]);
+    }
+
     #[test]
     fn test_degree() {
         let mut graph = VowlGraph::new();
@@ -412,6 +871,51 @@ mod tests {
         assert_eq!(degree, 2);
     }
 
+    #[test]
+    fn test_degree_counts_incoming_edges() {
+        let mut graph = VowlGraph::new();
+        for id in ["hub", "a", "b", "c", "d"] {
+            graph.add_node(create_test_node(id, id)).unwrap();
+        }
+        for from in ["a", "b", "c"] {
+            graph
+                .add_edge(from, "hub", create_test_edge(&format!("{}-hub", from), ""))
+                .unwrap();
+        }
+        graph.add_edge("hub", "d", create_test_edge("hub-d", "")).unwrap();
+
+        assert_eq!(graph.degree("hub").unwrap(), 4);
+        assert_eq!(graph.degree("a").unwrap(), 1);
+        assert_eq!(graph.metadata().max_degree, 4);
+    }
+
+    #[test]
+    fn test_neighbors_with_edges() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(create_test_node("person", "Person")).unwrap();
+        graph.add_node(create_test_node("org", "Organization")).unwrap();
+        graph.add_node(create_test_node("city", "City")).unwrap();
+        graph
+            .add_edge("person", "org", create_test_edge("worksFor", "works for"))
+            .unwrap();
+        graph
+            .add_edge("person", "city", create_test_edge("livesIn", "lives in"))
+            .unwrap();
+
+        let pairs = graph.neighbors_with_edges("person").unwrap();
+        assert_eq!(pairs.len(), 2);
+        for (edge, node) in pairs {
+            match node.id.as_str() {
+                "org" => assert_eq!(edge.label, "works for"),
+                "city" => assert_eq!(edge.label, "lives in"),
+                other => panic!("unexpected neighbor {}", other),
+            }
+        }
+
+        assert!(graph.neighbors_with_edges("org").unwrap().is_empty());
+        assert!(graph.neighbors_with_edges("missing").is_err());
+    }
+
     #[test]
     fn test_update_metadata() {
         let mut graph = VowlGraph::new();
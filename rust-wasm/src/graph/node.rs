@@ -49,18 +49,42 @@ impl NodeBuilder {
         self
     }
 
+    /// Add a label for a language tag
+    pub fn localized_label(mut self, lang: impl Into<String>, label: impl Into<String>) -> Self {
+        self.semantic.labels.insert(lang.into(), label.into());
+        self
+    }
+
+    /// Set the IDs of equivalent classes
+    pub fn equivalent(mut self, equivalent: Vec<String>) -> Self {
+        self.semantic.equivalent = equivalent;
+        self
+    }
+
     /// Set external flag
     pub fn external(mut self, external: bool) -> Self {
         self.semantic.external = external;
         self
     }
 
+    /// Set deprecated flag
+    pub fn deprecated(mut self, deprecated: bool) -> Self {
+        self.semantic.deprecated = deprecated;
+        self
+    }
+
     /// Set weight
     pub fn weight(mut self, weight: f64) -> Self {
         self.visual.weight = weight;
         self
     }
 
+    /// Set a per-node charge strength for the force layout
+    pub fn charge(mut self, charge: f64) -> Self {
+        self.visual.charge = Some(charge);
+        self
+    }
+
     /// Build the node
     pub fn build(self) -> Node {
         Node {
@@ -107,4 +131,15 @@ mod tests {
         assert!(node.semantic.external);
         assert_eq!(node.semantic.iri, "http://example.org/External");
     }
+
+    #[test]
+    fn test_node_builder_localized_labels() {
+        let node = NodeBuilder::new("person")
+            .localized_label("en", "Person")
+            .localized_label("fr", "Personne")
+            .build();
+
+        assert_eq!(node.semantic.labels.len(), 2);
+        assert_eq!(node.semantic.labels["fr"], "Personne");
+    }
 }
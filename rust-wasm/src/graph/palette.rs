@@ -0,0 +1,113 @@
+//! Per-namespace node colors for multi-ontology views
+
+use crate::ontology::Namespace;
+
+/// Saturation of namespace colors, in [0, 1]
+const SATURATION: f64 = 0.6;
+
+/// Lightness of namespace colors, light enough for dark labels
+const LIGHTNESS: f64 = 0.7;
+
+/// Namespace IRI an entity IRI belongs to
+///
+/// A full IRI belongs to the longest declared namespace it starts with, a
+/// CURIE to the namespace of its prefix. The prefix itself stands in for
+/// undeclared prefixes. IRIs in no namespace give `None`.
+pub fn namespace_key<'a>(iri: &'a str, namespaces: &'a [Namespace]) -> Option<&'a str> {
+    if let Some(ns) = namespaces
+        .iter()
+        .filter(|ns| !ns.iri.is_empty() && iri.starts_with(&ns.iri))
+        .max_by_key(|ns| ns.iri.len())
+    {
+        return Some(&ns.iri);
+    }
+
+    let (prefix, rest) = iri.split_once(':')?;
+    if prefix.is_empty() || rest.starts_with("//") || prefix.contains(['/', '#']) {
+        return None;
+    }
+    Some(
+        namespaces
+            .iter()
+            .find(|ns| ns.prefix == prefix && !ns.iri.is_empty())
+            .map_or(prefix, |ns| ns.iri.as_str()),
+    )
+}
+
+/// Color of a namespace as `#RRGGBB`
+///
+/// The hue is an FNV-1a hash of the namespace IRI, so a namespace gets the
+/// same color in every document; saturation and lightness are fixed.
+pub fn namespace_color(namespace: &str) -> String {
+    let hash = namespace.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    let hue = (hash % 360) as f64;
+
+    let (r, g, b) = hsl_to_rgb(hue, SATURATION, LIGHTNESS);
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// Color of an entity by the namespace of its IRI, if it has one
+pub fn iri_color(iri: &str, namespaces: &[Namespace]) -> Option<String> {
+    namespace_key(iri, namespaces).map(namespace_color)
+}
+
+/// Convert a hue in degrees and saturation and lightness in [0, 1] to RGB
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (u8, u8, u8) {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let channel = |value: f64| ((value + m) * 255.0).round() as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn namespaces() -> Vec<Namespace> {
+        [("foaf", "http://xmlns.com/foaf/0.1/"), ("ex", "http://example.org/")]
+            .into_iter()
+            .map(|(prefix, iri)| Namespace {
+                prefix: prefix.to_string(),
+                iri: iri.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_namespace_key() {
+        let namespaces = namespaces();
+        let key = |iri| namespace_key(iri, &namespaces);
+
+        assert_eq!(key("http://xmlns.com/foaf/0.1/Person"), Some("http://xmlns.com/foaf/0.1/"));
+        assert_eq!(key("foaf:Agent"), Some("http://xmlns.com/foaf/0.1/"));
+        assert_eq!(key("dc:title"), Some("dc"));
+        assert_eq!(key("http://other.org/Thing"), None);
+        assert_eq!(key("Person"), None);
+    }
+
+    #[test]
+    fn test_iri_color() {
+        let namespaces = namespaces();
+        let color = |iri| iri_color(iri, &namespaces);
+
+        let person = color("foaf:Person").unwrap();
+        assert!(person.starts_with('#') && person.len() == 7);
+        assert_eq!(color("http://xmlns.com/foaf/0.1/Agent"), Some(person.clone()));
+        assert_ne!(color("ex:Person"), Some(person));
+        assert_eq!(color("Person"), None);
+        assert_eq!(hsl_to_rgb(0.0, 1.0, 0.5), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(240.0, 1.0, 0.5), (0, 0, 255));
+    }
+}
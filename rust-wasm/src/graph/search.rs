@@ -0,0 +1,144 @@
+//! Label search and translation coverage
+
+use super::{NodeType, VowlGraph};
+use serde::{Deserialize, Serialize};
+
+/// What `search` matches against and how many results it returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SearchOptions {
+    /// Also match against node IRIs
+    pub match_iri: bool,
+
+    /// Maximum number of results (`None` for all)
+    pub limit: Option<usize>,
+}
+
+impl VowlGraph {
+    /// Find nodes whose label matches a query
+    ///
+    /// Matching is a case-insensitive substring test against the display
+    /// label and every language-tagged label, plus the IRI with
+    /// `match_iri`. Returns matching node IDs in graph order.
+    pub fn search(&self, query: &str, opts: SearchOptions) -> Vec<String> {
+        let query = query.to_lowercase();
+
+        self.graph
+            .node_weights()
+            .filter(|node| {
+                let iri = opts.match_iri.then_some(&node.semantic.iri);
+                std::iter::once(&node.label)
+                    .chain(node.semantic.labels.values())
+                    .chain(iri)
+                    .any(|text| text.to_lowercase().contains(&query))
+            })
+            .take(opts.limit.unwrap_or(usize::MAX))
+            .map(|node| node.id.clone())
+            .collect()
+    }
+
+    /// List classes that have no label for the given language tag
+    pub fn missing_label_language(&self, lang: &str) -> Vec<String> {
+        self.graph
+            .node_weights()
+            .filter(|node| matches!(node.node_type, NodeType::Class))
+            .filter(|node| !node.semantic.labels.contains_key(lang))
+            .map(|node| node.id.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::node::NodeBuilder;
+
+    fn create_multilingual_graph() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(
+                NodeBuilder::new("person")
+                    .label("Person")
+                    .localized_label("en", "Person")
+                    .localized_label("fr", "Personne")
+                    .build(),
+            )
+            .unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("city")
+                    .label("City")
+                    .localized_label("en", "City")
+                    .localized_label("de", "Stadt")
+                    .build(),
+            )
+            .unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("string")
+                    .node_type(NodeType::Datatype)
+                    .build(),
+            )
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_search_matches_any_language() {
+        let graph = create_multilingual_graph();
+
+        let opts = SearchOptions::default();
+        assert_eq!(graph.search("stadt", opts), vec!["city"]);
+        assert_eq!(graph.search("PERSONNE", opts), vec!["person"]);
+        assert_eq!(graph.search("person", opts), vec!["person"]);
+        assert!(graph.search("ville", opts).is_empty());
+    }
+
+    #[test]
+    fn test_search_partial_match_ignores_case() {
+        let graph = create_multilingual_graph();
+
+        assert_eq!(graph.search("erSo", SearchOptions::default()), vec!["person"]);
+        assert_eq!(graph.search("IT", SearchOptions::default()), vec!["city"]);
+    }
+
+    #[test]
+    fn test_search_matches_iri_when_enabled() {
+        let mut graph = create_multilingual_graph();
+        graph.get_node_mut("city").unwrap().semantic.iri = "http://schema.org/Place".to_string();
+
+        assert!(graph.search("schema.org", SearchOptions::default()).is_empty());
+        let opts = SearchOptions {
+            match_iri: true,
+            ..Default::default()
+        };
+        assert_eq!(graph.search("SCHEMA.org", opts), vec!["city"]);
+    }
+
+    #[test]
+    fn test_search_respects_limit() {
+        let graph = create_multilingual_graph();
+
+        // The datatype node's label defaults to its ID, so "" matches all three
+        assert_eq!(graph.search("", SearchOptions::default()).len(), 3);
+        let opts = SearchOptions {
+            limit: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(graph.search("", opts), vec!["person", "city"]);
+        let none = SearchOptions {
+            limit: Some(0),
+            ..Default::default()
+        };
+        assert!(graph.search("", none).is_empty());
+    }
+
+    #[test]
+    fn test_missing_label_language() {
+        let graph = create_multilingual_graph();
+
+        assert_eq!(graph.missing_label_language("fr"), vec!["city"]);
+        assert_eq!(graph.missing_label_language("de"), vec!["person"]);
+        assert!(graph.missing_label_language("en").is_empty());
+    }
+}
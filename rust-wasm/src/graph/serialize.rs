@@ -0,0 +1,222 @@
+//! Graph persistence
+//!
+//! Graphs are saved as a flat list of nodes plus edges with their endpoint
+//! IDs, so structure and computed positions can be restored without
+//! re-running the layout.
+
+use super::{Edge, Node, VowlGraph};
+use crate::ontology::OntologyMetadata;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+
+/// Serializable snapshot of graph structure and positions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphSnapshot {
+    /// All nodes including visual attributes
+    pub nodes: Vec<Node>,
+
+    /// Edges as `(source_id, edge, target_id)`
+    pub edges: Vec<(String, Edge, String)>,
+
+    /// Header of the source ontology
+    #[serde(default)]
+    pub ontology: Option<OntologyMetadata>,
+}
+
+impl GraphSnapshot {
+    /// Capture a snapshot of a graph
+    pub fn from_graph(graph: &VowlGraph) -> Self {
+        Self {
+            nodes: graph.nodes().into_iter().cloned().collect(),
+            edges: graph
+                .edge_endpoints()
+                .into_iter()
+                .map(|(from, edge, to)| (from.to_string(), edge.clone(), to.to_string()))
+                .collect(),
+            ontology: graph.ontology_metadata().cloned(),
+        }
+    }
+
+    /// Rebuild a graph from this snapshot
+    pub fn into_graph(self) -> Result<VowlGraph> {
+        let mut graph = VowlGraph::new();
+
+        for node in self.nodes {
+            graph.add_node(node)?;
+        }
+
+        for (from, edge, to) in self.edges {
+            graph.add_edge(&from, &to, edge)?;
+        }
+
+        graph.set_ontology_metadata(self.ontology);
+        Ok(graph)
+    }
+}
+
+impl VowlGraph {
+    /// Serialize the graph to JSON
+    ///
+    /// Metadata is derived from the structure and recomputed on load.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(&GraphSnapshot::from_graph(self))
+            .map_err(|e| crate::VowlError::GraphError(format!("Failed to encode graph: {}", e)))
+    }
+
+    /// Restore a graph from JSON produced by [`VowlGraph::to_json`]
+    pub fn from_json(json: &str) -> Result<Self> {
+        let snapshot: GraphSnapshot = serde_json::from_str(json)?;
+        snapshot.into_graph()
+    }
+}
+
+/// Leading byte of the binary format
+///
+/// Postcard is not self-describing, so unlike JSON a snapshot with an added
+/// field cannot be read by defaulting it. Bump this whenever `GraphSnapshot`
+/// or anything it contains changes shape.
+#[cfg(feature = "binary")]
+const BINARY_VERSION: u8 = 1;
+
+#[cfg(feature = "binary")]
+impl VowlGraph {
+    /// Serialize the graph to a compact binary format
+    ///
+    /// The first byte is the format version; [`VowlGraph::from_bytes`]
+    /// rejects other versions.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        postcard::to_extend(&GraphSnapshot::from_graph(self), vec![BINARY_VERSION])
+            .map_err(|e| crate::VowlError::GraphError(format!("Failed to encode graph: {}", e)))
+    }
+
+    /// Restore a graph from bytes produced by [`VowlGraph::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (version, body) = bytes
+            .split_first()
+            .ok_or_else(|| crate::VowlError::ParseError("Empty graph data".to_string()))?;
+        if *version != BINARY_VERSION {
+            return Err(crate::VowlError::ParseError(format!(
+                "Unsupported graph format version {}, expected {}",
+                version, BINARY_VERSION
+            )));
+        }
+
+        let snapshot: GraphSnapshot = postcard::from_bytes(body)
+            .map_err(|e| crate::VowlError::ParseError(format!("Failed to decode graph: {}", e)))?;
+
+        snapshot.into_graph()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder, NodeType};
+
+    fn create_test_graph() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(1.5, -2.25).build())
+            .unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("b")
+                    .node_type(NodeType::Datatype)
+                    .position(40.0, 12.5)
+                    .build(),
+            )
+            .unwrap();
+        graph
+            .add_edge("a", "b", EdgeBuilder::new("ab").functional().build())
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let graph = create_test_graph();
+        let restored = GraphSnapshot::from_graph(&graph).into_graph().unwrap();
+
+        assert_eq!(restored.node_count(), 2);
+        assert_eq!(restored.edge_count(), 1);
+        assert_eq!(restored.get_node("b"), graph.get_node("b"));
+    }
+
+    #[test]
+    fn test_snapshot_keeps_ontology_metadata() {
+        let mut graph = create_test_graph();
+        let metadata = OntologyMetadata {
+            iri: "http://example.org/onto".to_string(),
+            version: Some("2.0".to_string()),
+            title: Some("Example".to_string()),
+            description: None,
+        };
+        graph.set_ontology_metadata(Some(metadata.clone()));
+
+        let restored = VowlGraph::from_json(&graph.to_json().unwrap()).unwrap();
+        assert_eq!(restored.ontology_metadata(), Some(&metadata));
+
+        // Snapshots from before the header was saved still load
+        let legacy = r#"{"nodes": [], "edges": []}"#;
+        assert_eq!(VowlGraph::from_json(legacy).unwrap().ontology_metadata(), None);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut graph = create_test_graph();
+        // Positions a simulation would produce are not short decimals
+        graph.set_position("a", 1.0 / 3.0, std::f64::consts::PI * 1e5).unwrap();
+
+        let restored = VowlGraph::from_json(&graph.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.nodes(), graph.nodes());
+        assert_eq!(restored.edge_endpoints(), graph.edge_endpoints());
+        assert_eq!(restored.metadata().class_count, graph.metadata().class_count);
+    }
+
+    #[test]
+    fn test_json_rejects_unknown_endpoint() {
+        let json = create_test_graph().to_json().unwrap();
+        let dangling = json.replace(r#","b"]"#, r#","missing"]"#);
+
+        assert_ne!(dangling, json);
+        assert!(VowlGraph::from_json(&dangling).is_err());
+        assert!(VowlGraph::from_json("not json").is_err());
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_binary_round_trip() {
+        let graph = create_test_graph();
+        let bytes = graph.to_bytes().unwrap();
+        let restored = VowlGraph::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.node_count(), graph.node_count());
+        assert_eq!(restored.edge_count(), graph.edge_count());
+        assert_eq!(restored.positions(), graph.positions());
+
+        let (from, edge, to) = restored.edge_endpoints()[0];
+        assert_eq!((from, to), ("a", "b"));
+        assert!(edge.characteristics.functional);
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_binary_rejects_garbage() {
+        assert!(VowlGraph::from_bytes(&[0xff, 0xff, 0xff]).is_err());
+        assert!(VowlGraph::from_bytes(&[]).is_err());
+    }
+
+    #[cfg(feature = "binary")]
+    #[test]
+    fn test_binary_rejects_other_versions() {
+        let mut bytes = create_test_graph().to_bytes().unwrap();
+        assert_eq!(bytes[0], BINARY_VERSION);
+
+        bytes[0] = BINARY_VERSION + 1;
+        match VowlGraph::from_bytes(&bytes) {
+            Err(crate::VowlError::ParseError(msg)) => assert!(msg.contains("version"), "{}", msg),
+            other => panic!("unexpected result: {:?}", other.map(|g| g.node_count())),
+        }
+    }
+}
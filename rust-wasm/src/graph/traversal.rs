@@ -0,0 +1,657 @@
+//! Graph traversal queries
+
+use super::{Edge, EdgeType, Node, VowlGraph};
+use crate::Result;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::EdgeRef;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// Edge direction followed during traversal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Follow edges from source to target
+    Directed,
+
+    /// Follow edges from target to source
+    Reverse,
+
+    /// Follow edges in either direction
+    #[default]
+    Undirected,
+}
+
+impl VowlGraph {
+    /// Get indices adjacent to a node in the given direction
+    fn adjacent(&self, idx: NodeIndex, direction: Direction) -> Vec<NodeIndex> {
+        match direction {
+            Direction::Directed => self
+                .graph
+                .neighbors_directed(idx, petgraph::Direction::Outgoing)
+                .collect(),
+            Direction::Reverse => self
+                .graph
+                .neighbors_directed(idx, petgraph::Direction::Incoming)
+                .collect(),
+            Direction::Undirected => self.graph.neighbors_undirected(idx).collect(),
+        }
+    }
+
+    /// Get neighbors of a node following the given direction
+    pub fn neighbors_directed(&self, id: &str, direction: Direction) -> Result<Vec<&Node>> {
+        let idx = self.index_of(id)?;
+
+        Ok(self
+            .adjacent(idx, direction)
+            .into_iter()
+            .filter_map(|n| self.graph.node_weight(n))
+            .collect())
+    }
+
+    /// Breadth-first traversal from a node
+    ///
+    /// Returns `(node_id, hop_distance)` pairs in visiting order, stopping at
+    /// `max_depth` hops when given. The start node is included at distance 0.
+    pub fn bfs(
+        &self,
+        id: &str,
+        max_depth: Option<usize>,
+        direction: Direction,
+    ) -> Result<Vec<(String, usize)>> {
+        let start = self.index_of(id)?;
+
+        Ok(self
+            .bfs_parents(start, max_depth, direction)
+            .0
+            .into_iter()
+            .map(|(idx, depth)| (self.graph[idx].id.clone(), depth))
+            .collect())
+    }
+
+    /// Find the shortest hop path between two nodes
+    ///
+    /// Returns `Ok(None)` when `to` is unreachable from `from`.
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+        direction: Direction,
+    ) -> Result<Option<Vec<String>>> {
+        let start = self.index_of(from)?;
+        let goal = self.index_of(to)?;
+
+        let (_, parents) = self.bfs_parents(start, None, direction);
+        if start != goal && !parents.contains_key(&goal) {
+            return Ok(None);
+        }
+
+        let mut path = vec![self.graph[goal].id.clone()];
+        let mut current = goal;
+        while let Some(&parent) = parents.get(&current) {
+            path.push(self.graph[parent].id.clone());
+            current = parent;
+        }
+        path.reverse();
+
+        Ok(Some(path))
+    }
+
+    /// Find the cheapest path between two nodes under per-edge costs
+    ///
+    /// Edges are followed in either direction. `weight_fn` must return
+    /// non-negative costs. Returns `Ok(None)` when the nodes are not
+    /// connected.
+    pub fn weighted_path(
+        &self,
+        from: &str,
+        to: &str,
+        weight_fn: impl Fn(&Edge) -> f64,
+    ) -> Result<Option<Vec<String>>> {
+        let start = self.index_of(from)?;
+        let goal = self.index_of(to)?;
+
+        let mut costs = HashMap::from([(start, 0.0)]);
+        let mut parents = HashMap::new();
+        let mut heap = BinaryHeap::from([Visit {
+            cost: 0.0,
+            node: start,
+        }]);
+
+        while let Some(Visit { cost, node }) = heap.pop() {
+            if node == goal {
+                break;
+            }
+            if costs.get(&node).is_some_and(|&best| cost > best) {
+                continue;
+            }
+
+            let outgoing = self.graph.edges_directed(node, petgraph::Direction::Outgoing);
+            let incoming = self.graph.edges_directed(node, petgraph::Direction::Incoming);
+            for edge in outgoing.chain(incoming) {
+                let next = if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                let next_cost = cost + weight_fn(edge.weight());
+                if costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                    continue;
+                }
+                costs.insert(next, next_cost);
+                parents.insert(next, node);
+                heap.push(Visit {
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+
+        if !costs.contains_key(&goal) {
+            return Ok(None);
+        }
+
+        let mut path = vec![self.graph[goal].id.clone()];
+        let mut current = goal;
+        while let Some(&parent) = parents.get(&current) {
+            path.push(self.graph[parent].id.clone());
+            current = parent;
+        }
+        path.reverse();
+
+        Ok(Some(path))
+    }
+
+    /// Get the IDs of all nodes within `depth` hops of a node
+    pub fn ego_network(&self, id: &str, depth: usize, direction: Direction) -> Result<Vec<String>> {
+        Ok(self
+            .bfs(id, Some(depth), direction)?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect())
+    }
+
+    /// Extract the subgraph within `depth` hops of a node
+    ///
+    /// Edges count in either direction. The result holds copies of the
+    /// reached nodes and of every edge between them, with all attributes.
+    pub fn neighborhood(&self, id: &str, depth: usize) -> Result<VowlGraph> {
+        let reached: HashSet<String> = self
+            .ego_network(id, depth, Direction::Undirected)?
+            .into_iter()
+            .collect();
+
+        let mut view = self.retain_view(|node| reached.contains(&node.id), |_| true);
+        view.update_metadata();
+        Ok(view)
+    }
+
+    /// Group node IDs by weakly connected component
+    ///
+    /// Edge direction is ignored. Components are ordered by their first node
+    /// in insertion order, and isolated nodes form their own component.
+    pub fn connected_components(&self) -> Vec<Vec<String>> {
+        let mut seen = HashSet::new();
+        let mut components = Vec::new();
+
+        for start in self.graph.node_indices() {
+            if !seen.insert(start) {
+                continue;
+            }
+
+            let (order, _) = self.bfs_parents(start, None, Direction::Undirected);
+            components.push(
+                order
+                    .into_iter()
+                    .map(|(idx, _)| {
+                        seen.insert(idx);
+                        self.graph[idx].id.clone()
+                    })
+                    .collect(),
+            );
+        }
+
+        components
+    }
+
+    /// Get the diameter of the largest weakly connected component
+    ///
+    /// The diameter is the longest shortest hop path, ignoring edge
+    /// direction. Ties between equally large components go to the first one.
+    /// Returns `None` for an empty graph. A breadth-first search runs from
+    /// every node of the component, so the result is cached in the metadata
+    /// until nodes or edges are added or removed.
+    pub fn diameter(&self) -> Option<usize> {
+        *self.metadata.diameter.get_or_init(|| self.largest_component_diameter())
+    }
+
+    /// Compute the diameter of the largest weakly connected component
+    fn largest_component_diameter(&self) -> Option<usize> {
+        let largest = self
+            .connected_components()
+            .into_iter()
+            .rev()
+            .max_by_key(|component| component.len())?;
+
+        largest
+            .iter()
+            .map(|id| {
+                let (order, _) = self.bfs_parents(self.node_map[id], None, Direction::Undirected);
+                order.into_iter().map(|(_, depth)| depth).max().unwrap_or(0)
+            })
+            .max()
+    }
+
+    /// Get the number of weakly connected components
+    pub fn component_count(&self) -> usize {
+        self.connected_components().len()
+    }
+
+    /// Check whether `rdfs:subClassOf` edges form a cycle
+    pub fn has_subclass_cycle(&self) -> bool {
+        !self.find_subclass_cycles().is_empty()
+    }
+
+    /// Find cycles among `rdfs:subClassOf` edges
+    ///
+    /// Each cycle is listed as node IDs in edge order, starting at the node
+    /// where the DFS re-entered it; the closing edge back to the first node is
+    /// implied. Other edge types are ignored.
+    pub fn find_subclass_cycles(&self) -> Vec<Vec<String>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+
+        let superclasses = |idx: NodeIndex| {
+            self.graph
+                .edges(idx)
+                .filter(|edge| edge.weight().edge_type == EdgeType::SubClass)
+                .map(|edge| edge.target())
+        };
+
+        let mut state = HashMap::new();
+        let mut cycles = Vec::new();
+        for root in self.graph.node_indices() {
+            if state.contains_key(&root) {
+                continue;
+            }
+
+            // The current DFS path, each node with its edges still to follow;
+            // an explicit stack so deep hierarchies cannot overflow the call stack
+            let mut stack = vec![(root, superclasses(root))];
+            state.insert(root, State::OnStack);
+            while let Some((idx, targets)) = stack.last_mut() {
+                let Some(next) = targets.next() else {
+                    state.insert(*idx, State::Done);
+                    stack.pop();
+                    continue;
+                };
+
+                match state.get(&next).copied().unwrap_or(State::Unvisited) {
+                    State::Unvisited => {
+                        state.insert(next, State::OnStack);
+                        stack.push((next, superclasses(next)));
+                    }
+                    State::OnStack => {
+                        let start = stack.iter().position(|(i, _)| *i == next).unwrap_or(0);
+                        cycles.push(
+                            stack[start..]
+                                .iter()
+                                .map(|(i, _)| self.graph[*i].id.clone())
+                                .collect(),
+                        );
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        cycles
+    }
+
+    /// Run BFS returning the visit order with depths and the parent map
+    fn bfs_parents(
+        &self,
+        start: NodeIndex,
+        max_depth: Option<usize>,
+        direction: Direction,
+    ) -> (Vec<(NodeIndex, usize)>, HashMap<NodeIndex, NodeIndex>) {
+        let mut order = vec![(start, 0)];
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((idx, depth)) = queue.pop_front() {
+            if max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+
+            for next in self.adjacent(idx, direction) {
+                if next == start || parents.contains_key(&next) {
+                    continue;
+                }
+                parents.insert(next, idx);
+                order.push((next, depth + 1));
+                queue.push_back((next, depth + 1));
+            }
+        }
+
+        (order, parents)
+    }
+}
+
+/// Default cost of following an edge in `VowlGraph::weighted_path`
+///
+/// Subclass edges are cheap and annotation edges expensive, so paths prefer
+/// the class hierarchy and object properties over annotations.
+pub fn default_edge_cost(edge: &Edge) -> f64 {
+    match edge.edge_type {
+        EdgeType::SubClass => 0.5,
+        EdgeType::Annotation => 5.0,
+        _ => 1.0,
+    }
+}
+
+/// Node queued for expansion in `VowlGraph::weighted_path`
+///
+/// Ordered by lowest cost first so `BinaryHeap` pops the cheapest node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Visit {
+    cost: f64,
+    node: NodeIndex,
+}
+
+impl Eq for Visit {}
+
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    /// Directed chain a -> b -> c
+    fn create_chain() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.add_edge("b", "c", EdgeBuilder::new("bc").build()).unwrap();
+        graph
+    }
+
+    fn ids(nodes: Vec<&Node>) -> Vec<String> {
+        nodes.into_iter().map(|n| n.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_neighbors_by_direction() {
+        let graph = create_chain();
+
+        assert_eq!(ids(graph.neighbors_directed("b", Direction::Directed).unwrap()), vec!["c"]);
+        assert_eq!(ids(graph.neighbors_directed("b", Direction::Reverse).unwrap()), vec!["a"]);
+        assert_eq!(graph.neighbors_directed("b", Direction::Undirected).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_shortest_path_by_direction() {
+        let graph = create_chain();
+
+        assert_eq!(
+            graph.shortest_path("a", "c", Direction::Directed).unwrap(),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(graph.shortest_path("c", "a", Direction::Directed).unwrap(), None);
+        assert_eq!(
+            graph.shortest_path("c", "a", Direction::Reverse).unwrap(),
+            Some(vec!["c".to_string(), "b".to_string(), "a".to_string()])
+        );
+        assert_eq!(graph.shortest_path("a", "c", Direction::Reverse).unwrap(), None);
+        assert!(graph
+            .shortest_path("c", "a", Direction::Undirected)
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_shortest_path_undirected() {
+        let mut graph = create_chain();
+        graph.add_node(NodeBuilder::new("d").build()).unwrap();
+
+        assert_eq!(
+            graph.shortest_path("b", "a", Direction::Undirected).unwrap(),
+            Some(vec!["b".to_string(), "a".to_string()])
+        );
+        assert_eq!(
+            graph.shortest_path("c", "a", Direction::Undirected).unwrap(),
+            Some(vec!["c".to_string(), "b".to_string(), "a".to_string()])
+        );
+        assert_eq!(graph.shortest_path("a", "d", Direction::Undirected).unwrap(), None);
+    }
+
+    #[test]
+    fn test_weighted_path_avoids_annotation_edges() {
+        let mut graph = VowlGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        let annotation = EdgeBuilder::new("ad").edge_type(EdgeType::Annotation).build();
+        graph.add_edge("a", "d", annotation).unwrap();
+        for (from, to) in [("a", "b"), ("b", "c"), ("d", "c")] {
+            let edge = EdgeBuilder::new(format!("{}{}", from, to))
+                .edge_type(EdgeType::ObjectProperty)
+                .build();
+            graph.add_edge(from, to, edge).unwrap();
+        }
+
+        let by_hops = graph.shortest_path("a", "d", Direction::Undirected).unwrap();
+        assert_eq!(by_hops, Some(vec!["a".to_string(), "d".to_string()]));
+
+        let weighted = graph.weighted_path("a", "d", default_edge_cost).unwrap();
+        let expected = ["a", "b", "c", "d"].map(String::from).to_vec();
+        assert_eq!(weighted, Some(expected));
+
+        // Uniform costs fall back to the fewest hops
+        let uniform = graph.weighted_path("a", "d", |_| 1.0).unwrap();
+        assert_eq!(uniform, by_hops);
+
+        graph.add_node(NodeBuilder::new("island").build()).unwrap();
+        assert_eq!(graph.weighted_path("a", "island", default_edge_cost).unwrap(), None);
+        assert!(graph.weighted_path("a", "missing", default_edge_cost).is_err());
+    }
+
+    #[test]
+    fn test_shortest_path_missing_node() {
+        let graph = create_chain();
+        assert!(graph.shortest_path("a", "missing", Direction::default()).is_err());
+    }
+
+    #[test]
+    fn test_ego_network_by_direction() {
+        let graph = create_chain();
+
+        assert_eq!(graph.ego_network("a", 5, Direction::Directed).unwrap().len(), 3);
+        assert_eq!(graph.ego_network("a", 5, Direction::Reverse).unwrap(), vec!["a"]);
+        assert_eq!(graph.ego_network("b", 1, Direction::Undirected).unwrap().len(), 3);
+        assert_eq!(graph.ego_network("a", 1, Direction::Undirected).unwrap(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_neighborhood_by_depth() {
+        let mut graph = create_chain();
+        graph.add_node(NodeBuilder::new("d").build()).unwrap();
+        graph.set_position("a", 5.0, 7.0).unwrap();
+
+        let own = graph.neighborhood("a", 0).unwrap();
+        assert_eq!(own.node_count(), 1);
+        assert_eq!(own.edge_count(), 0);
+        assert_eq!(own.get_node("a").unwrap().visual.x, 5.0);
+
+        // Reached against the edge direction
+        let near = graph.neighborhood("b", 1).unwrap();
+        assert_eq!(near.node_count(), 3);
+        assert_eq!(near.edge_count(), 2);
+        let one_hop = graph.neighborhood("c", 1).unwrap();
+        assert!(one_hop.get_node("b").is_some());
+        assert!(one_hop.get_node("a").is_none());
+        assert_eq!(one_hop.edge_count(), 1);
+
+        // Deeper than the diameter yields the component, not the isolated node
+        let whole = graph.neighborhood("c", 10).unwrap();
+        assert_eq!(whole.node_count(), 3);
+        assert_eq!(whole.edge_count(), 2);
+        assert!(whole.get_node("d").is_none());
+
+        assert!(graph.neighborhood("missing", 1).is_err());
+    }
+
+    #[test]
+    fn test_diameter_of_path() {
+        let mut graph = create_chain();
+        assert_eq!(graph.diameter(), Some(2));
+
+        graph.add_node(NodeBuilder::new("d").build()).unwrap();
+        graph.add_edge("d", "c", EdgeBuilder::new("dc").build()).unwrap();
+        assert_eq!(graph.diameter(), Some(3));
+        assert_eq!(graph.metadata().diameter.get(), Some(&Some(3)));
+
+        graph.remove_node("d").unwrap();
+        assert_eq!(graph.metadata().diameter.get(), None);
+        assert_eq!(graph.diameter(), Some(2));
+
+        assert_eq!(VowlGraph::new().diameter(), None);
+    }
+
+    #[test]
+    fn test_diameter_of_star() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("hub").build()).unwrap();
+        for leaf in ["a", "b", "c", "d"] {
+            graph.add_node(NodeBuilder::new(leaf).build()).unwrap();
+            let edge = EdgeBuilder::new(format!("hub-{}", leaf)).build();
+            graph.add_edge("hub", leaf, edge).unwrap();
+        }
+
+        assert_eq!(graph.diameter(), Some(2));
+    }
+
+    #[test]
+    fn test_diameter_uses_largest_component() {
+        let mut graph = create_chain();
+        for id in ["x", "y"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        graph.add_edge("x", "y", EdgeBuilder::new("xy").build()).unwrap();
+
+        assert_eq!(graph.diameter(), Some(2));
+    }
+
+    #[test]
+    fn test_connected_graph_has_one_component() {
+        let graph = create_chain();
+
+        let components = graph.connected_components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), 3);
+        assert_eq!(graph.component_count(), 1);
+    }
+
+    #[test]
+    fn test_disjoint_components() {
+        let mut graph = VowlGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.add_edge("d", "c", EdgeBuilder::new("dc").build()).unwrap();
+
+        let components = graph.connected_components();
+        assert_eq!(
+            components,
+            vec![vec!["a", "b"], vec!["c", "d"], vec!["e"], vec!["f"]]
+        );
+        assert_eq!(graph.component_count(), 4);
+    }
+
+    fn create_hierarchy(edges: &[(&str, &str)]) -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for id in ["animal", "mammal", "dog"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        for (from, to) in edges {
+            let edge = EdgeBuilder::new(format!("{}-{}", from, to))
+                .edge_type(EdgeType::SubClass)
+                .build();
+            graph.add_edge(from, to, edge).unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_acyclic_hierarchy() {
+        let mut graph = create_hierarchy(&[("dog", "mammal"), ("mammal", "animal")]);
+        // A cycle through a non-subclass edge does not count
+        graph.add_edge("animal", "dog", EdgeBuilder::new("eats").build()).unwrap();
+
+        assert!(!graph.has_subclass_cycle());
+        assert!(graph.find_subclass_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_three_class_cycle() {
+        let graph =
+            create_hierarchy(&[("dog", "mammal"), ("mammal", "animal"), ("animal", "dog")]);
+
+        assert!(graph.has_subclass_cycle());
+        assert_eq!(graph.find_subclass_cycles(), vec![vec!["animal", "dog", "mammal"]]);
+    }
+
+    #[test]
+    fn test_deep_hierarchy_cycle() {
+        let mut graph = VowlGraph::new();
+        let depth = 100_000;
+        for i in 0..depth {
+            graph.add_node(NodeBuilder::new(format!("c{}", i)).build()).unwrap();
+        }
+        for i in 0..depth {
+            let (from, to) = (format!("c{}", i), format!("c{}", (i + 1) % depth));
+            let edge = EdgeBuilder::new(format!("{}-{}", from, to))
+                .edge_type(EdgeType::SubClass)
+                .build();
+            graph.add_edge(&from, &to, edge).unwrap();
+        }
+
+        let cycles = graph.find_subclass_cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].len(), depth);
+    }
+
+    #[test]
+    fn test_bfs_depths() {
+        let graph = create_chain();
+        let visited = graph.bfs("a", None, Direction::Directed).unwrap();
+
+        assert_eq!(
+            visited,
+            vec![
+                ("a".to_string(), 0),
+                ("b".to_string(), 1),
+                ("c".to_string(), 2)
+            ]
+        );
+    }
+}
@@ -1,6 +1,261 @@
 //! Force calculation utilities
 
+use super::quadtree::QuadTree;
+use crate::graph::{EdgeType, Node, VowlGraph};
 use nalgebra::Vector2;
+use std::collections::HashMap;
+
+/// A force contributing to node movement on each simulation tick
+///
+/// Implementations add their contribution for each node into `forces`,
+/// keyed by node ID. Custom forces can be registered with
+/// [`ForceSimulation::add_force`](super::simulation::ForceSimulation::add_force).
+///
+/// Hidden nodes, e.g. those collapsed away, neither exert nor receive forces:
+/// the built-in forces skip them and the simulation never moves them.
+pub trait Force {
+    /// Accumulate this force into the per-node force map
+    fn apply(&self, graph: &VowlGraph, forces: &mut HashMap<String, Vector2<f64>>, alpha: f64);
+}
+
+/// Repulsion between all nodes, approximated with a Barnes-Hut quadtree
+///
+/// Nodes with a [`charge`](crate::graph::VisualAttributes::charge) use it
+/// instead of `strength`; a pair of nodes repels with the mean of their charges.
+#[derive(Debug, Clone)]
+pub struct ManyBodyForce {
+    /// Default charge strength (negative repels)
+    pub strength: f64,
+
+    /// Barnes-Hut accuracy (0 computes every pair exactly)
+    pub theta: f64,
+}
+
+impl Force for ManyBodyForce {
+    fn apply(&self, graph: &VowlGraph, forces: &mut HashMap<String, Vector2<f64>>, _alpha: f64) {
+        let nodes: Vec<&Node> = graph.nodes().into_iter().filter(|n| !n.visual.hidden).collect();
+        let positions: Vec<Vector2<f64>> = nodes
+            .iter()
+            .map(|n| Vector2::new(n.visual.x, n.visual.y))
+            .collect();
+        let charges: Vec<f64> = nodes
+            .iter()
+            .map(|n| n.visual.charge.unwrap_or(self.strength))
+            .collect();
+        let tree = QuadTree::new(&positions, &charges);
+
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        let node_forces = self.parallel_forces(&tree, &positions, &charges);
+        #[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+        let node_forces = self.serial_forces(&tree, &positions, &charges);
+
+        for (node, force) in nodes.iter().zip(node_forces) {
+            *forces.entry(node.id.clone()).or_insert_with(Vector2::zeros) += force;
+        }
+    }
+}
+
+impl ManyBodyForce {
+    /// Repulsion on each node, in input order
+    #[cfg_attr(all(feature = "parallel", not(target_arch = "wasm32")), allow(dead_code))]
+    fn serial_forces(
+        &self,
+        tree: &QuadTree,
+        positions: &[Vector2<f64>],
+        charges: &[f64],
+    ) -> Vec<Vector2<f64>> {
+        (0..positions.len())
+            .map(|i| tree.repulsion(i, positions[i], charges[i], self.theta))
+            .collect()
+    }
+
+    /// Repulsion on each node computed across threads, in input order
+    ///
+    /// Every node's force is computed independently and collected in order,
+    /// so the result is bit-identical to [`Self::serial_forces`].
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    fn parallel_forces(
+        &self,
+        tree: &QuadTree,
+        positions: &[Vector2<f64>],
+        charges: &[f64],
+    ) -> Vec<Vector2<f64>> {
+        use rayon::prelude::*;
+
+        (0..positions.len())
+            .into_par_iter()
+            .map(|i| tree.repulsion(i, positions[i], charges[i], self.theta))
+            .collect()
+    }
+}
+
+/// Spring attraction between connected nodes
+#[derive(Debug, Clone)]
+pub struct LinkForce {
+    /// Target link distance
+    pub distance: f64,
+
+    /// Target distance per edge type, overriding `distance`
+    pub type_distances: HashMap<EdgeType, f64>,
+
+    /// Spring strength
+    pub strength: f64,
+}
+
+impl LinkForce {
+    /// Target distance for edges of the given type
+    pub fn distance_for(&self, edge_type: &EdgeType) -> f64 {
+        self.type_distances
+            .get(edge_type)
+            .copied()
+            .unwrap_or(self.distance)
+    }
+
+    /// Attraction on a node from each edge connecting it to another node
+    ///
+    /// Both incoming and outgoing edges count, one entry per edge.
+    pub fn contributions(
+        &self,
+        graph: &VowlGraph,
+        id: &str,
+        alpha: f64,
+    ) -> Vec<(String, Vector2<f64>)> {
+        let Some(node) = graph.get_node(id).filter(|n| !n.visual.hidden) else {
+            return Vec::new();
+        };
+
+        let pos1 = Vector2::new(node.visual.x, node.visual.y);
+        graph
+            .edge_endpoints()
+            .into_iter()
+            .filter_map(|(from, edge, to)| match (from == id, to == id) {
+                (true, false) => Some((to, edge)),
+                (false, true) => Some((from, edge)),
+                _ => None,
+            })
+            .filter_map(|(other, edge)| Some((graph.get_node(other)?, edge)))
+            .filter(|(neighbor, _)| !neighbor.visual.hidden)
+            .map(|(neighbor, edge)| {
+                let pos2 = Vector2::new(neighbor.visual.x, neighbor.visual.y);
+                let distance = self.distance_for(&edge.edge_type);
+                let force = calculate_attraction(pos1, pos2, distance, self.strength);
+                (neighbor.id.clone(), force * alpha)
+            })
+            .collect()
+    }
+}
+
+impl Force for LinkForce {
+    fn apply(&self, graph: &VowlGraph, forces: &mut HashMap<String, Vector2<f64>>, alpha: f64) {
+        for (from, edge, to) in graph.edge_endpoints() {
+            // Self-loops exert no pull
+            if from == to {
+                continue;
+            }
+            let (Some(source), Some(target)) = (graph.get_node(from), graph.get_node(to)) else {
+                continue;
+            };
+            if source.visual.hidden || target.visual.hidden {
+                continue;
+            }
+
+            let pos1 = Vector2::new(source.visual.x, source.visual.y);
+            let pos2 = Vector2::new(target.visual.x, target.visual.y);
+            let distance = self.distance_for(&edge.edge_type);
+            let force = calculate_attraction(pos1, pos2, distance, self.strength) * alpha;
+
+            // Equal and opposite pull on both endpoints
+            *forces.entry(source.id.clone()).or_insert_with(Vector2::zeros) += force;
+            *forces.entry(target.id.clone()).or_insert_with(Vector2::zeros) -= force;
+        }
+    }
+}
+
+/// Pull toward a center point
+#[derive(Debug, Clone)]
+pub struct CenterForce {
+    /// Center position
+    pub center: (f64, f64),
+
+    /// Pull strength
+    pub strength: f64,
+}
+
+impl Force for CenterForce {
+    fn apply(&self, graph: &VowlGraph, forces: &mut HashMap<String, Vector2<f64>>, _alpha: f64) {
+        let center = Vector2::new(self.center.0, self.center.1);
+
+        for node in graph.nodes().into_iter().filter(|n| !n.visual.hidden) {
+            let pos = Vector2::new(node.visual.x, node.visual.y);
+            let force = calculate_center_force(pos, center, self.strength);
+            *forces.entry(node.id.clone()).or_insert_with(Vector2::zeros) += force;
+        }
+    }
+}
+
+/// Pull each connected component toward its own sub-center
+///
+/// Sub-centers sit on a grid around `center`. Each grid cell is sized from
+/// the largest component so that components don't overlap, while the
+/// forces within a component act as usual.
+#[derive(Debug, Clone)]
+pub struct ComponentCenterForce {
+    /// Center of the grid
+    pub center: (f64, f64),
+
+    /// Pull strength
+    pub strength: f64,
+
+    /// Spacing unit for the grid, usually the link distance
+    pub spacing: f64,
+}
+
+impl ComponentCenterForce {
+    /// Sub-center of each component, in component order
+    pub fn sub_centers(&self, sizes: &[usize]) -> Vec<Vector2<f64>> {
+        let count = sizes.len();
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let largest = sizes.iter().copied().max().unwrap_or(1) as f64;
+        let cell = 2.0 * self.spacing * (largest.sqrt() + 1.0);
+        let cols = (count as f64).sqrt().ceil() as usize;
+        let rows = count.div_ceil(cols);
+
+        (0..count)
+            .map(|i| {
+                let col = (i % cols) as f64 - (cols - 1) as f64 / 2.0;
+                let row = (i / cols) as f64 - (rows - 1) as f64 / 2.0;
+                Vector2::new(self.center.0 + col * cell, self.center.1 + row * cell)
+            })
+            .collect()
+    }
+}
+
+impl Force for ComponentCenterForce {
+    fn apply(&self, graph: &VowlGraph, forces: &mut HashMap<String, Vector2<f64>>, _alpha: f64) {
+        let visible = |id: &String| graph.get_node(id).is_some_and(|n| !n.visual.hidden);
+        let components: Vec<Vec<String>> = graph
+            .connected_components()
+            .into_iter()
+            .map(|ids| ids.into_iter().filter(visible).collect::<Vec<_>>())
+            .filter(|ids| !ids.is_empty())
+            .collect();
+        let sizes: Vec<usize> = components.iter().map(Vec::len).collect();
+
+        for (ids, center) in components.iter().zip(self.sub_centers(&sizes)) {
+            for id in ids {
+                let Some(node) = graph.get_node(id) else {
+                    continue;
+                };
+                let pos = Vector2::new(node.visual.x, node.visual.y);
+                let force = calculate_center_force(pos, center, self.strength);
+                *forces.entry(id.clone()).or_insert_with(Vector2::zeros) += force;
+            }
+        }
+    }
+}
 
 /// Calculate repulsive force between two nodes (Coulomb's law)
 pub fn calculate_repulsion(
@@ -19,8 +274,8 @@ pub fn calculate_repulsion(
         return Vector2::new(offset_x, offset_y);
     }
 
-    // Normal repulsion force
-    let force_magnitude = strength / distance_sq;
+    // Negative strength pushes pos1 away from pos2
+    let force_magnitude = -strength / distance_sq;
     delta.normalize() * force_magnitude
 }
 
@@ -68,10 +323,33 @@ mod tests {
 
         // Force should point away from pos2 (negative strength means pos1 repels from pos2)
         // So force.x should be negative (pointing left, away from pos2)
-        assert!(force.x < 0.0 || force.x.abs() > 0.0); // Has meaningful force
+        assert!(force.x < 0.0);
         assert!(force.y.abs() < 0.01); // Nearly zero in Y direction
     }
 
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_parallel_forces_match_serial() {
+        // Deterministic scatter of nodes with mixed charges
+        let positions: Vec<Vector2<f64>> = (0..500)
+            .map(|i| {
+                let t = i as f64;
+                Vector2::new((t * 12.9898).sin() * 400.0, (t * 78.233).cos() * 300.0)
+            })
+            .collect();
+        let charges: Vec<f64> = (0..500).map(|i| -30.0 - (i % 7) as f64 * 10.0).collect();
+        let tree = QuadTree::new(&positions, &charges);
+        let force = ManyBodyForce {
+            strength: -30.0,
+            theta: 0.9,
+        };
+
+        assert_eq!(
+            force.parallel_forces(&tree, &positions, &charges),
+            force.serial_forces(&tree, &positions, &charges)
+        );
+    }
+
     #[test]
     fn test_attraction_force() {
         let pos1 = Vector2::new(0.0, 0.0);
@@ -85,6 +363,18 @@ mod tests {
         assert!(force.x > 0.0);
     }
 
+    #[test]
+    fn test_link_distance_for() {
+        let link = LinkForce {
+            distance: 30.0,
+            type_distances: HashMap::from([(EdgeType::SubClass, 10.0)]),
+            strength: 1.0,
+        };
+
+        assert_eq!(link.distance_for(&EdgeType::SubClass), 10.0);
+        assert_eq!(link.distance_for(&EdgeType::ObjectProperty), 30.0);
+    }
+
     #[test]
     fn test_center_force() {
         let pos = Vector2::new(100.0, 100.0);
@@ -109,6 +399,64 @@ mod tests {
         assert_eq!(new_velocity.y, 6.0);
     }
 
+    #[test]
+    fn test_per_node_charge_pushes_harder() {
+        use crate::graph::node::NodeBuilder;
+
+        let push_on_b = |a: NodeBuilder| {
+            let mut graph = VowlGraph::new();
+            graph.add_node(a.position(0.0, 0.0).build()).unwrap();
+            graph
+                .add_node(NodeBuilder::new("b").position(10.0, 0.0).build())
+                .unwrap();
+
+            let mut forces = HashMap::new();
+            let force = ManyBodyForce {
+                strength: -30.0,
+                theta: 0.9,
+            };
+            force.apply(&graph, &mut forces, 1.0);
+            forces["b"].x
+        };
+
+        let default_push = push_on_b(NodeBuilder::new("a"));
+        let strong_push = push_on_b(NodeBuilder::new("a").charge(-90.0));
+
+        // b is pushed away from a (positive x), twice as hard with mean charge -60
+        assert!(default_push > 0.0);
+        assert!((strong_push - 2.0 * default_push).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hidden_nodes_exert_no_force() {
+        use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").position(0.0, 0.0).build()).unwrap();
+        graph.add_node(NodeBuilder::new("b").position(10.0, 0.0).build()).unwrap();
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.get_node_mut("b").unwrap().visual.hidden = true;
+
+        let forces_on = |force: &dyn Force| {
+            let mut forces = HashMap::new();
+            force.apply(&graph, &mut forces, 1.0);
+            forces
+        };
+        let many_body = forces_on(&ManyBodyForce {
+            strength: -30.0,
+            theta: 0.0,
+        });
+        let link = forces_on(&LinkForce {
+            distance: 30.0,
+            type_distances: HashMap::new(),
+            strength: 1.0,
+        });
+
+        assert_eq!(many_body.get("a"), Some(&Vector2::zeros()));
+        assert!(!many_body.contains_key("b"));
+        assert!(link.is_empty());
+    }
+
     #[test]
     fn test_repulsion_at_same_position() {
         let pos = Vector2::new(5.0, 5.0);
@@ -4,10 +4,14 @@
 //! primarily force-directed layouts similar to D3.js force simulations.
 
 pub mod force;
+pub mod quadtree;
+pub mod radial;
 pub mod simulation;
+pub mod tree;
 
 use crate::Result;
-use crate::graph::VowlGraph;
+use crate::graph::{EdgeType, VowlGraph};
+use std::collections::HashMap;
 
 /// Trait for layout algorithms
 #[cfg_attr(test, mockall::automock)]
@@ -28,6 +32,30 @@ pub trait LayoutAlgorithm {
     fn alpha(&self) -> f64;
 }
 
+/// Strategy for placing nodes before the first simulation tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InitialLayout {
+    /// Place nodes evenly on a circle
+    #[default]
+    Circle,
+
+    /// Place nodes at the centroid of their already-placed neighbors,
+    /// processing high-degree nodes first
+    NeighborCentroid,
+
+    /// Place nodes on a roughly square lattice spaced by `link_distance`,
+    /// after auto-scaling
+    Grid,
+
+    /// Scatter nodes pseudo-randomly over the area the grid would cover.
+    /// The sequence is seeded, so the same graph always starts the same way
+    Random,
+}
+
+/// Former name of [`InitialLayout`]
+#[deprecated(note = "renamed to `InitialLayout`")]
+pub type InitStrategy = InitialLayout;
+
 /// Configuration for force-directed layout
 #[derive(Debug, Clone)]
 pub struct LayoutConfig {
@@ -46,17 +74,50 @@ pub struct LayoutConfig {
     /// Link distance
     pub link_distance: f64,
 
+    /// Link distance per edge type, overriding `link_distance`. Defaults
+    /// pull subclasses and datatypes closer than other properties, as VOWL
+    /// draws them
+    pub link_distances: HashMap<EdgeType, f64>,
+
     /// Link strength
     pub link_strength: f64,
 
     /// Charge strength (repulsion)
     pub charge_strength: f64,
 
+    /// Barnes-Hut accuracy for repulsion (0 = exact all-pairs)
+    pub theta: f64,
+
     /// Center force strength
     pub center_strength: f64,
 
     /// Center position
     pub center: (f64, f64),
+
+    /// Initial placement strategy
+    pub initial_layout: InitialLayout,
+
+    /// Box `(min_x, min_y, max_x, max_y)` that nodes are kept inside
+    pub bounds: Option<(f64, f64, f64, f64)>,
+
+    /// Finish early once no node moves further than this in a tick
+    /// (0 = only stop at `alpha_min`)
+    pub min_displacement: f64,
+
+    /// Consecutive ticks below `min_displacement` needed to finish, so a
+    /// single slow tick, e.g. at a velocity reversal, does not end the layout
+    pub convergence_ticks: usize,
+
+    /// Give each connected component its own sub-center on a grid
+    pub separate_components: bool,
+
+    /// Scale charge and link distances with the node count on initialize,
+    /// see [`LayoutConfig::force_scale`]
+    pub auto_scale_forces: bool,
+
+    /// Ticks after which a cooling simulation finishes regardless of alpha,
+    /// so a misconfigured decay cannot spin forever
+    pub max_iterations: usize,
 }
 
 impl Default for LayoutConfig {
@@ -67,14 +128,59 @@ impl Default for LayoutConfig {
             alpha_min: 0.001,
             velocity_decay: 0.6,
             link_distance: 30.0,
+            link_distances: HashMap::from([
+                (EdgeType::SubClass, 20.0),
+                (EdgeType::DatatypeProperty, 20.0),
+            ]),
             link_strength: 1.0,
             charge_strength: -30.0,
+            theta: 0.9,
             center_strength: 1.0,
             center: (0.0, 0.0),
+            initial_layout: InitialLayout::Circle,
+            bounds: None,
+            min_displacement: 0.0,
+            convergence_ticks: 5,
+            separate_components: false,
+            auto_scale_forces: false,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+impl LayoutConfig {
+    /// Force scale factor for a graph of `node_count` nodes
+    ///
+    /// `s = sqrt(max(n, 10) / 10)`, so graphs of up to 10 nodes keep the
+    /// configured forces. See [`LayoutConfig::scaled`] for how it applies.
+    pub fn force_scale(node_count: usize) -> f64 {
+        (node_count.max(AUTO_SCALE_REFERENCE) as f64 / AUTO_SCALE_REFERENCE as f64).sqrt()
+    }
+
+    /// Copy of this configuration with forces scaled by `scale`
+    ///
+    /// Charge strength becomes `charge_strength * s` and every link distance
+    /// `link_distance * sqrt(s)`. Repulsion thus grows with `sqrt(n)` while
+    /// links stretch only slightly, which spreads large graphs instead of
+    /// letting the center force pack them into a blob.
+    pub fn scaled(&self, scale: f64) -> LayoutConfig {
+        let stretch = scale.sqrt();
+        LayoutConfig {
+            charge_strength: self.charge_strength * scale,
+            link_distance: self.link_distance * stretch,
+            link_distances: self
+                .link_distances
+                .iter()
+                .map(|(edge_type, distance)| (edge_type.clone(), distance * stretch))
+                .collect(),
+            ..self.clone()
         }
     }
 }
 
+/// Node count up to which auto-scaled forces equal the configured ones
+const AUTO_SCALE_REFERENCE: usize = 10;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +204,14 @@ mod tests {
         assert_eq!(config.alpha, 0.5);
         assert_eq!(config.link_distance, 50.0);
     }
+
+    #[test]
+    fn test_default_link_distances() {
+        let config = LayoutConfig::default();
+        let distance = |edge_type| config.link_distances.get(&edge_type).copied();
+
+        assert!(distance(EdgeType::SubClass).unwrap() < config.link_distance);
+        assert!(distance(EdgeType::DatatypeProperty).unwrap() < config.link_distance);
+        assert_eq!(distance(EdgeType::ObjectProperty), None);
+    }
 }
@@ -0,0 +1,283 @@
+//! Barnes-Hut quadtree for approximating many-body repulsion
+
+use super::force::calculate_repulsion;
+use nalgebra::Vector2;
+
+/// Cells stop splitting at this depth so coincident points cannot recurse forever
+const MAX_DEPTH: usize = 32;
+
+/// Quadtree over node positions with per-cell center of mass and total charge
+///
+/// Points are identified by their index in the slice passed to
+/// [`QuadTree::new`]. Two points repel with the mean of their charges, which
+/// lets a distant cell act with the sum of the charges it contains.
+#[derive(Debug, Clone)]
+pub struct QuadTree {
+    root: Option<Cell>,
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    /// Lower-left corner
+    origin: Vector2<f64>,
+
+    /// Side length of the square cell
+    size: f64,
+
+    /// Number of points in the cell
+    mass: f64,
+
+    /// Center of mass of the points in the cell
+    center: Vector2<f64>,
+
+    /// Sum of the charges of the points in the cell
+    charge: f64,
+
+    kind: CellKind,
+}
+
+#[derive(Debug, Clone)]
+enum CellKind {
+    Leaf(Vec<(usize, Vector2<f64>, f64)>),
+    Internal(Box<[Option<Cell>; 4]>),
+}
+
+impl QuadTree {
+    /// Build a tree containing all given points with their charges
+    ///
+    /// `charges` is indexed like `points`.
+    pub fn new(points: &[Vector2<f64>], charges: &[f64]) -> Self {
+        let Some(first) = points.first() else {
+            return Self { root: None };
+        };
+
+        let (min, max) = points.iter().fold((*first, *first), |(min, max), p| {
+            (min.inf(p), max.sup(p))
+        });
+        let size = (max - min).max().max(1.0);
+
+        let mut root = Cell::new(min, size);
+        for (index, (point, charge)) in points.iter().zip(charges).enumerate() {
+            root.insert(index, *point, *charge, 0);
+        }
+
+        Self { root: Some(root) }
+    }
+
+    /// Total repulsion on point `index` at `position` with `charge` from all other points
+    ///
+    /// Cells whose side length divided by their distance is below `theta` are
+    /// treated as a single mass at their center of mass. A `theta` of zero
+    /// visits every point and matches the exact all-pairs computation.
+    pub fn repulsion(
+        &self,
+        index: usize,
+        position: Vector2<f64>,
+        charge: f64,
+        theta: f64,
+    ) -> Vector2<f64> {
+        let mut force = Vector2::zeros();
+        if let Some(root) = &self.root {
+            root.accumulate(index, position, charge, theta, &mut force);
+        }
+        force
+    }
+}
+
+impl Cell {
+    fn new(origin: Vector2<f64>, size: f64) -> Self {
+        Self {
+            origin,
+            size,
+            mass: 0.0,
+            center: Vector2::zeros(),
+            charge: 0.0,
+            kind: CellKind::Leaf(Vec::new()),
+        }
+    }
+
+    fn contains(&self, point: Vector2<f64>) -> bool {
+        let rel = point - self.origin;
+        rel.x >= 0.0 && rel.y >= 0.0 && rel.x <= self.size && rel.y <= self.size
+    }
+
+    fn quadrant(&self, point: Vector2<f64>) -> usize {
+        let half = self.size / 2.0;
+        let right = point.x >= self.origin.x + half;
+        let top = point.y >= self.origin.y + half;
+        usize::from(right) | (usize::from(top) << 1)
+    }
+
+    fn child_origin(&self, quadrant: usize) -> Vector2<f64> {
+        let half = self.size / 2.0;
+        self.origin
+            + Vector2::new(
+                if quadrant & 1 != 0 { half } else { 0.0 },
+                if quadrant & 2 != 0 { half } else { 0.0 },
+            )
+    }
+
+    fn insert(&mut self, index: usize, point: Vector2<f64>, charge: f64, depth: usize) {
+        self.center = (self.center * self.mass + point) / (self.mass + 1.0);
+        self.mass += 1.0;
+        self.charge += charge;
+
+        match &mut self.kind {
+            CellKind::Leaf(points) if points.is_empty() || depth >= MAX_DEPTH => {
+                points.push((index, point, charge));
+            }
+            CellKind::Leaf(points) => {
+                let existing = std::mem::take(points);
+                self.kind = CellKind::Internal(Box::new([None, None, None, None]));
+                for (i, p, c) in existing {
+                    self.insert_child(i, p, c, depth);
+                }
+                self.insert_child(index, point, charge, depth);
+            }
+            CellKind::Internal(_) => self.insert_child(index, point, charge, depth),
+        }
+    }
+
+    fn insert_child(&mut self, index: usize, point: Vector2<f64>, charge: f64, depth: usize) {
+        let quadrant = self.quadrant(point);
+        let origin = self.child_origin(quadrant);
+        let size = self.size / 2.0;
+
+        if let CellKind::Internal(children) = &mut self.kind {
+            children[quadrant]
+                .get_or_insert_with(|| Cell::new(origin, size))
+                .insert(index, point, charge, depth + 1);
+        }
+    }
+
+    fn accumulate(
+        &self,
+        index: usize,
+        position: Vector2<f64>,
+        charge: f64,
+        theta: f64,
+        force: &mut Vector2<f64>,
+    ) {
+        match &self.kind {
+            CellKind::Leaf(points) => {
+                for &(other, point, other_charge) in points {
+                    let strength = (charge + other_charge) / 2.0;
+                    // Mirror the all-pairs ordering so results match it exactly
+                    match index.cmp(&other) {
+                        std::cmp::Ordering::Less => {
+                            *force += calculate_repulsion(position, point, strength)
+                        }
+                        std::cmp::Ordering::Greater => {
+                            *force -= calculate_repulsion(point, position, strength)
+                        }
+                        std::cmp::Ordering::Equal => {}
+                    }
+                }
+            }
+            CellKind::Internal(children) => {
+                if !self.contains(position) {
+                    let distance = (self.center - position).norm();
+                    if distance > 0.0 && self.size / distance < theta {
+                        // Sum of the pairwise mean charges against every point in the cell
+                        let strength = (charge * self.mass + self.charge) / 2.0;
+                        *force += calculate_repulsion(position, self.center, strength);
+                        return;
+                    }
+                }
+
+                for child in children.iter().flatten() {
+                    child.accumulate(index, position, charge, theta, force);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_points(n: usize) -> Vec<Vector2<f64>> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64;
+                Vector2::new((t * 12.9898).sin() * 200.0, (t * 78.233).cos() * 150.0)
+            })
+            .collect()
+    }
+
+    fn all_pairs(points: &[Vector2<f64>], charges: &[f64]) -> Vec<Vector2<f64>> {
+        let mut forces = vec![Vector2::zeros(); points.len()];
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                let strength = (charges[i] + charges[j]) / 2.0;
+                let force = calculate_repulsion(points[i], points[j], strength);
+                forces[i] += force;
+                forces[j] -= force;
+            }
+        }
+        forces
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = QuadTree::new(&[], &[]);
+        assert_eq!(tree.repulsion(0, Vector2::zeros(), -30.0, 0.9), Vector2::zeros());
+    }
+
+    #[test]
+    fn test_zero_theta_matches_all_pairs() {
+        let points = sample_points(60);
+        let charges = vec![-30.0; points.len()];
+        let tree = QuadTree::new(&points, &charges);
+        let exact = all_pairs(&points, &charges);
+
+        for (i, point) in points.iter().enumerate() {
+            let approx = tree.repulsion(i, *point, -30.0, 0.0);
+            assert!((approx - exact[i]).norm() < 1e-9, "node {} differs", i);
+        }
+    }
+
+    #[test]
+    fn test_zero_theta_matches_all_pairs_with_mixed_charges() {
+        let points = sample_points(60);
+        let charges: Vec<f64> = (0..points.len()).map(|i| -10.0 * (i % 5 + 1) as f64).collect();
+        let tree = QuadTree::new(&points, &charges);
+        let exact = all_pairs(&points, &charges);
+
+        for (i, point) in points.iter().enumerate() {
+            let approx = tree.repulsion(i, *point, charges[i], 0.0);
+            assert!((approx - exact[i]).norm() < 1e-9, "node {} differs", i);
+        }
+    }
+
+    #[test]
+    fn test_theta_approximation_is_close() {
+        let points = sample_points(200);
+        let charges = vec![-30.0; points.len()];
+        let tree = QuadTree::new(&points, &charges);
+        let exact = all_pairs(&points, &charges);
+
+        let error: f64 = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (tree.repulsion(i, *p, -30.0, 0.5) - exact[i]).norm())
+            .sum();
+        let magnitude: f64 = exact.iter().map(|f| f.norm()).sum();
+
+        assert!(error / magnitude < 0.05);
+    }
+
+    #[test]
+    fn test_coincident_points() {
+        let points = vec![Vector2::new(1.0, 1.0); 3];
+        let charges = vec![-30.0; 3];
+        let tree = QuadTree::new(&points, &charges);
+        let exact = all_pairs(&points, &charges);
+
+        for (i, point) in points.iter().enumerate() {
+            let force = tree.repulsion(i, *point, -30.0, 0.0);
+            assert!((force - exact[i]).norm() < 1e-12);
+        }
+    }
+}
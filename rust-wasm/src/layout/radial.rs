@@ -0,0 +1,183 @@
+//! Radial layout around a chosen root node
+
+use super::LayoutAlgorithm;
+use crate::graph::{traversal::Direction, VowlGraph};
+use crate::{Result, VowlError};
+use std::collections::{HashMap, HashSet};
+
+/// Layout placing nodes on concentric rings by hop distance from a root
+///
+/// The root sits at the origin and ring `n` holds the nodes `n` hops away,
+/// ignoring edge direction. Nodes on a ring are spread evenly in BFS order,
+/// which keeps siblings next to each other. Nodes not connected to the root
+/// are placed on one extra ring outside the deepest level. The layout is
+/// computed in one pass by `initialize`.
+#[derive(Debug, Clone)]
+pub struct RadialLayout {
+    root_id: String,
+    ring_gap: f64,
+    finished: bool,
+}
+
+impl RadialLayout {
+    /// Create a radial layout centered on the given node
+    pub fn new(root_id: impl Into<String>) -> Self {
+        Self {
+            root_id: root_id.into(),
+            ring_gap: 100.0,
+            finished: false,
+        }
+    }
+
+    /// Set the radial distance between consecutive rings
+    pub fn with_ring_gap(mut self, ring_gap: f64) -> Self {
+        self.ring_gap = ring_gap;
+        self
+    }
+
+    /// Compute `(x, y)` for every node
+    fn compute_positions(&self, graph: &VowlGraph) -> Result<HashMap<String, (f64, f64)>> {
+        if graph.get_node(&self.root_id).is_none() {
+            return Err(VowlError::LayoutError(format!(
+                "Root node '{}' not found",
+                self.root_id
+            )));
+        }
+
+        let visited = graph.bfs(&self.root_id, None, Direction::Undirected)?;
+        let reached: HashSet<&str> = visited.iter().map(|(id, _)| id.as_str()).collect();
+        let unreachable: Vec<String> = graph
+            .nodes()
+            .into_iter()
+            .filter(|n| !reached.contains(n.id.as_str()))
+            .map(|n| n.id.clone())
+            .collect();
+
+        let mut rings: Vec<Vec<String>> = Vec::new();
+        for (id, depth) in visited {
+            if rings.len() <= depth {
+                rings.resize(depth + 1, Vec::new());
+            }
+            rings[depth].push(id);
+        }
+        if !unreachable.is_empty() {
+            rings.push(unreachable);
+        }
+
+        let mut positions = HashMap::new();
+        for (depth, ring) in rings.into_iter().enumerate() {
+            let radius = depth as f64 * self.ring_gap;
+            let step = std::f64::consts::TAU / ring.len() as f64;
+            for (i, id) in ring.into_iter().enumerate() {
+                let angle = i as f64 * step;
+                positions.insert(id, (radius * angle.cos(), radius * angle.sin()));
+            }
+        }
+
+        Ok(positions)
+    }
+}
+
+impl LayoutAlgorithm for RadialLayout {
+    fn initialize(&mut self, graph: &mut VowlGraph) -> Result<()> {
+        for (id, (x, y)) in self.compute_positions(graph)? {
+            if let Some(node) = graph.get_node_mut(&id) {
+                if !node.visual.fixed {
+                    node.visual.x = x;
+                    node.visual.y = y;
+                    node.visual.vx = 0.0;
+                    node.visual.vy = 0.0;
+                }
+            }
+        }
+
+        self.finished = true;
+        Ok(())
+    }
+
+    fn tick(&mut self, _graph: &mut VowlGraph) -> Result<()> {
+        self.finished = true;
+        Ok(())
+    }
+
+    fn run(&mut self, graph: &mut VowlGraph, _iterations: usize) -> Result<()> {
+        self.initialize(graph)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn alpha(&self) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    /// Star around "hub" with a second level below "a" and an isolated node
+    fn create_graph() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for id in ["hub", "a", "b", "c", "a1", "a2", "lonely"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        for (from, to) in [("hub", "a"), ("b", "hub"), ("hub", "c"), ("a", "a1"), ("a", "a2")] {
+            let id = format!("{}-{}", from, to);
+            graph.add_edge(from, to, EdgeBuilder::new(id).build()).unwrap();
+        }
+        graph
+    }
+
+    fn radius(graph: &VowlGraph, id: &str) -> f64 {
+        let visual = &graph.get_node(id).unwrap().visual;
+        visual.x.hypot(visual.y)
+    }
+
+    #[test]
+    fn test_root_at_center() {
+        let mut graph = create_graph();
+        let mut layout = RadialLayout::new("hub");
+        layout.initialize(&mut graph).unwrap();
+
+        assert_eq!(radius(&graph, "hub"), 0.0);
+        assert!(layout.is_finished());
+    }
+
+    #[test]
+    fn test_rings_by_hop_distance() {
+        let mut graph = create_graph();
+        let mut layout = RadialLayout::new("hub").with_ring_gap(50.0);
+        layout.initialize(&mut graph).unwrap();
+
+        for id in ["a", "b", "c"] {
+            assert!((radius(&graph, id) - 50.0).abs() < 1e-9);
+        }
+        for id in ["a1", "a2"] {
+            assert!(radius(&graph, id) > radius(&graph, "a"));
+        }
+        assert!(radius(&graph, "lonely") > radius(&graph, "a1"));
+    }
+
+    #[test]
+    fn test_ring_spreads_nodes_evenly() {
+        let mut graph = create_graph();
+        let mut layout = RadialLayout::new("hub");
+        layout.initialize(&mut graph).unwrap();
+
+        let a = &graph.get_node("a").unwrap().visual;
+        let b = &graph.get_node("b").unwrap().visual;
+        let gap = (a.x - b.x).hypot(a.y - b.y);
+
+        // Three nodes on a ring of radius 100 form an equilateral triangle
+        assert!((gap - 100.0 * 3.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_missing_root() {
+        let mut graph = create_graph();
+        assert!(RadialLayout::new("missing").initialize(&mut graph).is_err());
+    }
+}
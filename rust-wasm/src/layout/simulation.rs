@@ -1,55 +1,371 @@
 //! Force-directed layout simulation
 
-use super::{force::*, LayoutAlgorithm, LayoutConfig};
+use super::{force::*, InitialLayout, LayoutAlgorithm, LayoutConfig};
 use crate::graph::VowlGraph;
-use crate::Result;
+use crate::{Result, VowlError};
 use nalgebra::Vector2;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Attraction exerted on a node by one linked neighbor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeighborForce {
+    /// Neighbor node ID
+    pub neighbor: String,
+
+    /// Force vector (x, y)
+    pub force: (f64, f64),
+}
+
+/// Breakdown of the forces currently acting on a node
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeExplanation {
+    /// Node ID
+    pub id: String,
+
+    /// Total repulsion from all other nodes
+    pub repulsion: (f64, f64),
+
+    /// Attraction per linked neighbor
+    pub attraction: Vec<NeighborForce>,
+
+    /// Pull toward the simulation center
+    pub centering: (f64, f64),
+
+    /// Sum of registered custom forces
+    pub custom: (f64, f64),
+
+    /// Net force (sum of all components)
+    pub total: (f64, f64),
+
+    /// Current velocity
+    pub velocity: (f64, f64),
+}
+
+/// Position and velocity of one node in a `LayoutSnapshot`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeState {
+    /// Node ID
+    pub id: String,
+
+    /// Position (x, y)
+    pub position: (f64, f64),
+
+    /// Velocity (x, y)
+    pub velocity: (f64, f64),
+}
+
+/// Saved layout state, e.g. for undo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutSnapshot {
+    /// State of every node, in graph order
+    pub nodes: Vec<NodeState>,
+
+    /// Simulation alpha at the time of the snapshot
+    pub alpha: f64,
+}
+
+/// Why a simulation finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FinishReason {
+    /// Alpha decayed below `alpha_min`
+    AlphaMin,
+
+    /// No node moved further than `min_displacement` for
+    /// `convergence_ticks` ticks in a row
+    Converged,
+
+    /// `max_iterations` ticks passed since the simulation last started
+    MaxIterations,
+}
 
 /// Force-directed layout simulation
 pub struct ForceSimulation {
     config: LayoutConfig,
     alpha: f64,
+    alpha_target: f64,
+    settled_ticks: usize,
     iteration: usize,
+    cooling_ticks: usize,
+    forces: Vec<Box<dyn Force>>,
+    custom_forces: Vec<Box<dyn Force>>,
+    force_scale: f64,
+    frozen: HashSet<String>,
 }
 
 impl ForceSimulation {
     /// Create a new simulation with default configuration
     pub fn new() -> Self {
-        Self {
-            config: LayoutConfig::default(),
-            alpha: 1.0,
-            iteration: 0,
-        }
+        Self::with_config(LayoutConfig::default())
     }
 
     /// Create a simulation with custom configuration
     pub fn with_config(config: LayoutConfig) -> Self {
         let alpha = config.alpha;
+        let forces = Self::build_forces(&config);
         Self {
             config,
             alpha,
+            alpha_target: 0.0,
+            settled_ticks: 0,
             iteration: 0,
+            cooling_ticks: 0,
+            forces,
+            custom_forces: Vec::new(),
+            force_scale: 1.0,
+            frozen: HashSet::new(),
+        }
+    }
+
+    /// Build the built-in forces from configuration
+    fn build_forces(config: &LayoutConfig) -> Vec<Box<dyn Force>> {
+        vec![
+            Box::new(ManyBodyForce {
+                strength: config.charge_strength,
+                theta: config.theta,
+            }),
+            Box::new(LinkForce {
+                distance: config.link_distance,
+                type_distances: config.link_distances.clone(),
+                strength: config.link_strength,
+            }),
+            Self::center_force(config),
+        ]
+    }
+
+    /// The centering force, per component if `separate_components` is set
+    fn center_force(config: &LayoutConfig) -> Box<dyn Force> {
+        if config.separate_components {
+            Box::new(ComponentCenterForce {
+                center: config.center,
+                strength: config.center_strength,
+                spacing: config.link_distance,
+            })
+        } else {
+            Box::new(CenterForce {
+                center: config.center,
+                strength: config.center_strength,
+            })
+        }
+    }
+
+    /// Get the current configuration
+    pub fn config(&self) -> &LayoutConfig {
+        &self.config
+    }
+
+    /// Get the configuration the forces use, after auto-scaling
+    ///
+    /// Equal to [`ForceSimulation::config`] unless `auto_scale_forces` is set
+    /// and the last initialized graph had more than 10 nodes.
+    pub fn scaled_config(&self) -> LayoutConfig {
+        self.config.scaled(self.force_scale)
+    }
+
+    /// Rebuild the built-in forces after a configuration change
+    fn rebuild_forces(&mut self) {
+        self.forces = Self::build_forces(&self.scaled_config());
+    }
+
+    /// Continue an initialized simulation for up to `ticks` steps
+    ///
+    /// Unlike [`LayoutAlgorithm::run`], positions are not re-initialized.
+    /// Stops early once the simulation has finished and returns the number of
+    /// ticks actually performed.
+    pub fn advance(&mut self, graph: &mut VowlGraph, ticks: usize) -> Result<usize> {
+        let mut performed = 0;
+        while performed < ticks && !self.is_finished() {
+            self.tick(graph)?;
+            performed += 1;
+        }
+        Ok(performed)
+    }
+
+    /// Restart a cooled simulation at the given alpha
+    ///
+    /// The alpha is clamped to `[alpha_min, 1.0]`. Positions and the
+    /// iteration count are kept, so ticking simply resumes, and the
+    /// `max_iterations` budget starts over.
+    pub fn reheat(&mut self, alpha: f64) {
+        self.alpha = alpha.clamp(self.config.alpha_min, 1.0);
+        self.settled_ticks = 0;
+        self.cooling_ticks = 0;
+    }
+
+    /// Why the simulation finished, or `None` while it is still running
+    ///
+    /// When several conditions hold at once, convergence is reported first,
+    /// then `alpha_min`.
+    pub fn finish_reason(&self) -> Option<FinishReason> {
+        let converged = self.config.min_displacement > 0.0
+            && self.settled_ticks >= self.config.convergence_ticks.max(1);
+        if converged {
+            Some(FinishReason::Converged)
+        } else if self.alpha < self.config.alpha_min {
+            Some(FinishReason::AlphaMin)
+        } else if self.cooling_ticks >= self.config.max_iterations {
+            Some(FinishReason::MaxIterations)
+        } else {
+            None
+        }
+    }
+
+    /// Capture node positions, velocities and the current alpha
+    pub fn snapshot(&self, graph: &VowlGraph) -> LayoutSnapshot {
+        LayoutSnapshot {
+            nodes: graph
+                .nodes()
+                .into_iter()
+                .map(|node| NodeState {
+                    id: node.id.clone(),
+                    position: (node.visual.x, node.visual.y),
+                    velocity: (node.visual.vx, node.visual.vy),
+                })
+                .collect(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Return the graph and simulation to a snapshot
+    ///
+    /// Nodes missing from the snapshot keep their state, and snapshot entries
+    /// for nodes no longer in the graph are ignored.
+    pub fn restore(&mut self, graph: &mut VowlGraph, snapshot: &LayoutSnapshot) {
+        for state in &snapshot.nodes {
+            if let Some(node) = graph.get_node_mut(&state.id) {
+                (node.visual.x, node.visual.y) = state.position;
+                (node.visual.vx, node.visual.vy) = state.velocity;
+            }
         }
+        self.alpha = snapshot.alpha;
+        self.settled_ticks = 0;
+        self.cooling_ticks = 0;
+    }
+
+    /// Set the alpha the simulation decays toward
+    ///
+    /// While the target is at or above `alpha_min` the simulation never
+    /// finishes, not even after `max_iterations` ticks, which keeps it
+    /// responsive during a drag. Set it back to 0 on
+    /// release so the layout cools down. A finished simulation must be
+    /// reheated before the target takes effect.
+    pub fn set_alpha_target(&mut self, target: f64) {
+        self.alpha_target = target.clamp(0.0, 1.0);
+    }
+
+    /// Get the alpha the simulation decays toward
+    pub fn alpha_target(&self) -> f64 {
+        self.alpha_target
+    }
+
+    /// Register an additional force applied after the built-in ones
+    pub fn add_force(&mut self, force: Box<dyn Force>) {
+        self.custom_forces.push(force);
+    }
+
+    /// Hold nodes in place during ticks, e.g. the area the user is reading
+    ///
+    /// Unlike pinning with `visual.fixed` this is simulation state only and
+    /// is not stored in the graph. Frozen nodes still push and pull the
+    /// others, and lose their velocity so they resume from rest. Adds to the
+    /// nodes already frozen.
+    pub fn freeze_nodes(&mut self, ids: &[String]) {
+        self.frozen.extend(ids.iter().cloned());
+    }
+
+    /// Let all frozen nodes move again
+    pub fn unfreeze_all(&mut self) {
+        self.frozen.clear();
+    }
+
+    /// Check whether a node is frozen
+    pub fn is_frozen(&self, id: &str) -> bool {
+        self.frozen.contains(id)
     }
 
     /// Set center position
     pub fn set_center(&mut self, x: f64, y: f64) {
         self.config.center = (x, y);
+        self.rebuild_forces();
     }
 
     /// Set link distance
     pub fn set_link_distance(&mut self, distance: f64) {
         self.config.link_distance = distance;
+        self.rebuild_forces();
     }
 
     /// Set charge strength
     pub fn set_charge_strength(&mut self, strength: f64) {
         self.config.charge_strength = strength;
+        self.rebuild_forces();
     }
 
-    /// Initialize node positions randomly
+    /// Initialize node positions according to the configured strategy
     fn initialize_positions(&self, graph: &mut VowlGraph) {
+        match self.config.initial_layout {
+            InitialLayout::Circle => self.initialize_circle(graph),
+            InitialLayout::NeighborCentroid => self.initialize_neighbor_centroid(graph),
+            InitialLayout::Grid => self.initialize_grid(graph),
+            InitialLayout::Random => self.initialize_random(graph),
+        }
+    }
+
+    /// IDs of the nodes that still need a starting position
+    fn unplaced_nodes(graph: &VowlGraph) -> Vec<String> {
+        graph
+            .nodes()
+            .iter()
+            .filter(|n| !n.visual.fixed && n.visual.x == 0.0 && n.visual.y == 0.0)
+            .map(|n| n.id.clone())
+            .collect()
+    }
+
+    /// Place unpositioned nodes row by row on a square lattice around the origin
+    fn initialize_grid(&self, graph: &mut VowlGraph) {
+        let node_ids = Self::unplaced_nodes(graph);
+        let columns = (node_ids.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let rows = node_ids.len().div_ceil(columns);
+        let spacing = self.scaled_config().link_distance.max(1.0);
+
+        // Centered, but nudged so no node lands on (0, 0), which reads as unplaced
+        let origin_x = -((columns - 1) as f64) * spacing / 2.0 + 0.5;
+        let origin_y = -((rows - 1) as f64) * spacing / 2.0 + 0.5;
+
+        for (i, node_id) in node_ids.iter().enumerate() {
+            if let Some(node) = graph.get_node_mut(node_id) {
+                node.visual.x = origin_x + (i % columns) as f64 * spacing;
+                node.visual.y = origin_y + (i / columns) as f64 * spacing;
+            }
+        }
+    }
+
+    /// Scatter unpositioned nodes over the square the grid would fill
+    fn initialize_random(&self, graph: &mut VowlGraph) {
+        let node_ids = Self::unplaced_nodes(graph);
+        let side = (node_ids.len() as f64).sqrt().ceil().max(1.0)
+            * self.scaled_config().link_distance.max(1.0);
+
+        // SplitMix64, so runs are reproducible without a rand dependency
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            (z ^ (z >> 31)) as f64 / u64::MAX as f64 - 0.5
+        };
+
+        for node_id in node_ids {
+            if let Some(node) = graph.get_node_mut(&node_id) {
+                node.visual.x = next() * side;
+                node.visual.y = next() * side;
+            }
+        }
+    }
+
+    /// Place unpositioned nodes evenly on a circle
+    fn initialize_circle(&self, graph: &mut VowlGraph) {
         let radius = 10.0;
         let mut angle: f64 = 0.0;
         let angle_step = std::f64::consts::TAU / graph.node_count() as f64;
@@ -59,7 +375,8 @@ impl ForceSimulation {
 
         for node_id in node_ids {
             if let Some(node_mut) = graph.get_node_mut(&node_id) {
-                if node_mut.visual.x == 0.0 && node_mut.visual.y == 0.0 {
+                let visual = &node_mut.visual;
+                if !visual.fixed && visual.x == 0.0 && visual.y == 0.0 {
                     node_mut.visual.x = radius * angle.cos();
                     node_mut.visual.y = radius * angle.sin();
                     angle += angle_step;
@@ -68,69 +385,157 @@ impl ForceSimulation {
         }
     }
 
-    /// Calculate all forces for one simulation step
-    fn calculate_forces(&self, graph: &VowlGraph) -> HashMap<String, Vector2<f64>> {
-        let mut forces: HashMap<String, Vector2<f64>> = HashMap::new();
-        let nodes = graph.nodes();
-
-        // Initialize forces
-        for node in &nodes {
-            forces.insert(node.id.clone(), Vector2::zeros());
-        }
+    /// Place each node at the centroid of its already-placed neighbors
+    ///
+    /// Nodes are processed in descending degree order so hubs settle first.
+    /// Nodes without placed neighbors fall back to the circle.
+    fn initialize_neighbor_centroid(&self, graph: &mut VowlGraph) {
+        let radius = 10.0;
+        let jitter = 1.0;
+        let golden_angle = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        let angle_step = std::f64::consts::TAU / graph.node_count().max(1) as f64;
 
-        // Apply repulsion between all nodes
-        for i in 0..nodes.len() {
-            for j in (i + 1)..nodes.len() {
-                let node1 = nodes[i];
-                let node2 = nodes[j];
+        let mut order: Vec<(String, Vec<String>)> = graph
+            .nodes()
+            .iter()
+            .map(|n| {
+                let neighbors = graph
+                    .undirected_neighbors(&n.id)
+                    .map(|ns| ns.iter().map(|m| m.id.clone()).collect())
+                    .unwrap_or_default();
+                (n.id.clone(), neighbors)
+            })
+            .collect();
+        order.sort_by_key(|(_, neighbors)| std::cmp::Reverse(neighbors.len()));
 
-                let pos1 = Vector2::new(node1.visual.x, node1.visual.y);
-                let pos2 = Vector2::new(node2.visual.x, node2.visual.y);
+        let mut placed: HashMap<String, (f64, f64)> = HashMap::new();
+        let mut circle_angle: f64 = 0.0;
 
-                let force = calculate_repulsion(pos1, pos2, self.config.charge_strength);
+        for (i, (node_id, neighbors)) in order.iter().enumerate() {
+            let Some(node) = graph.get_node_mut(node_id) else {
+                continue;
+            };
 
-                *forces.get_mut(&node1.id).unwrap() += force;
-                *forces.get_mut(&node2.id).unwrap() -= force;
+            if node.visual.fixed || node.visual.x != 0.0 || node.visual.y != 0.0 {
+                placed.insert(node_id.clone(), (node.visual.x, node.visual.y));
+                continue;
             }
-        }
 
-        // Apply attraction along edges
-        // Find source and target nodes
-        // This is a simplified version - in practice, we'd need edge indices
-        for node in &nodes {
-            if let Ok(neighbors) = graph.neighbors(&node.id) {
-                for neighbor in neighbors {
-                    let pos1 = Vector2::new(node.visual.x, node.visual.y);
-                    let pos2 = Vector2::new(neighbor.visual.x, neighbor.visual.y);
+            let anchors: Vec<(f64, f64)> = neighbors
+                .iter()
+                .filter_map(|id| placed.get(id).copied())
+                .collect();
 
-                    let force = calculate_attraction(
-                        pos1,
-                        pos2,
-                        self.config.link_distance,
-                        self.config.link_strength,
-                    );
+            let (x, y) = if anchors.is_empty() {
+                let pos = (radius * circle_angle.cos(), radius * circle_angle.sin());
+                circle_angle += angle_step;
+                pos
+            } else {
+                let n = anchors.len() as f64;
+                let cx = anchors.iter().map(|p| p.0).sum::<f64>() / n;
+                let cy = anchors.iter().map(|p| p.1).sum::<f64>() / n;
+                // Offset slightly so siblings don't start on top of each other
+                let angle = i as f64 * golden_angle;
+                (cx + jitter * angle.cos(), cy + jitter * angle.sin())
+            };
 
-                    *forces.get_mut(&node.id).unwrap() += force * self.alpha;
-                }
-            }
+            node.visual.x = x;
+            node.visual.y = y;
+            placed.insert(node_id.clone(), (x, y));
         }
+    }
 
-        // Apply centering force
-        let center = Vector2::new(self.config.center.0, self.config.center.1);
-        for node in &nodes {
-            let pos = Vector2::new(node.visual.x, node.visual.y);
-            let force = calculate_center_force(pos, center, self.config.center_strength);
-            *forces.get_mut(&node.id).unwrap() += force;
+    /// Explain the forces currently acting on a node
+    ///
+    /// Each component is computed as in the next tick, so the components
+    /// sum to `total`.
+    pub fn explain_node(&self, graph: &VowlGraph, id: &str) -> Result<NodeExplanation> {
+        let node = graph
+            .get_node(id)
+            .ok_or_else(|| VowlError::LayoutError(format!("Node '{}' not found", id)))?;
+
+        let component = |forces: &[&dyn Force]| -> Vector2<f64> {
+            let mut map: HashMap<String, Vector2<f64>> = HashMap::new();
+            for force in forces {
+                force.apply(graph, &mut map, self.alpha);
+            }
+            map.get(id).copied().unwrap_or_else(Vector2::zeros)
+        };
+
+        let config = self.scaled_config();
+        let repulsion = component(&[&ManyBodyForce {
+            strength: config.charge_strength,
+            theta: config.theta,
+        }]);
+        let centering = component(&[Self::center_force(&config).as_ref()]);
+        let custom: Vec<&dyn Force> = self.custom_forces.iter().map(|f| f.as_ref()).collect();
+        let custom = component(&custom);
+
+        let link = LinkForce {
+            distance: config.link_distance,
+            type_distances: config.link_distances,
+            strength: config.link_strength,
+        };
+        let attraction: Vec<NeighborForce> = link
+            .contributions(graph, id, self.alpha)
+            .into_iter()
+            .map(|(neighbor, force)| NeighborForce {
+                neighbor,
+                force: (force.x, force.y),
+            })
+            .collect();
+
+        let total = attraction
+            .iter()
+            .fold(repulsion + centering + custom, |acc, a| {
+                acc + Vector2::new(a.force.0, a.force.1)
+            });
+
+        Ok(NodeExplanation {
+            id: id.to_string(),
+            repulsion: (repulsion.x, repulsion.y),
+            attraction,
+            centering: (centering.x, centering.y),
+            custom: (custom.x, custom.y),
+            total: (total.x, total.y),
+            velocity: (node.visual.vx, node.visual.vy),
+        })
+    }
+
+    /// Calculate all forces for one simulation step
+    fn calculate_forces(&self, graph: &VowlGraph) -> HashMap<String, Vector2<f64>> {
+        // Collapsed nodes neither exert nor receive forces
+        let mut forces: HashMap<String, Vector2<f64>> = graph
+            .nodes()
+            .iter()
+            .filter(|node| !node.visual.hidden)
+            .map(|node| (node.id.clone(), Vector2::zeros()))
+            .collect();
+
+        for force in self.forces.iter().chain(&self.custom_forces) {
+            force.apply(graph, &mut forces, self.alpha);
         }
 
         forces
     }
 
     /// Apply forces to update node positions
-    fn apply_forces(&self, graph: &mut VowlGraph, forces: &HashMap<String, Vector2<f64>>) {
+    ///
+    /// Returns the largest distance any node moved.
+    fn apply_forces(&self, graph: &mut VowlGraph, forces: &HashMap<String, Vector2<f64>>) -> f64 {
+        let mut max_displacement: f64 = 0.0;
         for (node_id, force) in forces {
+            if self.frozen.contains(node_id) {
+                // Unfrozen nodes start from rest instead of their old momentum
+                if let Some(node) = graph.get_node_mut(node_id) {
+                    (node.visual.vx, node.visual.vy) = (0.0, 0.0);
+                }
+                continue;
+            }
             if let Some(node) = graph.get_node_mut(node_id) {
-                if !node.visual.fixed {
+                if !node.visual.fixed && !node.visual.hidden {
+                    let (old_x, old_y) = (node.visual.x, node.visual.y);
+
                     // Update velocity
                     node.visual.vx += force.x * self.alpha;
                     node.visual.vy += force.y * self.alpha;
@@ -142,17 +547,39 @@ impl ForceSimulation {
                     // Update position
                     node.visual.x += node.visual.vx;
                     node.visual.y += node.visual.vy;
+
+                    // Keep inside the bounds, stopping motion along the blocked axis
+                    if let Some((min_x, min_y, max_x, max_y)) = self.config.bounds {
+                        if node.visual.x < min_x || node.visual.x > max_x {
+                            node.visual.x = node.visual.x.clamp(min_x, max_x);
+                            node.visual.vx = 0.0;
+                        }
+                        if node.visual.y < min_y || node.visual.y > max_y {
+                            node.visual.y = node.visual.y.clamp(min_y, max_y);
+                            node.visual.vy = 0.0;
+                        }
+                    }
+
+                    let moved = (node.visual.x - old_x).hypot(node.visual.y - old_y);
+                    max_displacement = max_displacement.max(moved);
                 }
             }
         }
+        max_displacement
     }
 }
 
 impl LayoutAlgorithm for ForceSimulation {
     fn initialize(&mut self, graph: &mut VowlGraph) -> Result<()> {
+        if self.config.auto_scale_forces {
+            self.force_scale = LayoutConfig::force_scale(graph.node_count());
+            self.rebuild_forces();
+        }
         self.initialize_positions(graph);
         self.alpha = self.config.alpha;
+        self.settled_ticks = 0;
         self.iteration = 0;
+        self.cooling_ticks = 0;
         Ok(())
     }
 
@@ -162,11 +589,23 @@ impl LayoutAlgorithm for ForceSimulation {
         }
 
         let forces = self.calculate_forces(graph);
-        self.apply_forces(graph, &forces);
+        let displacement = self.apply_forces(graph, &forces);
+
+        // An alpha target keeps the layout live on purpose, e.g. during a drag
+        let settled = self.config.min_displacement > 0.0
+            && self.alpha_target < self.config.alpha_min
+            && displacement < self.config.min_displacement;
+        self.settled_ticks = if settled { self.settled_ticks + 1 } else { 0 };
 
-        // Decay alpha
-        self.alpha *= 1.0 - self.config.alpha_decay;
+        // Decay alpha toward the target
+        self.alpha += (self.alpha_target - self.alpha) * self.config.alpha_decay;
         self.iteration += 1;
+        // Only a cooling simulation counts toward the iteration cap
+        if self.alpha_target < self.config.alpha_min {
+            self.cooling_ticks += 1;
+        } else {
+            self.cooling_ticks = 0;
+        }
 
         Ok(())
     }
@@ -185,7 +624,7 @@ impl LayoutAlgorithm for ForceSimulation {
     }
 
     fn is_finished(&self) -> bool {
-        self.alpha < self.config.alpha_min
+        self.finish_reason().is_some()
     }
 
     fn alpha(&self) -> f64 {
@@ -202,7 +641,7 @@ impl Default for ForceSimulation {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::{node::NodeBuilder, NodeType, VowlGraph};
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder, EdgeType, NodeType, VowlGraph};
 
     fn create_test_graph() -> VowlGraph {
         let mut graph = VowlGraph::new();
@@ -265,6 +704,288 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fixed_node_keeps_position() {
+        let mut graph = create_test_graph();
+        graph.set_fixed("node1", true).unwrap();
+        graph.set_fixed("node2", true).unwrap();
+        graph.set_position("node2", 25.0, -15.0).unwrap();
+
+        let mut sim = ForceSimulation::new();
+        sim.run(&mut graph, 50).unwrap();
+
+        // Pinned at the origin, so initialization must not move it either
+        let node1 = &graph.get_node("node1").unwrap().visual;
+        assert_eq!((node1.x, node1.y), (0.0, 0.0));
+        let node2 = &graph.get_node("node2").unwrap().visual;
+        assert_eq!((node2.x, node2.y), (25.0, -15.0));
+
+        let node3 = &graph.get_node("node3").unwrap().visual;
+        assert!(node3.x != 0.0 || node3.y != 0.0);
+    }
+
+    #[test]
+    fn test_frozen_nodes_hold_until_unfrozen() {
+        let mut graph = create_test_graph();
+        let mut sim = ForceSimulation::new();
+        sim.initialize(&mut graph).unwrap();
+        sim.tick(&mut graph).unwrap();
+        assert_ne!(graph.get_node("node1").unwrap().visual.vx, 0.0);
+
+        sim.freeze_nodes(&["node1".to_string(), "node2".to_string()]);
+        assert!(sim.is_frozen("node1"));
+        let before = graph.positions();
+        for _ in 0..20 {
+            sim.tick(&mut graph).unwrap();
+        }
+
+        let after = graph.positions();
+        assert_eq!(after["node1"], before["node1"]);
+        assert_eq!(after["node2"], before["node2"]);
+        assert_ne!(after["node3"], before["node3"]);
+        let node1 = &graph.get_node("node1").unwrap().visual;
+        assert!(!node1.fixed);
+        assert_eq!((node1.vx, node1.vy), (0.0, 0.0));
+
+        sim.unfreeze_all();
+        assert!(!sim.is_frozen("node1"));
+        for _ in 0..20 {
+            sim.tick(&mut graph).unwrap();
+        }
+        let resumed = graph.positions();
+        assert_ne!(resumed["node1"], after["node1"]);
+        assert_ne!(resumed["node2"], after["node2"]);
+    }
+
+    #[test]
+    fn test_hidden_node_is_left_out() {
+        let mut graph = create_test_graph();
+        graph.set_position("node3", 500.0, 500.0).unwrap();
+        graph.get_node_mut("node3").unwrap().visual.hidden = true;
+
+        let mut sim = ForceSimulation::new();
+        sim.run(&mut graph, 50).unwrap();
+
+        let node3 = &graph.get_node("node3").unwrap().visual;
+        assert_eq!((node3.x, node3.y), (500.0, 500.0));
+    }
+
+    #[test]
+    fn test_grid_init_has_distinct_positions() {
+        let mut graph = VowlGraph::new();
+        for i in 0..9 {
+            graph.add_node(NodeBuilder::new(format!("n{}", i)).build()).unwrap();
+        }
+
+        let config = LayoutConfig {
+            initial_layout: InitialLayout::Grid,
+            link_distance: 40.0,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.initialize(&mut graph).unwrap();
+
+        let positions: Vec<(f64, f64)> =
+            graph.nodes().iter().map(|n| (n.visual.x, n.visual.y)).collect();
+        for (i, a) in positions.iter().enumerate() {
+            assert!(a.0 != 0.0 || a.1 != 0.0);
+            for b in &positions[i + 1..] {
+                let distance = ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt();
+                assert!(distance >= 40.0 - 1e-9, "{:?} and {:?} collide", a, b);
+            }
+        }
+
+        // Nine nodes fill a 3x3 lattice
+        let xs: std::collections::BTreeSet<i64> =
+            positions.iter().map(|p| p.0.round() as i64).collect();
+        assert_eq!(xs.len(), 3);
+    }
+
+    #[test]
+    fn test_grid_init_uses_scaled_link_distance() {
+        let mut graph = VowlGraph::new();
+        for i in 0..40 {
+            graph.add_node(NodeBuilder::new(format!("n{}", i)).build()).unwrap();
+        }
+
+        let config = LayoutConfig {
+            initial_layout: InitialLayout::Grid,
+            auto_scale_forces: true,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.initialize(&mut graph).unwrap();
+
+        let x = |id: &str| graph.get_node(id).unwrap().visual.x;
+        let spacing = x("n1") - x("n0");
+        assert!((spacing - sim.scaled_config().link_distance).abs() < 1e-9);
+        assert!(spacing > sim.config().link_distance);
+    }
+
+    #[test]
+    fn test_random_init_is_reproducible() {
+        let config = LayoutConfig {
+            initial_layout: InitialLayout::Random,
+            ..Default::default()
+        };
+
+        let mut first = create_test_graph();
+        let mut second = create_test_graph();
+        ForceSimulation::with_config(config.clone()).initialize(&mut first).unwrap();
+        ForceSimulation::with_config(config).initialize(&mut second).unwrap();
+
+        for (a, b) in first.nodes().iter().zip(second.nodes()) {
+            assert!(a.visual.x != 0.0 || a.visual.y != 0.0);
+            assert_eq!((a.visual.x, a.visual.y), (b.visual.x, b.visual.y));
+        }
+    }
+
+    #[test]
+    fn test_neighbor_centroid_places_leaf_near_neighbor() {
+        let mut graph = create_test_graph();
+        graph.add_node(NodeBuilder::new("leaf").build()).unwrap();
+        for target in ["node2", "node3", "leaf"] {
+            graph
+                .add_edge("node1", target, EdgeBuilder::new(format!("e_{}", target)).build())
+                .unwrap();
+        }
+
+        let config = LayoutConfig {
+            initial_layout: InitialLayout::NeighborCentroid,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.initialize(&mut graph).unwrap();
+
+        let hub = graph.get_node("node1").unwrap().visual.clone();
+        let leaf = graph.get_node("leaf").unwrap().visual.clone();
+        let distance = ((hub.x - leaf.x).powi(2) + (hub.y - leaf.y).powi(2)).sqrt();
+
+        assert!(hub.x != 0.0 || hub.y != 0.0);
+        assert!(distance > 0.0 && distance <= 1.0 + 1e-9, "distance was {}", distance);
+    }
+
+    #[test]
+    fn test_custom_force_takes_effect() {
+        /// Spring pulling every node onto the x axis
+        struct FlattenForce;
+
+        impl Force for FlattenForce {
+            fn apply(
+                &self,
+                graph: &VowlGraph,
+                forces: &mut HashMap<String, Vector2<f64>>,
+                _alpha: f64,
+            ) {
+                for node in graph.nodes() {
+                    forces.get_mut(&node.id).unwrap().y -= node.visual.y;
+                }
+            }
+        }
+
+        let config = LayoutConfig {
+            charge_strength: 0.0,
+            center_strength: 0.0,
+            ..Default::default()
+        };
+
+        let mut graph = create_test_graph();
+        let mut sim = ForceSimulation::with_config(config);
+        sim.add_force(Box::new(FlattenForce));
+        sim.run(&mut graph, 1000).unwrap();
+
+        for node in graph.nodes() {
+            assert!(node.visual.y.abs() < 0.01, "y was {}", node.visual.y);
+        }
+    }
+
+    #[test]
+    fn test_explain_node_components_sum_to_total() {
+        let mut graph = create_test_graph();
+        graph
+            .add_edge("node1", "node2", EdgeBuilder::new("e1").build())
+            .unwrap();
+        graph
+            .add_edge("node1", "node3", EdgeBuilder::new("e2").build())
+            .unwrap();
+
+        let mut sim = ForceSimulation::new();
+        sim.initialize(&mut graph).unwrap();
+        sim.tick(&mut graph).unwrap();
+
+        let explanation = sim.explain_node(&graph, "node1").unwrap();
+        let net = sim.calculate_forces(&graph)["node1"];
+
+        assert_eq!(explanation.attraction.len(), 2);
+        assert!((explanation.total.0 - net.x).abs() < 1e-9);
+        assert!((explanation.total.1 - net.y).abs() < 1e-9);
+
+        let node = graph.get_node("node1").unwrap();
+        assert_eq!(explanation.velocity, (node.visual.vx, node.visual.vy));
+    }
+
+    #[test]
+    fn test_link_force_is_equal_and_opposite() {
+        let mut graph = create_test_graph();
+        graph
+            .add_edge("node1", "node2", EdgeBuilder::new("e1").build())
+            .unwrap();
+        graph
+            .add_edge("node2", "node3", EdgeBuilder::new("e2").build())
+            .unwrap();
+        ForceSimulation::new().initialize(&mut graph).unwrap();
+
+        let link = LinkForce {
+            distance: 30.0,
+            type_distances: HashMap::new(),
+            strength: 1.0,
+        };
+        let mut forces = HashMap::new();
+        link.apply(&graph, &mut forces, 1.0);
+
+        let net = forces.values().fold(Vector2::zeros(), |acc, f| acc + f);
+        assert!(net.norm() < 1e-9);
+        assert!(forces["node3"].norm() > 0.0);
+
+        // The middle node is pulled by both its incoming and outgoing edge
+        assert_eq!(link.contributions(&graph, "node2", 1.0).len(), 2);
+    }
+
+    #[test]
+    fn test_single_edge_converges_to_link_distance() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+        graph.add_node(NodeBuilder::new("b").build()).unwrap();
+        graph
+            .add_edge("a", "b", EdgeBuilder::new("e").build())
+            .unwrap();
+
+        let config = LayoutConfig {
+            charge_strength: 0.0,
+            center_strength: 0.0,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config.clone());
+        sim.run(&mut graph, 1000).unwrap();
+
+        let a = graph.get_node("a").unwrap();
+        let b = graph.get_node("b").unwrap();
+        let separation = (a.visual.x - b.visual.x).hypot(a.visual.y - b.visual.y);
+        assert!(
+            (separation - config.link_distance).abs() < 1.0,
+            "separation {} should approach {}",
+            separation,
+            config.link_distance
+        );
+    }
+
+    #[test]
+    fn test_explain_unknown_node() {
+        let graph = create_test_graph();
+        let sim = ForceSimulation::new();
+        assert!(sim.explain_node(&graph, "missing").is_err());
+    }
+
     #[test]
     fn test_single_tick() {
         let mut graph = create_test_graph();
@@ -291,6 +1012,265 @@ mod tests {
         assert!(sim.alpha() < 0.5);
     }
 
+    #[test]
+    fn test_advance_continues_without_reinitializing() {
+        let mut graph = create_test_graph();
+        let mut sim = ForceSimulation::new();
+
+        sim.run(&mut graph, 10).unwrap();
+        let alpha = sim.alpha();
+
+        assert_eq!(sim.advance(&mut graph, 5).unwrap(), 5);
+        assert!(sim.alpha() < alpha);
+
+        // Stops at convergence instead of running every requested tick
+        let performed = sim.advance(&mut graph, 10_000).unwrap();
+        assert!(performed < 10_000);
+        assert!(sim.is_finished());
+        assert_eq!(sim.advance(&mut graph, 5).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_bounds_keep_nodes_inside() {
+        let mut graph = create_test_graph();
+        for i in 0..20 {
+            graph
+                .add_node(NodeBuilder::new(format!("extra{}", i)).build())
+                .unwrap();
+        }
+
+        let config = LayoutConfig {
+            charge_strength: -500.0,
+            center_strength: 0.0,
+            bounds: Some((-25.0, -10.0, 25.0, 10.0)),
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.run(&mut graph, 300).unwrap();
+
+        for node in graph.nodes() {
+            let (x, y) = (node.visual.x, node.visual.y);
+            assert!((-25.0..=25.0).contains(&x), "{} x = {}", node.id, x);
+            assert!((-10.0..=10.0).contains(&y), "{} y = {}", node.id, y);
+        }
+    }
+
+    #[test]
+    fn test_reheat_finished_simulation() {
+        let mut graph = create_test_graph();
+        let mut sim = ForceSimulation::new();
+        sim.run(&mut graph, 1000).unwrap();
+        assert!(sim.is_finished());
+        let iteration = sim.iteration;
+
+        // Disturb the converged layout, as a user drag would
+        graph.set_position("node1", 200.0, 200.0).unwrap();
+        sim.reheat(0.5);
+
+        assert!(!sim.is_finished());
+        assert_eq!(sim.alpha(), 0.5);
+        sim.tick(&mut graph).unwrap();
+        assert_eq!(sim.iteration, iteration + 1);
+        assert_ne!(graph.get_node("node1").unwrap().visual.x, 200.0);
+
+        sim.reheat(5.0);
+        assert_eq!(sim.alpha(), 1.0);
+    }
+
+    #[test]
+    fn test_converges_before_alpha_min() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+        graph.add_node(NodeBuilder::new("b").build()).unwrap();
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+
+        let config = LayoutConfig {
+            min_displacement: 0.01,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.run(&mut graph, 1000).unwrap();
+
+        assert!(sim.is_finished());
+        assert_eq!(sim.finish_reason(), Some(FinishReason::Converged));
+        assert!(sim.iteration < 200, "took {} ticks", sim.iteration);
+        assert!(sim.alpha() >= sim.config().alpha_min);
+
+        // Ticks are no-ops until reheated
+        let iteration = sim.iteration;
+        sim.tick(&mut graph).unwrap();
+        assert_eq!(sim.iteration, iteration);
+        sim.reheat(0.5);
+        assert!(!sim.is_finished());
+    }
+
+    #[test]
+    fn test_convergence_needs_consecutive_still_ticks() {
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+
+        let config = LayoutConfig {
+            min_displacement: 0.01,
+            convergence_ticks: 3,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+
+        // A lone node at the center never moves
+        for _ in 0..2 {
+            sim.tick(&mut graph).unwrap();
+            assert!(!sim.is_finished());
+        }
+        sim.tick(&mut graph).unwrap();
+        assert_eq!(sim.finish_reason(), Some(FinishReason::Converged));
+    }
+
+    #[test]
+    fn test_max_iterations_stops_without_decay() {
+        let mut graph = create_test_graph();
+        let config = LayoutConfig {
+            alpha_decay: 0.0,
+            max_iterations: 50,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.initialize(&mut graph).unwrap();
+
+        let mut ticks = 0;
+        while !sim.is_finished() && ticks < 1000 {
+            sim.tick(&mut graph).unwrap();
+            ticks += 1;
+        }
+
+        assert_eq!(ticks, 50);
+        assert_eq!(sim.finish_reason(), Some(FinishReason::MaxIterations));
+        assert_eq!(sim.alpha(), 1.0);
+
+        // A live alpha target is exempt from the cap
+        sim.reheat(1.0);
+        sim.set_alpha_target(0.5);
+        assert_eq!(sim.advance(&mut graph, 100).unwrap(), 100);
+        assert_eq!(sim.finish_reason(), None);
+
+        let mut sim = ForceSimulation::new();
+        sim.run(&mut graph, usize::MAX).unwrap();
+        assert_eq!(sim.finish_reason(), Some(FinishReason::AlphaMin));
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let mut graph = create_test_graph();
+        graph
+            .add_edge("node1", "node2", EdgeBuilder::new("e1").build())
+            .unwrap();
+        let mut sim = ForceSimulation::new();
+        sim.initialize(&mut graph).unwrap();
+        sim.advance(&mut graph, 20).unwrap();
+
+        let snapshot = sim.snapshot(&graph);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        sim.advance(&mut graph, 20).unwrap();
+        assert_ne!(sim.snapshot(&graph), snapshot);
+
+        let restored: LayoutSnapshot = serde_json::from_str(&json).unwrap();
+        sim.restore(&mut graph, &restored);
+        assert_eq!(sim.snapshot(&graph), snapshot);
+        assert_eq!(sim.alpha(), snapshot.alpha);
+    }
+
+    #[test]
+    fn test_link_distance_per_edge_type() {
+        let mut graph = VowlGraph::new();
+        for id in ["a", "b", "c", "d"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+        let subclass = EdgeBuilder::new("ab").edge_type(EdgeType::SubClass).build();
+        graph.add_edge("a", "b", subclass).unwrap();
+        let property = EdgeBuilder::new("cd").edge_type(EdgeType::ObjectProperty).build();
+        graph.add_edge("c", "d", property).unwrap();
+
+        let config = LayoutConfig {
+            link_distances: HashMap::from([
+                (EdgeType::SubClass, 20.0),
+                (EdgeType::ObjectProperty, 60.0),
+            ]),
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.run(&mut graph, 500).unwrap();
+
+        let distance = |a: &str, b: &str| {
+            let a = &graph.get_node(a).unwrap().visual;
+            let b = &graph.get_node(b).unwrap().visual;
+            (a.x - b.x).hypot(a.y - b.y)
+        };
+        assert!(
+            distance("a", "b") < distance("c", "d"),
+            "subclass {} vs property {}",
+            distance("a", "b"),
+            distance("c", "d")
+        );
+    }
+
+    #[test]
+    fn test_separate_components_do_not_overlap() {
+        let mut graph = VowlGraph::new();
+        for prefix in ["a", "b"] {
+            for i in 0..3 {
+                let id = format!("{}{}", prefix, i);
+                graph.add_node(NodeBuilder::new(id).build()).unwrap();
+            }
+            for i in 0..3 {
+                let from = format!("{}{}", prefix, i);
+                let to = format!("{}{}", prefix, (i + 1) % 3);
+                let edge = EdgeBuilder::new(format!("{}-{}", from, to)).build();
+                graph.add_edge(&from, &to, edge).unwrap();
+            }
+        }
+
+        let config = LayoutConfig {
+            separate_components: true,
+            ..Default::default()
+        };
+        let mut sim = ForceSimulation::with_config(config);
+        sim.run(&mut graph, 500).unwrap();
+
+        let bounds = |prefix: &str| {
+            let xs: Vec<(f64, f64)> = (0..3)
+                .map(|i| {
+                    let node = graph.get_node(&format!("{}{}", prefix, i)).unwrap();
+                    (node.visual.x, node.visual.y)
+                })
+                .collect();
+            let min_x = xs.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+            let max_x = xs.iter().map(|p| p.0).fold(f64::NEG_INFINITY, f64::max);
+            let min_y = xs.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+            let max_y = xs.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+            (min_x, max_x, min_y, max_y)
+        };
+        let a = bounds("a");
+        let b = bounds("b");
+        let overlap = a.0 <= b.1 && b.0 <= a.1 && a.2 <= b.3 && b.2 <= a.3;
+        assert!(!overlap, "components overlap: {:?} and {:?}", a, b);
+    }
+
+    #[test]
+    fn test_alpha_target_holds_energy() {
+        let mut graph = create_test_graph();
+        let mut sim = ForceSimulation::new();
+        sim.set_alpha_target(0.3);
+
+        sim.run(&mut graph, 1000).unwrap();
+
+        assert!(!sim.is_finished());
+        assert!((sim.alpha() - 0.3).abs() < 1e-6, "alpha was {}", sim.alpha());
+
+        // Releasing the target lets the simulation cool down
+        sim.set_alpha_target(0.0);
+        sim.advance(&mut graph, 10_000).unwrap();
+        assert!(sim.is_finished());
+    }
+
     #[test]
     fn test_simulation_finishes() {
         let mut graph = create_test_graph();
@@ -320,4 +1300,60 @@ mod tests {
         assert_eq!(sim.config.link_distance, 50.0);
         assert_eq!(sim.config.charge_strength, -100.0);
     }
+
+    /// Binary tree of `n` nodes
+    fn create_tree(n: usize) -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for i in 0..n {
+            graph.add_node(NodeBuilder::new(format!("n{}", i)).build()).unwrap();
+        }
+        for i in 1..n {
+            let edge = EdgeBuilder::new(format!("e{}", i)).build();
+            graph
+                .add_edge(&format!("n{}", (i - 1) / 2), &format!("n{}", i), edge)
+                .unwrap();
+        }
+        graph
+    }
+
+    #[test]
+    fn test_auto_scale_forces() {
+        let config = LayoutConfig {
+            auto_scale_forces: true,
+            ..Default::default()
+        };
+
+        // Average pairwise distance per sqrt(n), i.e. the spacing a uniform
+        // layout of the same area would have
+        let mut spacing = Vec::new();
+        let mut charges = Vec::new();
+        for n in [10, 200] {
+            let mut graph = create_tree(n);
+            let mut sim = ForceSimulation::with_config(config.clone());
+            sim.initialize(&mut graph).unwrap();
+            sim.run(&mut graph, 300).unwrap();
+            charges.push(sim.scaled_config().charge_strength.abs());
+
+            let positions: Vec<Vector2<f64>> = graph
+                .nodes()
+                .iter()
+                .map(|node| Vector2::new(node.visual.x, node.visual.y))
+                .collect();
+            let mut total = 0.0;
+            for (i, a) in positions.iter().enumerate() {
+                for b in &positions[i + 1..] {
+                    total += (a - b).norm();
+                }
+            }
+            let average = total / (n * (n - 1) / 2) as f64;
+            spacing.push(average / (n as f64).sqrt());
+        }
+
+        assert_eq!(charges[0], 30.0);
+        assert!(charges[1] > charges[0]);
+        let ratio = spacing[1] / spacing[0];
+        assert!((0.8..1.25).contains(&ratio), "spacing ratio {}", ratio);
+        assert_eq!(LayoutConfig::force_scale(5), 1.0);
+        assert_eq!(LayoutConfig::force_scale(40), 2.0);
+    }
 }
@@ -0,0 +1,220 @@
+//! Layered layout for subclass hierarchies
+
+use super::LayoutAlgorithm;
+use crate::graph::{EdgeType, VowlGraph};
+use crate::Result;
+use std::collections::{HashMap, HashSet};
+
+/// Hierarchical layout arranging nodes by `rdfs:subClassOf` depth
+///
+/// Superclasses are placed above their subclasses: y grows with depth and
+/// each parent is centered over its children. Nodes outside any subclass
+/// tree are lined up in a separate band below the deepest level. The layout
+/// is computed in one pass by `initialize`.
+#[derive(Debug, Clone)]
+pub struct TreeLayout {
+    level_gap: f64,
+    sibling_gap: f64,
+    finished: bool,
+}
+
+impl TreeLayout {
+    /// Create a tree layout with default spacing
+    pub fn new() -> Self {
+        Self {
+            level_gap: 80.0,
+            sibling_gap: 60.0,
+            finished: false,
+        }
+    }
+
+    /// Set the vertical distance between levels and horizontal distance between leaves
+    pub fn with_spacing(mut self, level_gap: f64, sibling_gap: f64) -> Self {
+        self.level_gap = level_gap;
+        self.sibling_gap = sibling_gap;
+        self
+    }
+
+    /// Compute `(x, y)` for every node
+    fn compute_positions(&self, graph: &VowlGraph) -> HashMap<String, (f64, f64)> {
+        // Subclass edges point from the subclass to its superclass
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut has_parent: HashSet<&str> = HashSet::new();
+        for (from, edge, to) in graph.edge_endpoints() {
+            if edge.edge_type == EdgeType::SubClass && from != to {
+                children.entry(to).or_default().push(from);
+                has_parent.insert(from);
+            }
+        }
+
+        let roots: Vec<&str> = graph
+            .nodes()
+            .into_iter()
+            .map(|n| n.id.as_str())
+            .filter(|id| children.contains_key(id) && !has_parent.contains(id))
+            .collect();
+
+        let mut positions = HashMap::new();
+        let mut next_leaf = 0.0;
+        let mut max_depth = 0;
+        for root in roots {
+            self.place(root, 0, &children, &mut positions, &mut next_leaf, &mut max_depth);
+        }
+
+        // Anything not reached from a root (unrelated nodes, subclass cycles)
+        let band_y = (max_depth + 2) as f64 * self.level_gap;
+        let mut band_x = 0.0;
+        for node in graph.nodes() {
+            if !positions.contains_key(&node.id) {
+                positions.insert(node.id.clone(), (band_x, band_y));
+                band_x += self.sibling_gap;
+            }
+        }
+
+        positions
+    }
+
+    /// Place a subtree, returning the x of its root
+    ///
+    /// Leaves take consecutive slots; a parent is centered over its first and
+    /// last child. Nodes with several superclasses are placed once, under the
+    /// first parent that reaches them.
+    fn place(
+        &self,
+        id: &str,
+        depth: usize,
+        children: &HashMap<&str, Vec<&str>>,
+        positions: &mut HashMap<String, (f64, f64)>,
+        next_leaf: &mut f64,
+        max_depth: &mut usize,
+    ) -> f64 {
+        // Reserve the slot before recursing so cycles terminate
+        positions.insert(id.to_string(), (0.0, 0.0));
+        *max_depth = (*max_depth).max(depth);
+
+        let mut child_xs = Vec::new();
+        for child in children.get(id).into_iter().flatten() {
+            if !positions.contains_key(*child) {
+                let x = self.place(child, depth + 1, children, positions, next_leaf, max_depth);
+                child_xs.push(x);
+            }
+        }
+
+        let x = match (child_xs.first(), child_xs.last()) {
+            (Some(first), Some(last)) => (first + last) / 2.0,
+            _ => {
+                let x = *next_leaf;
+                *next_leaf += self.sibling_gap;
+                x
+            }
+        };
+
+        positions.insert(id.to_string(), (x, depth as f64 * self.level_gap));
+        x
+    }
+}
+
+impl Default for TreeLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LayoutAlgorithm for TreeLayout {
+    fn initialize(&mut self, graph: &mut VowlGraph) -> Result<()> {
+        for (id, (x, y)) in self.compute_positions(graph) {
+            if let Some(node) = graph.get_node_mut(&id) {
+                if !node.visual.fixed {
+                    node.visual.x = x;
+                    node.visual.y = y;
+                    node.visual.vx = 0.0;
+                    node.visual.vy = 0.0;
+                }
+            }
+        }
+
+        self.finished = true;
+        Ok(())
+    }
+
+    fn tick(&mut self, _graph: &mut VowlGraph) -> Result<()> {
+        self.finished = true;
+        Ok(())
+    }
+
+    fn run(&mut self, graph: &mut VowlGraph, _iterations: usize) -> Result<()> {
+        self.initialize(graph)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn alpha(&self) -> f64 {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    fn create_hierarchy() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        for id in ["animal", "mammal", "dog", "cat", "rock"] {
+            graph.add_node(NodeBuilder::new(id).build()).unwrap();
+        }
+
+        let subclass = |id: &str| EdgeBuilder::new(id).edge_type(EdgeType::SubClass).build();
+        graph.add_edge("mammal", "animal", subclass("s1")).unwrap();
+        graph.add_edge("dog", "mammal", subclass("s2")).unwrap();
+        graph.add_edge("cat", "mammal", subclass("s3")).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_levels_increase_with_depth() {
+        let mut graph = create_hierarchy();
+        let mut layout = TreeLayout::new();
+        layout.initialize(&mut graph).unwrap();
+
+        let y = |id: &str| graph.get_node(id).unwrap().visual.y;
+        assert!(y("animal") < y("mammal"));
+        assert!(y("mammal") < y("dog"));
+        assert_eq!(y("dog"), y("cat"));
+    }
+
+    #[test]
+    fn test_parent_centered_over_children() {
+        let mut graph = create_hierarchy();
+        let mut layout = TreeLayout::new().with_spacing(100.0, 50.0);
+        layout.initialize(&mut graph).unwrap();
+
+        let x = |id: &str| graph.get_node(id).unwrap().visual.x;
+        assert_eq!((x("dog") - x("cat")).abs(), 50.0);
+        assert_eq!(x("mammal"), (x("dog") + x("cat")) / 2.0);
+        assert_eq!(x("animal"), x("mammal"));
+    }
+
+    #[test]
+    fn test_unrelated_nodes_in_separate_band() {
+        let mut graph = create_hierarchy();
+        let mut layout = TreeLayout::new();
+        layout.initialize(&mut graph).unwrap();
+
+        let rock = graph.get_node("rock").unwrap().visual.y;
+        let deepest = graph.get_node("dog").unwrap().visual.y;
+        assert!(rock > deepest);
+    }
+
+    #[test]
+    fn test_finishes_immediately() {
+        let mut graph = create_hierarchy();
+        let mut layout = TreeLayout::new();
+
+        layout.tick(&mut graph).unwrap();
+        assert!(layout.is_finished());
+        assert_eq!(layout.alpha(), 0.0);
+    }
+}
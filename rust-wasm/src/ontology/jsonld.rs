@@ -0,0 +1,320 @@
+//! JSON-LD ontology parser
+//!
+//! Reads ontologies exported as flattened JSON-LD by translating their node
+//! objects into the standard VOWL JSON document, which is then parsed and
+//! validated by [`StandardParser`].
+
+use super::parser::{ParserConfig, StandardParser};
+use super::{OntologyData, OntologyParser, ValidationReport};
+use crate::{Result, VowlError};
+use serde_json::{json, Map, Value};
+
+/// Namespaces whose full term IRIs are shortened before matching
+const TERM_NAMESPACES: [(&str, &str); 2] = [
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+];
+
+/// Parser for ontologies in flattened JSON-LD
+///
+/// Node objects are read from the top-level `@graph`, or from the document
+/// itself when it is an array. Nodes typed `owl:Class` or `rdfs:Class` become
+/// classes and nodes typed `owl:ObjectProperty` or `owl:DatatypeProperty`
+/// become properties, with `rdfs:label`, `rdfs:domain`, `rdfs:range` and
+/// `rdfs:subClassOf` carried over. Types and keys may be written as CURIEs or
+/// full IRIs; other nodes and keys are ignored.
+pub struct JsonLdParser {
+    /// Parser the translated document is handed to
+    standard: StandardParser,
+}
+
+impl JsonLdParser {
+    /// Create a new parser with default configuration
+    pub fn new() -> Self {
+        Self::with_config(ParserConfig::default())
+    }
+
+    /// Create a parser with custom configuration
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            standard: StandardParser::with_config(config),
+        }
+    }
+
+    /// Translate a JSON-LD document into a VOWL JSON document
+    fn translate(&self, document: &Value) -> Result<Value> {
+        let nodes = match document {
+            Value::Array(nodes) => nodes,
+            _ => document
+                .get("@graph")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| VowlError::ParseError("Missing '@graph' array".to_string()))?,
+        };
+
+        let mut header = Map::new();
+        let mut classes = Vec::new();
+        let mut properties = Vec::new();
+        let mut subclass_of = Vec::new();
+
+        for node in nodes {
+            let Some(id) = node.get("@id").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let node_types = types(node);
+            let node_label = label(node);
+
+            if node_types.iter().any(|t| t == "owl:Ontology") {
+                header.insert("iri".to_string(), json!(id));
+                if let Some(title) = node_label.as_ref().and_then(|l| l.as_str()) {
+                    header.insert("title".to_string(), json!(title));
+                }
+                if let Some(version) = term(node, "owl:versionInfo").and_then(literal) {
+                    header.insert("version".to_string(), json!(version));
+                }
+            }
+
+            let is_class = node_types.iter().any(|t| t == "owl:Class" || t == "rdfs:Class");
+            let property_type = node_types
+                .iter()
+                .find(|t| *t == "owl:ObjectProperty" || *t == "owl:DatatypeProperty");
+
+            let mut entry = Map::new();
+            entry.insert("id".to_string(), json!(id));
+            entry.insert("iri".to_string(), json!(id));
+            if let Some(label) = node_label {
+                entry.insert("label".to_string(), label);
+            }
+
+            if is_class {
+                for parent in references(term(node, "rdfs:subClassOf")) {
+                    // Blank nodes are anonymous restrictions, not named classes
+                    if !parent.starts_with("_:") {
+                        subclass_of.push(json!({"from": id, "to": parent}));
+                    }
+                }
+                classes.push(Value::Object(entry));
+            } else if let Some(property_type) = property_type {
+                entry.insert("type".to_string(), json!(property_type));
+                for key in ["domain", "range"] {
+                    let target = references(term(node, &format!("rdfs:{}", key)));
+                    if let Some(target) = target.into_iter().next() {
+                        entry.insert(key.to_string(), json!(target));
+                    }
+                }
+                properties.push(Value::Object(entry));
+            }
+        }
+
+        // Prefixes of the context become the declared namespaces
+        let namespaces: Map<String, Value> = document
+            .get("@context")
+            .and_then(|v| v.as_object())
+            .map(|context| {
+                context
+                    .iter()
+                    .filter(|(prefix, iri)| !prefix.starts_with('@') && iri.is_string())
+                    .map(|(prefix, iri)| (prefix.clone(), iri.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut vowl = json!({
+            "namespace": namespaces,
+            "class": classes,
+            "property": properties,
+            "subClassOf": subclass_of,
+        });
+        if !header.is_empty() {
+            vowl["header"] = Value::Object(header);
+        }
+        Ok(vowl)
+    }
+}
+
+/// Shorten a full OWL or RDFS term IRI to its CURIE
+fn compact(term: &str) -> String {
+    TERM_NAMESPACES
+        .iter()
+        .find_map(|(prefix, iri)| {
+            term.strip_prefix(iri)
+                .map(|local| format!("{}:{}", prefix, local))
+        })
+        .unwrap_or_else(|| term.to_string())
+}
+
+/// Value of a node's key given as the CURIE `name`, or as its full IRI
+fn term<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.as_object()?
+        .iter()
+        .find(|(key, _)| compact(key) == name)
+        .map(|(_, value)| value)
+}
+
+/// The `@type`s of a node as CURIEs where possible
+fn types(node: &Value) -> Vec<String> {
+    match node.get("@type") {
+        Some(Value::String(t)) => vec![compact(t)],
+        Some(Value::Array(ts)) => ts.iter().filter_map(|t| t.as_str()).map(compact).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// IDs referenced by a value, either plain strings or `{"@id": ...}` objects
+fn references(value: Option<&Value>) -> Vec<String> {
+    let one = |v: &Value| match v {
+        Value::String(id) => Some(id.clone()),
+        _ => v.get("@id").and_then(|id| id.as_str()).map(str::to_string),
+    };
+
+    match value {
+        Some(Value::Array(values)) => values.iter().filter_map(one).collect(),
+        Some(v) => one(v).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Text of a literal, either a plain string or a `{"@value": ...}` object
+fn literal(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(text) => Some(text),
+        Value::Array(values) => values.first().and_then(literal),
+        _ => value.get("@value").and_then(|v| v.as_str()),
+    }
+}
+
+/// The `rdfs:label` of a node in VOWL form
+///
+/// A single untagged label stays a string; tagged labels become a language
+/// map, with untagged ones under `und`.
+fn label(node: &Value) -> Option<Value> {
+    let values = match term(node, "rdfs:label")? {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    };
+
+    let mut labels = Map::new();
+    for value in values {
+        let Some(text) = literal(value) else {
+            continue;
+        };
+        let language = value.get("@language").and_then(|v| v.as_str()).unwrap_or("und");
+        labels.entry(language).or_insert_with(|| json!(text));
+    }
+
+    match labels.len() {
+        0 => None,
+        1 if labels.contains_key("und") => labels.remove("und"),
+        _ => Some(Value::Object(labels)),
+    }
+}
+
+impl OntologyParser for JsonLdParser {
+    fn parse(&self, json: &str) -> Result<OntologyData> {
+        let document: Value = serde_json::from_str(json)?;
+        self.standard.parse_value(self.translate(&document)?)
+    }
+
+    fn validate(&self, data: &OntologyData) -> Result<ValidationReport> {
+        self.standard.validate(data)
+    }
+}
+
+impl Default for JsonLdParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flattened_jsonld() {
+        let jsonld = r#"
+        {
+            "@context": {
+                "owl": "http://www.w3.org/2002/07/owl#",
+                "rdfs": "http://www.w3.org/2000/01/rdf-schema#",
+                "ex": "http://example.org/"
+            },
+            "@graph": [
+                {
+                    "@id": "http://example.org/onto",
+                    "@type": "owl:Ontology",
+                    "rdfs:label": "People"
+                },
+                {
+                    "@id": "ex:Person",
+                    "@type": "owl:Class",
+                    "rdfs:label": [
+                        {"@value": "Person", "@language": "en"},
+                        {"@value": "Person", "@language": "de"}
+                    ]
+                },
+                {
+                    "@id": "ex:Student",
+                    "@type": "http://www.w3.org/2002/07/owl#Class",
+                    "rdfs:label": "Student",
+                    "rdfs:subClassOf": [{"@id": "ex:Person"}, {"@id": "_:restriction"}]
+                },
+                {
+                    "@id": "ex:knows",
+                    "@type": ["owl:ObjectProperty"],
+                    "http://www.w3.org/2000/01/rdf-schema#label": {"@value": "knows"},
+                    "rdfs:domain": {"@id": "ex:Person"},
+                    "rdfs:range": {"@id": "ex:Person"}
+                },
+                {"@id": "_:restriction", "@type": "owl:Restriction"}
+            ]
+        }
+        "#;
+
+        let expected = r#"
+        {
+            "header": {"iri": "http://example.org/onto", "title": "People"},
+            "namespace": {
+                "owl": "http://www.w3.org/2002/07/owl#",
+                "rdfs": "http://www.w3.org/2000/01/rdf-schema#",
+                "ex": "http://example.org/"
+            },
+            "class": [
+                {"id": "ex:Person", "iri": "ex:Person", "label": {"en": "Person", "de": "Person"}},
+                {"id": "ex:Student", "iri": "ex:Student", "label": "Student"}
+            ],
+            "property": [
+                {
+                    "id": "ex:knows",
+                    "iri": "ex:knows",
+                    "label": "knows",
+                    "type": "owl:ObjectProperty",
+                    "domain": "ex:Person",
+                    "range": "ex:Person"
+                }
+            ],
+            "subClassOf": [{"from": "ex:Student", "to": "ex:Person"}]
+        }
+        "#;
+
+        let parser = JsonLdParser::new();
+        let data = parser.parse(jsonld).unwrap();
+
+        assert_eq!(data, StandardParser::new().parse(expected).unwrap());
+        assert_eq!(data.classes.len(), 2);
+        assert_eq!(data.properties.len(), 2);
+        assert_eq!(data.properties[0].domain, "ex:Person");
+        assert_eq!(data.properties[1].range, "ex:Person");
+        assert!(parser.validate(&data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_jsonld_without_graph() {
+        let parser = JsonLdParser::new();
+        assert!(parser.parse(r#"{"@id": "ex:Person"}"#).is_err());
+
+        let data = parser
+            .parse(r#"[{"@id": "ex:Person", "@type": "owl:Class"}]"#)
+            .unwrap();
+        assert_eq!(data.classes[0].label, "ex:Person");
+    }
+}
@@ -4,10 +4,37 @@
 //! converting them into internal graph representations.
 
 pub mod parser;
+pub mod jsonld;
 pub mod model;
 
 use crate::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Class ID standing for `owl:Thing`, used when a property has no domain or range
+pub const OWL_THING: &str = "owl:Thing";
+
+/// Full IRI of `owl:Thing`
+pub const OWL_THING_IRI: &str = "http://www.w3.org/2002/07/owl#Thing";
+
+/// Full IRI of `owl:Nothing`
+pub const OWL_NOTHING_IRI: &str = "http://www.w3.org/2002/07/owl#Nothing";
+
+/// Special property type of `rdfs:subClassOf` relations
+pub const RDFS_SUBCLASS_OF: &str = "rdfs:subClassOf";
+
+/// Special property type of `owl:disjointWith` relations
+pub const OWL_DISJOINT_WITH: &str = "owl:disjointWith";
+
+/// Special property type of `owl:sameAs` and `skos:exactMatch` equivalences
+pub const SAME_AS: &str = "sameAs";
+
+/// Namespaces whose datatype IRIs are shortened to `prefix:local`
+pub const DATATYPE_NAMESPACES: [(&str, &str); 3] = [
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+];
 
 /// Trait for parsing OWL ontology data
 #[cfg_attr(test, mockall::automock)]
@@ -16,7 +43,10 @@ pub trait OntologyParser {
     fn parse(&self, json: &str) -> Result<OntologyData>;
 
     /// Validate ontology structure
-    fn validate(&self, data: &OntologyData) -> Result<()>;
+    ///
+    /// Hard errors are returned as `Err`; otherwise the report lists any
+    /// non-fatal warnings.
+    fn validate(&self, data: &OntologyData) -> Result<ValidationReport>;
 }
 
 /// Represents parsed OWL ontology data
@@ -33,6 +63,65 @@ pub struct OntologyData {
 
     /// Namespace definitions
     pub namespaces: Vec<Namespace>,
+
+    /// Errors of class and property entries dropped while parsing with
+    /// `skip_invalid_entries`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_entries: Vec<String>,
+}
+
+impl OntologyData {
+    /// Compare the namespace prefixes used by class and property IRIs with
+    /// the declared namespaces
+    ///
+    /// A prefix counts as used when an IRI is a CURIE with that prefix
+    /// (`foaf:Person`) or a full IRI starting with the declared namespace IRI.
+    pub fn namespace_usage(&self) -> NamespaceUsage {
+        let mut used = std::collections::BTreeSet::new();
+        let mut undeclared = std::collections::BTreeSet::new();
+
+        let iris = self
+            .classes
+            .iter()
+            .map(|c| c.iri.as_str())
+            .chain(self.properties.iter().map(|p| p.iri.as_str()));
+
+        for iri in iris {
+            if let Some(ns) = self
+                .namespaces
+                .iter()
+                .find(|ns| !ns.iri.is_empty() && iri.starts_with(&ns.iri))
+            {
+                used.insert(ns.prefix.clone());
+                continue;
+            }
+
+            let Some((prefix, rest)) = iri.split_once(':') else {
+                continue;
+            };
+            if prefix.is_empty() || rest.starts_with("//") || prefix.contains(['/', '#']) {
+                continue;
+            }
+
+            used.insert(prefix.to_string());
+            if !self.namespaces.iter().any(|ns| ns.prefix == prefix) {
+                undeclared.insert(prefix.to_string());
+            }
+        }
+
+        let declared_unused = self
+            .namespaces
+            .iter()
+            .filter(|ns| !used.contains(&ns.prefix))
+            .map(|ns| ns.prefix.clone())
+            .collect();
+
+        NamespaceUsage {
+            used: used.into_iter().collect(),
+            declared_unused,
+            used_undeclared: undeclared.into_iter().collect(),
+        }
+    }
 }
 
 /// Ontology metadata
@@ -60,9 +149,18 @@ pub struct ClassNode {
     /// IRI of the class
     pub iri: String,
 
+    /// Prefixed form of `iri` (e.g. `pizza:Margherita`) when it was expanded
+    /// against the declared namespaces
+    #[serde(default)]
+    pub curie: Option<String>,
+
     /// Label for display
     pub label: String,
 
+    /// Labels keyed by language tag, when given as a language map
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
     /// Class type (e.g., owl:Class, rdfs:Class)
     pub class_type: String,
 
@@ -82,10 +180,28 @@ pub struct ClassAttributes {
     /// Individuals count
     pub individuals: Option<usize>,
 
+    /// Whether the class is marked `owl:deprecated`
+    #[serde(default)]
+    pub deprecated: bool,
+
+    /// Named individuals, when listed rather than only counted
+    #[serde(default)]
+    pub instances: Vec<Individual>,
+
     /// Additional properties
     pub properties: std::collections::HashMap<String, String>,
 }
 
+/// A named individual of a class
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Individual {
+    /// Unique identifier
+    pub id: String,
+
+    /// Label for display
+    pub label: String,
+}
+
 /// Represents an OWL property (object or datatype property)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Property {
@@ -95,9 +211,18 @@ pub struct Property {
     /// IRI of the property
     pub iri: String,
 
+    /// Prefixed form of `iri` when it was expanded against the declared
+    /// namespaces
+    #[serde(default)]
+    pub curie: Option<String>,
+
     /// Label for display
     pub label: String,
 
+    /// Labels keyed by language tag, when given as a language map
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
     /// Property type
     pub property_type: PropertyType,
 
@@ -109,6 +234,18 @@ pub struct Property {
 
     /// Property characteristics
     pub characteristics: PropertyCharacteristics,
+
+    /// Property chain (`owl:propertyChainAxiom`) this property is composed of
+    #[serde(default)]
+    pub chain: Vec<String>,
+
+    /// ID of the property this one is the inverse of (`owl:inverseOf`)
+    #[serde(default)]
+    pub inverse_of: Option<String>,
+
+    /// IDs of the super-properties (`rdfs:subPropertyOf`)
+    #[serde(default)]
+    pub sub_property_of: Vec<String>,
 }
 
 /// Type of OWL property
@@ -159,6 +296,30 @@ pub struct Cardinality {
     pub exact: Option<u32>,
 }
 
+/// Outcome of validating ontology data
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ValidationReport {
+    /// Hard errors that make the data unusable
+    pub errors: Vec<String>,
+
+    /// Non-fatal issues worth surfacing to the user
+    pub warnings: Vec<String>,
+
+    /// IDs of the properties that were checked
+    pub checked: Vec<String>,
+
+    /// Errors of entries that were skipped while parsing
+    #[serde(default)]
+    pub skipped: Vec<String>,
+}
+
+impl ValidationReport {
+    /// Check if no errors were found
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 /// Namespace definition
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Namespace {
@@ -169,6 +330,19 @@ pub struct Namespace {
     pub iri: String,
 }
 
+/// Comparison of used and declared namespace prefixes
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NamespaceUsage {
+    /// Prefixes referenced by at least one class or property IRI
+    pub used: Vec<String>,
+
+    /// Declared prefixes no IRI refers to
+    pub declared_unused: Vec<String>,
+
+    /// Prefixes used in CURIEs without a namespace declaration
+    pub used_undeclared: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -187,23 +361,72 @@ mod tests {
             classes: vec![],
             properties: vec![],
             namespaces: vec![],
+            skipped_entries: vec![],
         };
 
         assert_eq!(data.classes.len(), 0);
         assert_eq!(data.metadata.iri, "http://example.org/ontology");
     }
 
+    #[test]
+    fn test_namespace_usage() {
+        let class = |iri: &str| ClassNode {
+            id: iri.to_string(),
+            iri: iri.to_string(),
+            curie: None,
+            label: iri.to_string(),
+            labels: Default::default(),
+            class_type: "owl:Class".to_string(),
+            equivalent: vec![],
+            attributes: ClassAttributes::default(),
+        };
+        let namespace = |prefix: &str, iri: &str| Namespace {
+            prefix: prefix.to_string(),
+            iri: iri.to_string(),
+        };
+
+        let data = OntologyData {
+            metadata: OntologyMetadata {
+                iri: "http://example.org/ontology".to_string(),
+                version: None,
+                title: None,
+                description: None,
+            },
+            classes: vec![
+                class("foaf:Person"),
+                class("http://example.org/ontology#Thing"),
+                class("schema:Event"),
+            ],
+            properties: vec![],
+            namespaces: vec![
+                namespace("foaf", "http://xmlns.com/foaf/0.1/"),
+                namespace("ex", "http://example.org/ontology#"),
+                namespace("dc", "http://purl.org/dc/elements/1.1/"),
+            ],
+            skipped_entries: vec![],
+        };
+
+        let usage = data.namespace_usage();
+        assert_eq!(usage.used, vec!["ex", "foaf", "schema"]);
+        assert_eq!(usage.declared_unused, vec!["dc"]);
+        assert_eq!(usage.used_undeclared, vec!["schema"]);
+    }
+
     #[test]
     fn test_class_node_with_attributes() {
         let class = ClassNode {
             id: "class1".to_string(),
             iri: "http://example.org/Class1".to_string(),
+            curie: None,
             label: "Class 1".to_string(),
+            labels: Default::default(),
             class_type: "owl:Class".to_string(),
             equivalent: vec![],
             attributes: ClassAttributes {
                 external: false,
                 individuals: Some(10),
+                deprecated: false,
+                instances: vec![],
                 properties: std::collections::HashMap::new(),
             },
         };
@@ -217,7 +440,9 @@ mod tests {
         let prop = Property {
             id: "prop1".to_string(),
             iri: "http://example.org/prop1".to_string(),
+            curie: None,
             label: "Property 1".to_string(),
+            labels: Default::default(),
             property_type: PropertyType::ObjectProperty,
             domain: "class1".to_string(),
             range: "class2".to_string(),
@@ -232,6 +457,9 @@ mod tests {
                     exact: None,
                 }),
             },
+            chain: vec![],
+            inverse_of: None,
+            sub_property_of: vec![],
         };
 
         assert!(prop.characteristics.functional);
@@ -45,6 +45,48 @@ pub enum SetOperator {
     DisjointUnion,
 }
 
+impl SetOperator {
+    /// Parse an operator from its short name (`union`) or OWL name (`owl:unionOf`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "union" | "owl:unionOf" => Some(Self::Union),
+            "intersection" | "owl:intersectionOf" => Some(Self::Intersection),
+            "complement" | "owl:complementOf" => Some(Self::Complement),
+            "disjointUnion" | "owl:disjointUnionOf" => Some(Self::DisjointUnion),
+            _ => None,
+        }
+    }
+
+    /// Short name, used as the special node and edge type
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Union => "union",
+            Self::Intersection => "intersection",
+            Self::Complement => "complement",
+            Self::DisjointUnion => "disjointUnion",
+        }
+    }
+
+    /// OWL property name of the operator
+    pub fn owl_name(&self) -> &'static str {
+        match self {
+            Self::Union => "owl:unionOf",
+            Self::Intersection => "owl:intersectionOf",
+            Self::Complement => "owl:complementOf",
+            Self::DisjointUnion => "owl:disjointUnionOf",
+        }
+    }
+
+    /// Symbol drawn inside the operator node
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Union | Self::DisjointUnion => "\u{222A}",
+            Self::Intersection => "\u{2229}",
+            Self::Complement => "\u{00AC}",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,6 +97,20 @@ mod tests {
         assert_eq!(OwlClassType::Nothing, OwlClassType::Nothing);
     }
 
+    #[test]
+    fn test_set_operator_names() {
+        for op in [
+            SetOperator::Union,
+            SetOperator::Intersection,
+            SetOperator::Complement,
+            SetOperator::DisjointUnion,
+        ] {
+            assert_eq!(SetOperator::from_name(op.name()), Some(op));
+            assert_eq!(SetOperator::from_name(op.owl_name()), Some(op));
+        }
+        assert_eq!(SetOperator::from_name("xor"), None);
+    }
+
     #[test]
     fn test_property_types() {
         let prop = OwlPropertyType::ObjectProperty;
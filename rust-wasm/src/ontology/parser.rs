@@ -1,9 +1,13 @@
 //! OWL ontology parser implementation
 
+use super::model::SetOperator;
 use super::*;
 use crate::{Result, VowlError};
-use serde_json::Value;
-use std::collections::HashMap;
+use serde::de::{self, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::Read;
 
 /// Standard OWL ontology parser
 pub struct StandardParser {
@@ -20,8 +24,30 @@ pub struct ParserConfig {
     /// Allow empty labels
     pub allow_empty_labels: bool,
 
-    /// Maximum classes to parse (0 = unlimited)
+    /// Maximum classes to parse (0 = unlimited), counting the classes of
+    /// set operators; larger documents fail with `VowlError::LimitExceeded`,
+    /// or with `skip_invalid_entries` keep the first classes and drop
+    /// properties referencing the others
     pub max_classes: usize,
+
+    /// Skip class and property entries that fail to parse instead of
+    /// failing the whole document; their errors are kept in
+    /// `OntologyData::skipped_entries`
+    pub skip_invalid_entries: bool,
+
+    /// Language tag whose label becomes the display label of multilingual
+    /// entities; other tags are used as a fallback
+    pub preferred_language: Option<String>,
+
+    /// Reject documents outside the documented schema: unknown top-level
+    /// keys, both the singular and plural form of an array, and properties
+    /// without an explicit `domain`, `range` and `type`. Such documents
+    /// fail even with `skip_invalid_entries`
+    pub strict_schema: bool,
+
+    /// Expand prefixed IRIs of classes and properties against the declared
+    /// namespaces, keeping the prefixed form in `curie`
+    pub expand_prefixes: bool,
 }
 
 impl Default for ParserConfig {
@@ -30,6 +56,10 @@ impl Default for ParserConfig {
             validate_iris: true,
             allow_empty_labels: false,
             max_classes: 0,
+            skip_invalid_entries: false,
+            preferred_language: None,
+            strict_schema: false,
+            expand_prefixes: false,
         }
     }
 }
@@ -47,26 +77,125 @@ impl StandardParser {
         Self { config }
     }
 
-    /// Parse class nodes from JSON value
-    fn parse_classes(&self, json: &Value) -> Result<Vec<ClassNode>> {
-        let classes_array = json
-            .get("class")
-            .or_else(|| json.get("classes"))
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| VowlError::ParseError("Missing 'class' array".to_string()))?;
+    /// Parse an ontology document from a reader
+    ///
+    /// The `class` and `property` arrays are streamed: each entry is parsed
+    /// and dropped before the next one is read, so no JSON tree of the whole
+    /// document is ever built.
+    pub fn parse_reader<R: Read>(&self, reader: R) -> Result<OntologyData> {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let mut entry_error = None;
+
+        let document = DocumentVisitor {
+            parser: self,
+            error: &mut entry_error,
+        }
+        .deserialize(&mut deserializer)
+        .and_then(|document| deserializer.end().map(|_| document));
+
+        // Report entry errors as-is rather than wrapped in a JSON error
+        match document {
+            Ok(document) => self.assemble(document),
+            Err(e) => Err(entry_error.unwrap_or_else(|| e.into())),
+        }
+    }
 
-        let mut classes = Vec::new();
+    /// Parse an ontology document that is already a JSON tree
+    pub(super) fn parse_value(&self, value: Value) -> Result<OntologyData> {
+        let mut entry_error = None;
 
-        for (idx, class_json) in classes_array.iter().enumerate() {
-            if self.config.max_classes > 0 && idx >= self.config.max_classes {
-                break;
+        let document = DocumentVisitor {
+            parser: self,
+            error: &mut entry_error,
+        }
+        .deserialize(value);
+
+        match document {
+            Ok(document) => self.assemble(document),
+            Err(e) => Err(entry_error.unwrap_or_else(|| e.into())),
+        }
+    }
+
+    /// Resolve the sections of a deserialized document into ontology data
+    fn assemble(&self, document: Document) -> Result<OntologyData> {
+        let rest = Value::Object(document.rest);
+        let metadata = self.parse_metadata(&rest)?;
+        let mut classes = document
+            .classes
+            .ok_or_else(|| VowlError::ParseError("Missing 'class' array".to_string()))?;
+        let mut properties = document
+            .properties
+            .ok_or_else(|| VowlError::ParseError("Missing 'property' array".to_string()))?;
+        properties.extend(self.parse_relations(&rest, "subClassOf", RDFS_SUBCLASS_OF)?);
+        properties.extend(self.parse_relations(&rest, "disjoint", OWL_DISJOINT_WITH)?);
+        properties.extend(self.parse_relations(&rest, "sameAs", SAME_AS)?);
+        let (mut operators, memberships) = self.parse_set_operators(&rest)?;
+        let mut skipped = document.skipped;
+        let mut truncated = document.truncated;
+
+        // Set operators are classes too and count against `max_classes`
+        let room = self.config.max_classes.saturating_sub(classes.len());
+        if self.config.max_classes > 0 && operators.len() > room {
+            let error = VowlError::LimitExceeded {
+                limit: "max_classes".to_string(),
+                value: classes.len() + truncated.count + operators.len(),
+            };
+            if !self.config.skip_invalid_entries {
+                return Err(error);
+            }
+            for operator in operators.split_off(room) {
+                skipped.push(format!("setOperator '{}': {}", operator.id, error));
+                truncated.count += 1;
+                truncated.ids.insert(operator.id);
+            }
+        }
+        classes.extend(operators);
+        properties.extend(memberships);
+        let namespaces = self.parse_namespaces(&rest)?;
+        if self.config.expand_prefixes {
+            for class in &mut classes {
+                expand_prefix(&mut class.iri, &mut class.curie, &namespaces);
+            }
+            for property in &mut properties {
+                expand_prefix(&mut property.iri, &mut property.curie, &namespaces);
             }
+        }
 
-            let class = self.parse_class_node(class_json)?;
-            classes.push(class);
+        // Drop properties left dangling by classes beyond `max_classes`
+        if !truncated.ids.is_empty() {
+            properties.retain(|property| {
+                let dropped = [&property.domain, &property.range]
+                    .into_iter()
+                    .find(|id| truncated.ids.contains(id.as_str()));
+                if let Some(id) = dropped {
+                    skipped.push(format!(
+                        "property '{}': class '{}' was dropped by max_classes",
+                        property.id, id
+                    ));
+                }
+                dropped.is_none()
+            });
         }
 
-        Ok(classes)
+        Ok(OntologyData {
+            metadata,
+            classes,
+            properties,
+            namespaces,
+            skipped_entries: skipped,
+        })
+    }
+
+    /// Parse a single class given as a JSON object
+    pub fn parse_class_fragment(&self, json: &str) -> Result<ClassNode> {
+        let value: Value = serde_json::from_str(json)?;
+        self.parse_class_node(&value)
+    }
+
+    /// Parse a single property given as a JSON object
+    pub fn parse_property_fragment(&self, json: &str) -> Result<Property> {
+        let value: Value = serde_json::from_str(json)?;
+        self.parse_property(&value)
     }
 
     /// Parse a single class node
@@ -77,17 +206,9 @@ impl StandardParser {
             .ok_or_else(|| VowlError::ParseError("Missing class id".to_string()))?
             .to_string();
 
-        let iri = json
-            .get("iri")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| id.clone());
+        let iri = self.parse_iri(json, &id)?;
 
-        let label = json
-            .get("label")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| id.clone());
+        let (label, labels) = self.parse_labels(json, &id);
 
         if !self.config.allow_empty_labels && label.is_empty() {
             return Err(VowlError::ParseError(format!(
@@ -118,7 +239,9 @@ impl StandardParser {
         Ok(ClassNode {
             id,
             iri,
+            curie: None,
             label,
+            labels,
             class_type,
             equivalent,
             attributes,
@@ -132,15 +255,38 @@ impl StandardParser {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        let individuals = json
-            .get("individuals")
-            .and_then(|v| v.as_u64())
-            .map(|n| n as usize);
+        // Individuals are given either as a count or as a list of {id, label}
+        let mut instances = Vec::new();
+        if let Some(entries) = json.get("individuals").and_then(|v| v.as_array()) {
+            for entry in entries {
+                let id = entry
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| VowlError::ParseError("Missing individual id".to_string()))?;
+                let label = entry.get("label").and_then(|v| v.as_str()).unwrap_or(id);
+                instances.push(Individual {
+                    id: id.to_string(),
+                    label: label.to_string(),
+                });
+            }
+        }
+        let individuals = match json.get("individuals") {
+            Some(Value::Array(_)) => Some(instances.len()),
+            Some(count) => count.as_u64().map(|n| n as usize),
+            None => None,
+        };
+
+        let mut deprecated = json
+            .get("deprecated")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
 
         let mut properties = HashMap::new();
         if let Some(attrs) = json.get("attributes").and_then(|v| v.as_object()) {
             for (key, value) in attrs {
-                if let Some(s) = value.as_str() {
+                if key == "deprecated" {
+                    deprecated |= value.as_bool().unwrap_or(false);
+                } else if let Some(s) = value.as_str() {
                     properties.insert(key.clone(), s.to_string());
                 }
             }
@@ -149,24 +295,12 @@ impl StandardParser {
         Ok(ClassAttributes {
             external,
             individuals,
+            deprecated,
+            instances,
             properties,
         })
     }
 
-    /// Parse properties from JSON value
-    fn parse_properties(&self, json: &Value) -> Result<Vec<Property>> {
-        let properties_array = json
-            .get("property")
-            .or_else(|| json.get("properties"))
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| VowlError::ParseError("Missing 'property' array".to_string()))?;
-
-        properties_array
-            .iter()
-            .map(|prop_json| self.parse_property(prop_json))
-            .collect()
-    }
-
     /// Parse a single property
     fn parse_property(&self, json: &Value) -> Result<Property> {
         let id = json
@@ -175,45 +309,215 @@ impl StandardParser {
             .ok_or_else(|| VowlError::ParseError("Missing property id".to_string()))?
             .to_string();
 
-        let iri = json
-            .get("iri")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| id.clone());
+        let iri = self.parse_iri(json, &id)?;
 
-        let label = json
-            .get("label")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| id.clone());
+        let (label, labels) = self.parse_labels(json, &id);
 
         let property_type = self.parse_property_type(json)?;
 
+        // An unbounded domain or range means owl:Thing
         let domain = json
             .get("domain")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| VowlError::ParseError(format!("Missing domain for property: {}", id)))?
+            .unwrap_or(OWL_THING)
             .to_string();
 
         let range = json
             .get("range")
             .and_then(|v| v.as_str())
-            .ok_or_else(|| VowlError::ParseError(format!("Missing range for property: {}", id)))?
+            .unwrap_or(OWL_THING)
             .to_string();
 
         let characteristics = self.parse_property_characteristics(json)?;
 
+        let chain = json
+            .get("propertyChain")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let inverse_of = json
+            .get("inverseOf")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        // A single super-property may be given as a plain string
+        let sub_property_of = match json.get("subPropertyOf") {
+            Some(Value::String(parent)) => vec![parent.clone()],
+            Some(Value::Array(parents)) => parents
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_string())
+                .collect(),
+            _ => Vec::new(),
+        };
+
         Ok(Property {
             id,
             iri,
+            curie: None,
             label,
+            labels,
             property_type,
             domain,
             range,
             characteristics,
+            chain,
+            inverse_of,
+            sub_property_of,
         })
     }
 
+    /// Parse a top-level array of `{from, to}` class pairs into special properties
+    ///
+    /// A missing section yields no properties. Property IDs are derived from
+    /// the section key and the pair.
+    fn parse_relations(&self, json: &Value, key: &str, kind: &str) -> Result<Vec<Property>> {
+        let Some(pairs) = json.get(key).and_then(|v| v.as_array()) else {
+            return Ok(Vec::new());
+        };
+
+        pairs
+            .iter()
+            .map(|pair| {
+                let end = |field: &str| {
+                    pair.get(field)
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string())
+                        .ok_or_else(|| {
+                            VowlError::ParseError(format!("Missing '{}' in {} entry", field, key))
+                        })
+                };
+                let (from, to) = (end("from")?, end("to")?);
+
+                Ok(Property {
+                    id: format!("{}:{}:{}", key, from, to),
+                    iri: kind.to_string(),
+                    curie: None,
+                    label: String::new(),
+                    labels: HashMap::new(),
+                    property_type: PropertyType::SpecialProperty(kind.to_string()),
+                    domain: from,
+                    range: to,
+                    characteristics: PropertyCharacteristics::default(),
+                    chain: Vec::new(),
+                    inverse_of: None,
+                    sub_property_of: Vec::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// Parse the `setOperators` section
+    ///
+    /// Each entry `{id, operator, members}` becomes a class node typed by the
+    /// operator's short name plus one membership relation per member class.
+    fn parse_set_operators(&self, json: &Value) -> Result<(Vec<ClassNode>, Vec<Property>)> {
+        let Some(entries) = json.get("setOperators").and_then(|v| v.as_array()) else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+
+        let mut nodes = Vec::new();
+        let mut memberships = Vec::new();
+        for entry in entries {
+            let id = entry
+                .get("id")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| VowlError::ParseError("Missing set operator id".to_string()))?
+                .to_string();
+            let name = entry.get("operator").and_then(|v| v.as_str()).unwrap_or_default();
+            let operator = SetOperator::from_name(name).ok_or_else(|| {
+                VowlError::ParseError(format!("Unknown set operator for {}: '{}'", id, name))
+            })?;
+            let members: Vec<String> = entry
+                .get("members")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(String::from).collect())
+                .unwrap_or_default();
+
+            for member in members {
+                memberships.push(Property {
+                    id: format!("setOperators:{}:{}", id, member),
+                    iri: operator.owl_name().to_string(),
+                    curie: None,
+                    label: String::new(),
+                    labels: HashMap::new(),
+                    property_type: PropertyType::SpecialProperty(operator.name().to_string()),
+                    domain: id.clone(),
+                    range: member,
+                    characteristics: PropertyCharacteristics::default(),
+                    chain: Vec::new(),
+                    inverse_of: None,
+                    sub_property_of: Vec::new(),
+                });
+            }
+
+            nodes.push(ClassNode {
+                iri: self.parse_iri(entry, &id)?,
+                curie: None,
+                id,
+                label: operator.symbol().to_string(),
+                labels: HashMap::new(),
+                class_type: operator.name().to_string(),
+                equivalent: Vec::new(),
+                attributes: ClassAttributes::default(),
+            });
+        }
+
+        Ok((nodes, memberships))
+    }
+
+    /// Read the `iri` of an entity, defaulting to its ID
+    ///
+    /// With `validate_iris` set, an explicit IRI must be an absolute URI or a
+    /// prefixed name (`prefix:local`).
+    fn parse_iri(&self, json: &Value, id: &str) -> Result<String> {
+        let Some(iri) = json.get("iri").and_then(|v| v.as_str()) else {
+            return Ok(id.to_string());
+        };
+
+        if self.config.validate_iris && !is_valid_iri(iri) {
+            return Err(VowlError::ParseError(format!(
+                "Invalid IRI for {}: '{}'",
+                id, iri
+            )));
+        }
+
+        Ok(iri.to_string())
+    }
+
+    /// Parse a label given either as a plain string or as a language map
+    ///
+    /// Returns the display label and the per-language labels. The display
+    /// label comes from the preferred language if present, otherwise from the
+    /// first language tag in alphabetical order, otherwise `fallback`.
+    fn parse_labels(&self, json: &Value, fallback: &str) -> (String, HashMap<String, String>) {
+        let labels: HashMap<String, String> = match json.get("label") {
+            Some(Value::String(label)) => return (label.clone(), HashMap::new()),
+            Some(Value::Object(map)) => map
+                .iter()
+                .filter_map(|(lang, v)| v.as_str().map(|s| (lang.clone(), s.to_string())))
+                .collect(),
+            _ => HashMap::new(),
+        };
+
+        let label = self
+            .config
+            .preferred_language
+            .as_ref()
+            .and_then(|lang| labels.get(lang))
+            .or_else(|| labels.iter().min_by_key(|(lang, _)| *lang).map(|(_, l)| l))
+            .cloned()
+            .unwrap_or_else(|| fallback.to_string());
+
+        (label, labels)
+    }
+
     /// Parse property type
     fn parse_property_type(&self, json: &Value) -> Result<PropertyType> {
         let type_str = json
@@ -263,12 +567,10 @@ impl StandardParser {
     }
 
     /// Parse cardinality constraints
+    ///
+    /// `minCardinality`, `maxCardinality` and `cardinality` (exact) are read
+    /// independently; any one of them is enough to produce a constraint.
     fn parse_cardinality(&self, json: &Value) -> Result<Option<Cardinality>> {
-        let cardinality = json.get("cardinality");
-        if cardinality.is_none() {
-            return Ok(None);
-        }
-
         let min = json
             .get("minCardinality")
             .and_then(|v| v.as_u64())
@@ -310,6 +612,115 @@ impl StandardParser {
         Ok(vec![])
     }
 
+    /// Check the given properties against the known classes and properties
+    fn check_properties<'a>(
+        &self,
+        is_class: impl Fn(&str) -> bool,
+        is_property: impl Fn(&str) -> bool,
+        properties: impl IntoIterator<Item = &'a Property>,
+    ) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        for prop in properties {
+            report.checked.push(prop.id.clone());
+
+            if !is_class(&prop.domain) && prop.domain != OWL_THING {
+                report.errors.push(format!(
+                    "Property '{}' references unknown domain class: {}",
+                    prop.id, prop.domain
+                ));
+            }
+
+            // Range might be a datatype, so we're more lenient
+            if !is_class(&prop.range)
+                && !prop.range.starts_with("xsd:")
+                && prop.range != OWL_THING
+            {
+                report.warnings.push(format!(
+                    "Property '{}' references possibly unknown range: {}",
+                    prop.id, prop.range
+                ));
+            }
+
+            for link in &prop.chain {
+                if !is_property(link) {
+                    report.errors.push(format!(
+                        "Property '{}' chain references unknown property: {}",
+                        prop.id, link
+                    ));
+                }
+            }
+        }
+
+        report
+    }
+
+    /// IDs given to more than one entity, in order of first repetition
+    fn duplicate_ids<'a>(ids: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut seen = HashSet::new();
+        let mut duplicates = Vec::new();
+        for id in ids {
+            if !seen.insert(id) && !duplicates.contains(&id) {
+                duplicates.push(id);
+            }
+        }
+        duplicates
+    }
+
+    /// Validate every class and property in the ontology
+    fn validation_report(&self, data: &OntologyData) -> ValidationReport {
+        let class_ids: HashSet<&str> = data.classes.iter().map(|c| c.id.as_str()).collect();
+        let property_ids: HashSet<&str> = data.properties.iter().map(|p| p.id.as_str()).collect();
+        let mut report = self.check_properties(
+            |id| class_ids.contains(id),
+            |id| property_ids.contains(id),
+            &data.properties,
+        );
+        report.skipped = data.skipped_entries.clone();
+
+        // Duplicates come first, as they make the per-property errors ambiguous
+        let classes = Self::duplicate_ids(data.classes.iter().map(|c| c.id.as_str()));
+        let properties = Self::duplicate_ids(data.properties.iter().map(|p| p.id.as_str()));
+        let duplicates = [("class", classes), ("property", properties)]
+            .into_iter()
+            .filter(|(_, ids)| !ids.is_empty())
+            .map(|(kind, ids)| format!("Duplicate {} IDs: {}", kind, ids.join(", ")));
+        report.errors.splice(0..0, duplicates);
+        report
+    }
+
+    /// Re-validate only the properties affected by changed classes or properties
+    ///
+    /// A property is re-checked when its own ID, its domain or its range is
+    /// listed in `changed_ids`, which must also list removed or renamed IDs.
+    /// The `index` is brought up to date from the changed IDs alone and
+    /// should be kept for the next call, so unchanged properties are not
+    /// scanned again.
+    pub fn validate_incremental(
+        &self,
+        data: &OntologyData,
+        index: &mut ValidationIndex,
+        changed_ids: &[String],
+    ) -> ValidationReport {
+        index.refresh(data, changed_ids);
+
+        let mut affected: Vec<usize> = changed_ids
+            .iter()
+            .filter_map(|id| index.dependents.get(id))
+            .flatten()
+            .chain(changed_ids.iter().filter_map(|id| index.properties.get(id)))
+            .copied()
+            .collect();
+        affected.sort_unstable();
+        affected.dedup();
+
+        self.check_properties(
+            |id| index.classes.contains_key(id),
+            |id| index.properties.contains_key(id),
+            affected.into_iter().map(|i| &data.properties[i]),
+        )
+    }
+
     /// Parse ontology metadata
     fn parse_metadata(&self, json: &Value) -> Result<OntologyMetadata> {
         let header = json.get("header");
@@ -344,46 +755,106 @@ impl StandardParser {
     }
 }
 
-impl OntologyParser for StandardParser {
-    fn parse(&self, json: &str) -> Result<OntologyData> {
-        let value: Value = serde_json::from_str(json)?;
-
-        let metadata = self.parse_metadata(&value)?;
-        let classes = self.parse_classes(&value)?;
-        let properties = self.parse_properties(&value)?;
-        let namespaces = self.parse_namespaces(&value)?;
+/// Lookup tables kept between calls to [`StandardParser::validate_incremental`]
+///
+/// Entries appended to the ontology and properties edited in place are
+/// indexed from the changed IDs alone. Removing or reordering entries makes
+/// the next call rebuild the tables, as positions no longer match.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationIndex {
+    /// Position of each class ID in `OntologyData::classes`
+    classes: HashMap<String, usize>,
+    /// Position of each property ID in `OntologyData::properties`
+    properties: HashMap<String, usize>,
+    /// Positions of the properties whose domain or range is each class ID
+    dependents: HashMap<String, Vec<usize>>,
+    /// Domain and range each indexed property was filed under
+    endpoints: Vec<(String, String)>,
+    /// Number of classes indexed so far
+    class_count: usize,
+}
 
-        Ok(OntologyData {
-            metadata,
-            classes,
-            properties,
-            namespaces,
-        })
+impl ValidationIndex {
+    /// Index every class and property of the ontology
+    pub fn new(data: &OntologyData) -> Self {
+        let mut index = Self::default();
+        index.append(data);
+        index
     }
 
-    fn validate(&self, data: &OntologyData) -> Result<()> {
-        // Validate that all property domains and ranges reference valid classes
-        let class_ids: HashMap<_, _> = data.classes.iter().map(|c| (&c.id, ())).collect();
+    /// Bring the index up to date with entries appended or changed since the
+    /// last call
+    fn refresh(&mut self, data: &OntologyData, changed_ids: &[String]) {
+        let moved = changed_ids.iter().any(|id| {
+            let class = self.classes.get(id).map(|&i| data.classes.get(i).map(|c| &c.id));
+            let property = self.properties.get(id).map(|&i| data.properties.get(i).map(|p| &p.id));
+            [class, property].into_iter().flatten().any(|entry| entry != Some(id))
+        });
+        if moved
+            || data.classes.len() < self.class_count
+            || data.properties.len() < self.endpoints.len()
+        {
+            *self = Self::new(data);
+            return;
+        }
 
-        for prop in &data.properties {
-            if !class_ids.contains_key(&prop.domain) {
-                return Err(VowlError::InvalidData(format!(
-                    "Property '{}' references unknown domain class: {}",
-                    prop.id, prop.domain
-                )));
+        let indexed = self.endpoints.len();
+        self.append(data);
+        for id in changed_ids {
+            if let Some(i) = self.properties.get(id).copied().filter(|&i| i < indexed) {
+                self.unfile(i);
+                self.file(i, &data.properties[i]);
             }
+        }
+    }
 
-            // Range might be a datatype, so we're more lenient
-            if !class_ids.contains_key(&prop.range) && !prop.range.starts_with("xsd:") {
-                // Only warn for non-datatype ranges
-                eprintln!(
-                    "Warning: Property '{}' references possibly unknown range: {}",
-                    prop.id, prop.range
-                );
+    /// Index the classes and properties added after the indexed ones
+    fn append(&mut self, data: &OntologyData) {
+        for (i, class) in data.classes.iter().enumerate().skip(self.class_count) {
+            self.classes.insert(class.id.clone(), i);
+        }
+        self.class_count = data.classes.len();
+
+        for (i, prop) in data.properties.iter().enumerate().skip(self.endpoints.len()) {
+            self.properties.insert(prop.id.clone(), i);
+            self.endpoints.push(Default::default());
+            self.file(i, prop);
+        }
+    }
+
+    /// File a property under its domain and range
+    fn file(&mut self, i: usize, prop: &Property) {
+        self.dependents.entry(prop.domain.clone()).or_default().push(i);
+        if prop.range != prop.domain {
+            self.dependents.entry(prop.range.clone()).or_default().push(i);
+        }
+        self.endpoints[i] = (prop.domain.clone(), prop.range.clone());
+    }
+
+    /// Remove a property from the classes it was filed under
+    fn unfile(&mut self, i: usize) {
+        let (domain, range) = std::mem::take(&mut self.endpoints[i]);
+        for class in [domain, range] {
+            if let Some(dependents) = self.dependents.get_mut(&class) {
+                dependents.retain(|&j| j != i);
             }
         }
+    }
+}
+
+impl OntologyParser for StandardParser {
+    fn parse(&self, json: &str) -> Result<OntologyData> {
+        self.parse_reader(json.as_bytes())
+    }
+
+    fn validate(&self, data: &OntologyData) -> Result<ValidationReport> {
+        let report = self.validation_report(data);
+
+        if let Some(error) = report.errors.first() {
+            return Err(VowlError::InvalidData(error.clone()));
+        }
 
-        Ok(())
+        Ok(report)
     }
 }
 
@@ -393,6 +864,360 @@ impl Default for StandardParser {
     }
 }
 
+/// Check that an IRI is an absolute URI or a prefixed name
+///
+/// Both have the form `head:rest` with a non-empty `rest` and no whitespace
+/// or characters IRIs forbid. The head must be a URI scheme (a letter
+/// followed by letters, digits, `+`, `-` or `.`) or a namespace prefix
+/// (letters, digits, `_`, `-` or `.`, possibly empty).
+fn is_valid_iri(iri: &str) -> bool {
+    let Some((head, rest)) = iri.split_once(':') else {
+        return false;
+    };
+    if rest.is_empty() || iri.chars().any(|c| c.is_whitespace() || "<>\"{}|\\^`".contains(c)) {
+        return false;
+    }
+
+    let is_scheme = head.starts_with(|c: char| c.is_ascii_alphabetic())
+        && head
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    let is_prefix = head
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+    is_scheme || is_prefix
+}
+
+/// Replace a prefixed IRI with its full form, moving the prefixed form to `curie`
+///
+/// IRIs with an undeclared prefix, and full IRIs like `http://...`, are
+/// left unchanged.
+fn expand_prefix(iri: &mut String, curie: &mut Option<String>, namespaces: &[Namespace]) {
+    let Some((prefix, local)) = iri.split_once(':') else {
+        return;
+    };
+    if local.starts_with("//") {
+        return;
+    }
+    let Some(namespace) = namespaces.iter().find(|ns| ns.prefix == prefix) else {
+        return;
+    };
+
+    let expanded = format!("{}{}", namespace.iri, local);
+    *curie = Some(std::mem::replace(iri, expanded));
+}
+
+/// Top-level keys accepted by `ParserConfig::strict_schema`
+const SCHEMA_KEYS: &[&str] = &[
+    "header",
+    "namespace",
+    "namespaces",
+    "class",
+    "classes",
+    "property",
+    "properties",
+    "subClassOf",
+    "disjoint",
+    "sameAs",
+    "setOperators",
+];
+
+/// Fields every property must set under `ParserConfig::strict_schema`
+const STRICT_PROPERTY_FIELDS: &[&str] = &["domain", "range", "type"];
+
+/// Store an error for the caller and abort deserialization
+fn reject<E: de::Error>(slot: &mut Option<VowlError>, error: VowlError) -> E {
+    let message = error.to_string();
+    *slot = Some(error);
+    E::custom(message)
+}
+
+/// Top-level sections of a streamed ontology document
+#[derive(Default)]
+struct Document {
+    classes: Option<Vec<ClassNode>>,
+    properties: Option<Vec<Property>>,
+    /// Errors of entries dropped by `skip_invalid_entries`
+    skipped: Vec<String>,
+    /// Classes beyond `max_classes`, dropped by `skip_invalid_entries`
+    truncated: Truncated,
+    /// Small sections (header, namespaces, relations) kept as JSON
+    rest: Map<String, Value>,
+}
+
+/// Entries dropped for exceeding a limit
+#[derive(Default)]
+struct Truncated {
+    /// Number of dropped entries, with or without an ID
+    count: usize,
+    /// IDs of the dropped entries
+    ids: HashSet<String>,
+}
+
+/// Streams the top-level object, parsing entity arrays entry by entry
+///
+/// An entry that fails to parse stores its error in `error` and aborts
+/// deserialization, so the caller can report the original `VowlError`.
+struct DocumentVisitor<'a> {
+    parser: &'a StandardParser,
+    error: &'a mut Option<VowlError>,
+}
+
+impl<'de> DeserializeSeed<'de> for DocumentVisitor<'_> {
+    type Value = Document;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<Document, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DocumentVisitor<'_> {
+    type Value = Document;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ontology object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<Document, A::Error> {
+        let mut document = Document::default();
+
+        let strict = self.parser.config.strict_schema;
+
+        while let Some(key) = map.next_key::<String>()? {
+            if strict {
+                if !SCHEMA_KEYS.contains(&key.as_str()) {
+                    let message = format!("Unknown top-level key at $.{}", key);
+                    return Err(reject(self.error, VowlError::ParseError(message)));
+                }
+                let duplicate = match key.as_str() {
+                    "class" | "classes" => document.classes.is_some(),
+                    "property" | "properties" => document.properties.is_some(),
+                    _ => false,
+                };
+                if duplicate {
+                    let message =
+                        format!("$.{} duplicates an array given under its other form", key);
+                    return Err(reject(self.error, VowlError::ParseError(message)));
+                }
+            }
+
+            // The singular keys win over their plural aliases
+            match key.as_str() {
+                "class" | "classes" if key == "class" || document.classes.is_none() => {
+                    document.classes = Some(map.next_value_seed(EntriesVisitor {
+                        parser: self.parser,
+                        error: &mut *self.error,
+                        skipped: &mut document.skipped,
+                        truncated: &mut document.truncated,
+                        kind: "class",
+                        path: &key,
+                        required: &[],
+                        parse: StandardParser::parse_class_node,
+                        limit: match self.parser.config.max_classes {
+                            0 => None,
+                            max => Some(("max_classes", max)),
+                        },
+                    })?);
+                }
+                "property" | "properties"
+                    if key == "property" || document.properties.is_none() =>
+                {
+                    document.properties = Some(map.next_value_seed(EntriesVisitor {
+                        parser: self.parser,
+                        error: &mut *self.error,
+                        skipped: &mut document.skipped,
+                        truncated: &mut document.truncated,
+                        kind: "property",
+                        path: &key,
+                        required: STRICT_PROPERTY_FIELDS,
+                        parse: StandardParser::parse_property,
+                        limit: None,
+                    })?);
+                }
+                "classes" | "properties" => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+                _ => {
+                    document.rest.insert(key, map.next_value()?);
+                }
+            }
+        }
+
+        Ok(document)
+    }
+}
+
+/// Streams an array of entities, parsing each entry as it is read
+struct EntriesVisitor<'a, T> {
+    parser: &'a StandardParser,
+    error: &'a mut Option<VowlError>,
+    skipped: &'a mut Vec<String>,
+    truncated: &'a mut Truncated,
+    /// Entity kind used in skipped-entry messages
+    kind: &'static str,
+    /// Top-level key of the array, used in schema error paths
+    path: &'a str,
+    /// Fields each entry must set under `ParserConfig::strict_schema`
+    required: &'static [&'static str],
+    parse: fn(&StandardParser, &Value) -> Result<T>,
+    /// Name and value of the maximum entry count, if limited
+    limit: Option<(&'static str, usize)>,
+}
+
+impl<'de, T> DeserializeSeed<'de> for EntriesVisitor<'_, T> {
+    type Value = Vec<T>;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<Vec<T>, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T> Visitor<'de> for EntriesVisitor<'_, T> {
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of entities")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Vec<T>, A::Error> {
+        let mut entries = Vec::new();
+
+        for position in 0.. {
+            if let Some((name, _)) = self.limit.filter(|&(_, max)| entries.len() >= max) {
+                let skip = self.parser.config.skip_invalid_entries;
+                let mut rest = Vec::new();
+                let mut total = position;
+                if skip {
+                    // Only the IDs are needed, so the entries are not buffered
+                    while let Some(EntryId(id)) = seq.next_element()? {
+                        rest.push(id);
+                    }
+                    total += rest.len();
+                } else {
+                    while seq.next_element::<IgnoredAny>()?.is_some() {
+                        total += 1;
+                    }
+                }
+                if total == position {
+                    break;
+                }
+
+                let error = VowlError::LimitExceeded {
+                    limit: name.to_string(),
+                    value: total,
+                };
+                if !skip {
+                    return Err(reject(self.error, error));
+                }
+                for (offset, id) in rest.into_iter().enumerate() {
+                    self.skipped.push(format!("{} #{}: {}", self.kind, position + offset, error));
+                    self.truncated.count += 1;
+                    self.truncated.ids.extend(id);
+                }
+                break;
+            }
+
+            let Some(entry) = seq.next_element::<Value>()? else {
+                break;
+            };
+            let missing = self
+                .required
+                .iter()
+                .find(|field| self.parser.config.strict_schema && entry.get(**field).is_none());
+            if let Some(field) = missing {
+                let error = VowlError::ParseError(format!(
+                    "Missing required field at $.{}[{}].{}",
+                    self.path, position, field
+                ));
+                return Err(reject(self.error, error));
+            }
+
+            match (self.parse)(self.parser, &entry) {
+                Ok(item) => entries.push(item),
+                Err(e) if self.parser.config.skip_invalid_entries => {
+                    self.skipped.push(format!("{} #{}: {}", self.kind, position, e));
+                }
+                Err(e) => return Err(reject(self.error, e)),
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// The `id` of an entry skipped unparsed, read without buffering the entry
+///
+/// Other fields are ignored, and entries that are not objects or whose ID is
+/// not a string have no ID.
+struct EntryId(Option<String>);
+
+impl<'de> de::Deserialize<'de> for EntryId {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(EntryIdVisitor)
+    }
+}
+
+/// Visitor behind `EntryId`
+struct EntryIdVisitor;
+
+impl<'de> Visitor<'de> for EntryIdVisitor {
+    type Value = EntryId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an entity")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<EntryId, A::Error> {
+        let mut id = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "id" {
+                id = map.next_value::<Value>()?.as_str().map(str::to_string);
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(EntryId(id))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<EntryId, A::Error> {
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(EntryId(None))
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+
+    fn visit_i64<E: de::Error>(self, _: i64) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+
+    fn visit_u64<E: de::Error>(self, _: u64) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+
+    fn visit_str<E: de::Error>(self, _: &str) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> std::result::Result<EntryId, E> {
+        Ok(EntryId(None))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,11 +1266,640 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_class_with_attributes() {
+    fn test_parse_reader_matches_parse() {
         let json = r#"
         {
+            "header": {"iri": "http://example.org/test", "title": "Test Ontology"},
+            "namespace": {"ex": "http://example.org/"},
             "class": [
-                {
+                {"id": "a", "label": {"en": "A", "de": "Ah"}},
+                {"id": "b", "label": "B", "attributes": {"deprecated": true}}
+            ],
+            "property": [
+                {"id": "p", "domain": "a", "range": "b", "maxCardinality": 2}
+            ],
+            "subClassOf": [{"from": "b", "to": "a"}],
+            "unknown": [1, 2, 3]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let streamed = parser.parse_reader(std::io::Cursor::new(json)).unwrap();
+
+        assert_eq!(streamed, parser.parse(json).unwrap());
+        assert_eq!(streamed.classes.len(), 2);
+        assert_eq!(streamed.properties.len(), 2);
+        assert_eq!(streamed.metadata.title, Some("Test Ontology".to_string()));
+        assert_eq!(streamed.namespaces.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_reader_errors() {
+        let parser = StandardParser::new();
+
+        let missing_id = r#"{"class": [{"label": "A"}], "property": []}"#;
+        match parser.parse_reader(missing_id.as_bytes()) {
+            Err(VowlError::ParseError(msg)) => assert_eq!(msg, "Missing class id"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        assert!(parser.parse_reader(r#"{"property": []}"#.as_bytes()).is_err());
+        assert!(parser.parse_reader(r#"{"class": [], "property": []} x"#.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_expand_prefixes() {
+        let json = r#"
+        {
+            "namespace": {"pizza": "http://www.co-ode.org/ontologies/pizza#"},
+            "class": [
+                {"id": "margherita", "iri": "pizza:Margherita"},
+                {"id": "topping", "iri": "food:Topping"}
+            ],
+            "property": [
+                {"id": "hasTopping", "iri": "pizza:hasTopping",
+                 "domain": "margherita", "range": "topping"}
+            ]
+        }
+        "#;
+
+        let verbatim = StandardParser::new().parse(json).unwrap();
+        assert_eq!(verbatim.classes[0].iri, "pizza:Margherita");
+        assert_eq!(verbatim.classes[0].curie, None);
+
+        let parser = StandardParser::with_config(ParserConfig {
+            expand_prefixes: true,
+            ..Default::default()
+        });
+        let data = parser.parse(json).unwrap();
+
+        let margherita = &data.classes[0];
+        assert_eq!(margherita.iri, "http://www.co-ode.org/ontologies/pizza#Margherita");
+        assert_eq!(margherita.curie.as_deref(), Some("pizza:Margherita"));
+        assert_eq!(data.properties[0].iri, "http://www.co-ode.org/ontologies/pizza#hasTopping");
+
+        // Undeclared prefixes stay as written
+        assert_eq!(data.classes[1].iri, "food:Topping");
+        assert_eq!(data.classes[1].curie, None);
+    }
+
+    #[test]
+    fn test_max_classes_limit() {
+        let json = r#"
+        {
+            "class": [{"id": "a"}, {"id": "b"}, {"id": "c"}],
+            "property": []
+        }
+        "#;
+        let parser = |max_classes| {
+            StandardParser::with_config(ParserConfig {
+                max_classes,
+                ..Default::default()
+            })
+        };
+
+        assert_eq!(parser(3).parse(json).unwrap().classes.len(), 3);
+
+        let error = parser(2).parse(json).unwrap_err();
+        assert_eq!(error.code(), "limit_exceeded");
+        match error {
+            VowlError::LimitExceeded { limit, value } => {
+                assert_eq!(limit, "max_classes");
+                assert_eq!(value, 3);
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_max_classes_drops_dangling_properties() {
+        let json = r#"
+        {
+            "class": [{"id": "a"}, {"id": "b"}],
+            "property": [{"id": "ab", "domain": "a", "range": "b"}]
+        }
+        "#;
+        let parser = StandardParser::with_config(ParserConfig {
+            max_classes: 1,
+            skip_invalid_entries: true,
+            ..Default::default()
+        });
+        let data = parser.parse(json).unwrap();
+
+        assert_eq!(data.classes.len(), 1);
+        assert!(data.properties.is_empty());
+
+        let report = parser.validate(&data).unwrap();
+        assert!(report.is_valid(), "errors: {:?}", report.errors);
+        assert_eq!(
+            report.skipped,
+            vec![
+                "class #1: Limit max_classes exceeded: 2",
+                "property 'ab': class 'b' was dropped by max_classes",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_max_classes_counts_set_operators() {
+        let json = r#"
+        {
+            "class": [{"id": "cat"}, {"id": "dog"}, 42, {"id": "fish", "label": {"en": "Fish"}}],
+            "property": [],
+            "setOperators": [{"id": "pet", "operator": "union", "members": ["cat", "dog"]}]
+        }
+        "#;
+        let parser = |max_classes, skip_invalid_entries| {
+            StandardParser::with_config(ParserConfig {
+                max_classes,
+                skip_invalid_entries,
+                ..Default::default()
+            })
+        };
+
+        // The classes beyond the limit are only scanned for their IDs
+        let data = parser(2, true).parse(json).unwrap();
+        assert_eq!(data.classes.len(), 2);
+        assert!(data.properties.is_empty());
+        assert_eq!(
+            data.skipped_entries,
+            vec![
+                "class #2: Limit max_classes exceeded: 4",
+                "class #3: Limit max_classes exceeded: 4",
+                "setOperator 'pet': Limit max_classes exceeded: 5",
+                "property 'setOperators:pet:cat': class 'pet' was dropped by max_classes",
+                "property 'setOperators:pet:dog': class 'pet' was dropped by max_classes",
+            ]
+        );
+
+        let json = r#"
+        {
+            "class": [{"id": "cat"}, {"id": "dog"}],
+            "property": [],
+            "setOperators": [{"id": "pet", "operator": "union", "members": ["cat", "dog"]}]
+        }
+        "#;
+        let error = parser(2, false).parse(json).unwrap_err();
+        assert!(matches!(error, VowlError::LimitExceeded { value: 3, .. }));
+        assert_eq!(parser(3, false).parse(json).unwrap().classes.len(), 3);
+    }
+
+    #[test]
+    fn test_strict_schema() {
+        let strict = StandardParser::with_config(ParserConfig {
+            strict_schema: true,
+            ..Default::default()
+        });
+        let error = |json: &str| match strict.parse(json) {
+            Err(VowlError::ParseError(msg)) => msg,
+            other => panic!("unexpected result: {:?}", other),
+        };
+
+        let unknown_key = r#"{"class": [], "property": [], "extra": 1}"#;
+        assert!(StandardParser::new().parse(unknown_key).is_ok());
+        assert_eq!(error(unknown_key), "Unknown top-level key at $.extra");
+
+        let both_forms = r#"{"class": [], "classes": [], "property": []}"#;
+        assert!(StandardParser::new().parse(both_forms).is_ok());
+        assert_eq!(
+            error(both_forms),
+            "$.classes duplicates an array given under its other form"
+        );
+
+        let implicit_range = r#"
+        {
+            "class": [{"id": "a"}],
+            "properties": [
+                {"id": "p", "type": "owl:ObjectProperty", "domain": "a", "range": "a"},
+                {"id": "q", "type": "owl:ObjectProperty", "domain": "a"}
+            ]
+        }
+        "#;
+        assert!(StandardParser::new().parse(implicit_range).is_ok());
+        assert_eq!(error(implicit_range), "Missing required field at $.properties[1].range");
+
+        let complete = r#"
+        {
+            "classes": [{"id": "a"}],
+            "property": [
+                {"id": "p", "type": "owl:ObjectProperty", "domain": "a", "range": "a"}
+            ],
+            "header": {"title": "Strict"}
+        }
+        "#;
+        assert_eq!(strict.parse(complete).unwrap().properties.len(), 1);
+    }
+
+    #[test]
+    fn test_strict_schema_is_not_skipped() {
+        let json = r#"
+        {
+            "class": [{"id": "a"}],
+            "property": [{"id": "q", "type": "owl:ObjectProperty", "domain": "a"}]
+        }
+        "#;
+
+        let skipping = StandardParser::with_config(ParserConfig {
+            skip_invalid_entries: true,
+            ..Default::default()
+        });
+        assert_eq!(skipping.parse(json).unwrap().properties.len(), 1);
+
+        let strict = StandardParser::with_config(ParserConfig {
+            skip_invalid_entries: true,
+            strict_schema: true,
+            ..Default::default()
+        });
+        match strict.parse(json) {
+            Err(VowlError::ParseError(msg)) => {
+                assert_eq!(msg, "Missing required field at $.property[0].range")
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_skip_invalid_entries() {
+        let json = r#"
+        {
+            "class": [
+                {"id": "a", "label": "A"},
+                {"label": "No ID"},
+                {"id": "b", "label": "B"},
+                {"label": "Also no ID"}
+            ],
+            "property": [
+                {"id": "p", "domain": "a", "range": "b"},
+                {"domain": "a", "range": "b"}
+            ]
+        }
+        "#;
+
+        assert!(StandardParser::new().parse(json).is_err());
+
+        let parser = StandardParser::with_config(ParserConfig {
+            skip_invalid_entries: true,
+            ..Default::default()
+        });
+        let data = parser.parse(json).unwrap();
+
+        let ids: Vec<&str> = data.classes.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert_eq!(data.properties.len(), 1);
+
+        let report = parser.validate(&data).unwrap();
+        assert_eq!(report.skipped.len(), 3);
+        assert_eq!(report.skipped[0], "class #1: Failed to parse ontology: Missing class id");
+    }
+
+    #[test]
+    fn test_iri_validation() {
+        for iri in ["http://example.org/A", "urn:isbn:123", "foaf:Person", ":local"] {
+            assert!(is_valid_iri(iri), "{}", iri);
+        }
+        for iri in ["", "Person", "foaf:", "http://example.org/a b", "<a:b>", "a/b:c"] {
+            assert!(!is_valid_iri(iri), "{}", iri);
+        }
+    }
+
+    #[test]
+    fn test_validate_iris_flag() {
+        let class = r#"{"id": "a", "label": "A", "iri": "not an iri"}"#;
+        let property = r#"{"id": "p", "iri": "", "domain": "a", "range": "a"}"#;
+
+        let strict = StandardParser::new();
+        assert!(strict.parse_class_fragment(class).is_err());
+        assert!(strict.parse_property_fragment(property).is_err());
+        // Entities without an explicit IRI fall back to their ID unchecked
+        assert!(strict.parse_class_fragment(r#"{"id": "a", "label": "A"}"#).is_ok());
+
+        let lenient = StandardParser::with_config(ParserConfig {
+            validate_iris: false,
+            ..Default::default()
+        });
+        assert_eq!(lenient.parse_class_fragment(class).unwrap().iri, "not an iri");
+        assert_eq!(lenient.parse_property_fragment(property).unwrap().iri, "");
+    }
+
+    #[test]
+    fn test_parse_property_chain() {
+        let json = r#"
+        {
+            "class": [{"id": "person"}],
+            "property": [
+                {"id": "hasParent", "domain": "person", "range": "person"},
+                {
+                    "id": "hasGrandparent",
+                    "domain": "person",
+                    "range": "person",
+                    "propertyChain": ["hasParent", "hasParent"]
+                }
+            ]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let data = parser.parse(json).unwrap();
+
+        assert!(data.properties[0].chain.is_empty());
+        assert_eq!(data.properties[1].chain, vec!["hasParent", "hasParent"]);
+        assert!(parser.validate(&data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_inverse_and_sub_property() {
+        let json = r#"
+        {
+            "class": [{"id": "person"}],
+            "property": [
+                {"id": "hasChild", "domain": "person", "range": "person"},
+                {
+                    "id": "hasParent",
+                    "domain": "person",
+                    "range": "person",
+                    "inverseOf": "hasChild",
+                    "subPropertyOf": "hasRelative"
+                },
+                {"id": "hasMother", "subPropertyOf": ["hasParent", "hasRelative"]}
+            ]
+        }
+        "#;
+
+        let data = StandardParser::new().parse(json).unwrap();
+
+        assert_eq!(data.properties[0].inverse_of, None);
+        assert!(data.properties[0].sub_property_of.is_empty());
+        assert_eq!(data.properties[1].inverse_of.as_deref(), Some("hasChild"));
+        assert_eq!(data.properties[1].sub_property_of, vec!["hasRelative"]);
+        assert_eq!(data.properties[2].sub_property_of, vec!["hasParent", "hasRelative"]);
+    }
+
+    #[test]
+    fn test_unbounded_property_defaults_to_thing() {
+        let json = r#"
+        {
+            "class": [{"id": "person"}],
+            "property": [{"id": "knows", "domain": "person"}]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let data = parser.parse(json).unwrap();
+
+        assert_eq!(data.properties[0].range, OWL_THING);
+        assert!(parser.validate(&data).is_ok());
+    }
+
+    #[test]
+    fn test_validate_collects_range_warning() {
+        let mut data = create_dependency_ontology();
+        data.properties[1].range = "unknownRange".to_string();
+
+        let report = StandardParser::new().validate(&data).unwrap();
+
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("unknownRange"));
+    }
+
+    #[test]
+    fn test_validate_duplicate_ids() {
+        let mut data = create_dependency_ontology();
+        let parser = StandardParser::new();
+        assert!(parser.validate(&data).is_ok());
+
+        data.properties[1].id = data.properties[0].id.clone();
+        match parser.validate(&data) {
+            Err(VowlError::InvalidData(message)) => {
+                assert_eq!(message, format!("Duplicate property IDs: {}", data.properties[0].id));
+            }
+            other => panic!("expected a duplicate ID error, got {:?}", other),
+        }
+
+        data.classes.push(data.classes[0].clone());
+        let report = parser.validation_report(&data);
+        assert_eq!(report.errors.len(), 2);
+        assert_eq!(report.errors[0], format!("Duplicate class IDs: {}", data.classes[0].id));
+    }
+
+    #[test]
+    fn test_validate_unknown_chain_reference() {
+        let mut data = create_dependency_ontology();
+        data.properties[0].chain = vec!["p2".to_string(), "missing".to_string()];
+
+        let parser = StandardParser::new();
+        let mut index = ValidationIndex::new(&data);
+        let report = parser.validate_incremental(&data, &mut index, &["p1".to_string()]);
+
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].contains("missing"));
+        assert!(parser.validate(&data).is_err());
+    }
+
+    #[test]
+    fn test_parse_multilingual_labels() {
+        let json = r#"
+        {
+            "class": [
+                {"id": "person", "label": {"en": "Person", "fr": "Personne"}},
+                {"id": "plain", "label": "Plain"}
+            ],
+            "property": [
+                {"id": "knows", "label": {"en": "knows", "fr": "connaît"}, "domain": "person", "range": "person"}
+            ]
+        }
+        "#;
+
+        let config = ParserConfig {
+            preferred_language: Some("fr".to_string()),
+            ..Default::default()
+        };
+        let data = StandardParser::with_config(config).parse(json).unwrap();
+
+        assert_eq!(data.classes[0].label, "Personne");
+        assert_eq!(data.classes[0].labels.len(), 2);
+        assert_eq!(data.classes[0].labels["en"], "Person");
+        assert_eq!(data.properties[0].label, "connaît");
+
+        assert_eq!(data.classes[1].label, "Plain");
+        assert!(data.classes[1].labels.is_empty());
+    }
+
+    #[test]
+    fn test_multilingual_label_fallback() {
+        let json = r#"
+        {
+            "class": [
+                {"id": "person", "label": {"fr": "Personne", "de": "Person"}},
+                {"id": "empty", "label": {}}
+            ],
+            "property": []
+        }
+        "#;
+
+        let config = ParserConfig {
+            preferred_language: Some("es".to_string()),
+            ..Default::default()
+        };
+        let data = StandardParser::with_config(config).parse(json).unwrap();
+
+        // Missing language falls back to the first available tag, then to the ID
+        assert_eq!(data.classes[0].label, "Person");
+        assert_eq!(data.classes[1].label, "empty");
+    }
+
+    #[test]
+    fn test_parse_cardinality_variants() {
+        let parser = StandardParser::new();
+        let parse = |json: Value| parser.parse_cardinality(&json).unwrap();
+
+        assert_eq!(parse(serde_json::json!({})), None);
+
+        let min_only = parse(serde_json::json!({"minCardinality": 1})).unwrap();
+        assert_eq!((min_only.min, min_only.max, min_only.exact), (Some(1), None, None));
+
+        let max_only = parse(serde_json::json!({"maxCardinality": 3})).unwrap();
+        assert_eq!((max_only.min, max_only.max, max_only.exact), (None, Some(3), None));
+
+        let exact = parse(serde_json::json!({"cardinality": 2})).unwrap();
+        assert_eq!((exact.min, exact.max, exact.exact), (None, None, Some(2)));
+
+        let combined = parse(serde_json::json!({
+            "minCardinality": 1,
+            "maxCardinality": 5,
+            "cardinality": 3
+        }))
+        .unwrap();
+        assert_eq!((combined.min, combined.max, combined.exact), (Some(1), Some(5), Some(3)));
+    }
+
+    #[test]
+    fn test_parse_property_min_cardinality_only() {
+        let json = r#"
+        {
+            "class": [{"id": "person"}],
+            "property": [
+                {"id": "hasChild", "domain": "person", "range": "person", "minCardinality": 1}
+            ]
+        }
+        "#;
+
+        let data = StandardParser::new().parse(json).unwrap();
+        let cardinality = data.properties[0].characteristics.cardinality.as_ref().unwrap();
+
+        assert_eq!(cardinality.min, Some(1));
+        assert_eq!(cardinality.exact, None);
+    }
+
+    #[test]
+    fn test_parse_fragments() {
+        let parser = StandardParser::new();
+
+        let class = parser
+            .parse_class_fragment(r#"{"id": "pet", "label": "Pet"}"#)
+            .unwrap();
+        assert_eq!(class.label, "Pet");
+
+        let property = parser
+            .parse_property_fragment(r#"{"id": "owns", "domain": "person", "range": "pet"}"#)
+            .unwrap();
+        assert_eq!((property.domain.as_str(), property.range.as_str()), ("person", "pet"));
+
+        assert!(parser.parse_class_fragment(r#"{"label": "No id"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_subclass_and_disjoint_sections() {
+        let json = r#"
+        {
+            "class": [{"id": "animal"}, {"id": "dog"}, {"id": "cat"}],
+            "property": [],
+            "subClassOf": [{"from": "dog", "to": "animal"}, {"from": "cat", "to": "animal"}],
+            "disjoint": [{"from": "dog", "to": "cat"}]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let data = parser.parse(json).unwrap();
+
+        assert_eq!(data.properties.len(), 3);
+        let dog = &data.properties[0];
+        assert_eq!(
+            dog.property_type,
+            PropertyType::SpecialProperty(RDFS_SUBCLASS_OF.to_string())
+        );
+        assert_eq!((dog.domain.as_str(), dog.range.as_str()), ("dog", "animal"));
+        assert_eq!(
+            data.properties[2].property_type,
+            PropertyType::SpecialProperty(OWL_DISJOINT_WITH.to_string())
+        );
+        assert_ne!(data.properties[0].id, data.properties[1].id);
+        assert!(parser.validate(&data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_same_as_section() {
+        let json = r#"
+        {
+            "class": [{"id": "person"}, {"id": "foaf:Person"}, {"id": "schema:Person"}],
+            "property": [],
+            "sameAs": [
+                {"from": "person", "to": "foaf:Person"},
+                {"from": "person", "to": "schema:Person"}
+            ]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let data = parser.parse(json).unwrap();
+
+        assert_eq!(data.properties.len(), 2);
+        for property in &data.properties {
+            assert_eq!(property.property_type, PropertyType::SpecialProperty(SAME_AS.to_string()));
+            assert_eq!(property.domain, "person");
+        }
+        assert_eq!(data.properties[1].range, "schema:Person");
+        assert!(parser.validate(&data).is_ok());
+    }
+
+    #[test]
+    fn test_parse_set_operator_section() {
+        let json = r#"
+        {
+            "class": [{"id": "cat"}, {"id": "dog"}],
+            "property": [],
+            "setOperators": [{"id": "pet", "operator": "union", "members": ["cat", "dog"]}]
+        }
+        "#;
+
+        let parser = StandardParser::new();
+        let data = parser.parse(json).unwrap();
+
+        assert_eq!(data.classes.len(), 3);
+        assert_eq!(data.classes[2].id, "pet");
+        assert_eq!(data.classes[2].class_type, "union");
+        assert_eq!(data.properties.len(), 2);
+        let member = &data.properties[1];
+        assert_eq!(member.property_type, PropertyType::SpecialProperty("union".to_string()));
+        assert_eq!((member.domain.as_str(), member.range.as_str()), ("pet", "dog"));
+        assert!(parser.validate(&data).is_ok());
+
+        let unknown = r#"{"class": [], "property": [], "setOperators": [{"id": "x"}]}"#;
+        assert!(parser.parse(unknown).is_err());
+    }
+
+    #[test]
+    fn test_parse_relation_missing_end() {
+        let json = r#"{"class": [], "property": [], "subClassOf": [{"from": "dog"}]}"#;
+        assert!(StandardParser::new().parse(json).is_err());
+    }
+
+    #[test]
+    fn test_parse_class_with_attributes() {
+        let json = r#"
+        {
+            "class": [
+                {
                     "id": "class1",
                     "label": "Class 1",
                     "external": true,
@@ -464,6 +1918,64 @@ mod tests {
         assert_eq!(data.classes.len(), 1);
         assert!(data.classes[0].attributes.external);
         assert_eq!(data.classes[0].attributes.individuals, Some(42));
+        assert!(!data.classes[0].attributes.deprecated);
+    }
+
+    #[test]
+    fn test_parse_named_individuals() {
+        let json = r#"
+        {
+            "class": [
+                {"id": "pizza", "individuals": [
+                    {"id": "margherita", "label": "Margherita"},
+                    {"id": "marinara"}
+                ]},
+                {"id": "topping", "individuals": 3}
+            ],
+            "property": []
+        }
+        "#;
+
+        let data = StandardParser::new().parse(json).unwrap();
+        let pizza = &data.classes[0].attributes;
+        assert_eq!(pizza.individuals, Some(2));
+        assert_eq!(
+            pizza.instances,
+            vec![
+                Individual {
+                    id: "margherita".to_string(),
+                    label: "Margherita".to_string()
+                },
+                Individual {
+                    id: "marinara".to_string(),
+                    label: "marinara".to_string()
+                },
+            ]
+        );
+        assert_eq!(data.classes[1].attributes.individuals, Some(3));
+        assert!(data.classes[1].attributes.instances.is_empty());
+
+        let missing_id = r#"{"class": [{"id": "a", "individuals": [{}]}], "property": []}"#;
+        assert!(StandardParser::new().parse(missing_id).is_err());
+    }
+
+    #[test]
+    fn test_parse_deprecated_class() {
+        let json = r#"
+        {
+            "class": [
+                {"id": "old", "deprecated": true},
+                {"id": "older", "attributes": {"deprecated": true}},
+                {"id": "current", "attributes": {"deprecated": false}}
+            ],
+            "property": []
+        }
+        "#;
+
+        let data = StandardParser::new().parse(json).unwrap();
+        let deprecated: Vec<bool> = data.classes.iter().map(|c| c.attributes.deprecated).collect();
+        assert_eq!(deprecated, vec![true, true, false]);
+        assert!(data.classes[1].attributes.properties.is_empty());
     }
 
     #[test]
@@ -478,7 +1990,9 @@ mod tests {
             classes: vec![ClassNode {
                 id: "class1".to_string(),
                 iri: "test".to_string(),
+                curie: None,
                 label: "Test".to_string(),
+                labels: Default::default(),
                 class_type: "owl:Class".to_string(),
                 equivalent: vec![],
                 attributes: ClassAttributes::default(),
@@ -486,13 +2000,19 @@ mod tests {
             properties: vec![Property {
                 id: "prop1".to_string(),
                 iri: "test".to_string(),
+                curie: None,
                 label: "Test".to_string(),
+                labels: Default::default(),
                 property_type: PropertyType::ObjectProperty,
                 domain: "invalid_class".to_string(),
                 range: "class1".to_string(),
                 characteristics: PropertyCharacteristics::default(),
+                chain: vec![],
+                inverse_of: None,
+                sub_property_of: vec![],
             }],
             namespaces: vec![],
+            skipped_entries: vec![],
         };
 
         let parser = StandardParser::new();
@@ -501,12 +2021,136 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn create_dependency_ontology() -> OntologyData {
+        let class = |id: &str| ClassNode {
+            id: id.to_string(),
+            iri: id.to_string(),
+            curie: None,
+            label: id.to_string(),
+            labels: Default::default(),
+            class_type: "owl:Class".to_string(),
+            equivalent: vec![],
+            attributes: ClassAttributes::default(),
+        };
+        let property = |id: &str, domain: &str, range: &str| Property {
+            id: id.to_string(),
+            iri: id.to_string(),
+            curie: None,
+            label: id.to_string(),
+            labels: Default::default(),
+            property_type: PropertyType::ObjectProperty,
+            domain: domain.to_string(),
+            range: range.to_string(),
+            characteristics: PropertyCharacteristics::default(),
+            chain: vec![],
+            inverse_of: None,
+            sub_property_of: vec![],
+        };
+
+        OntologyData {
+            metadata: OntologyMetadata {
+                iri: "test".to_string(),
+                version: None,
+                title: None,
+                description: None,
+            },
+            classes: vec![class("a"), class("b"), class("c")],
+            properties: vec![
+                property("p1", "a", "b"),
+                property("p2", "b", "c"),
+                property("p3", "c", "c"),
+            ],
+            namespaces: vec![],
+            skipped_entries: vec![],
+        }
+    }
+
+    #[test]
+    fn test_validate_incremental_checks_only_dependents() {
+        let data = create_dependency_ontology();
+        let parser = StandardParser::new();
+
+        let mut index = ValidationIndex::new(&data);
+
+        let report = parser.validate_incremental(&data, &mut index, &["a".to_string()]);
+        assert_eq!(report.checked, vec!["p1"]);
+        assert!(report.is_valid());
+
+        let report = parser.validate_incremental(&data, &mut index, &["p3".to_string()]);
+        assert_eq!(report.checked, vec!["p3"]);
+    }
+
+    #[test]
+    fn test_validate_incremental_keeps_index() {
+        let mut data = create_dependency_ontology();
+        let parser = StandardParser::new();
+        let mut index = ValidationIndex::default();
+        assert_eq!(parser.validate_incremental(&data, &mut index, &[]).checked.len(), 0);
+
+        // An unreported edit is not seen, as unchanged properties are not rescanned
+        data.properties[2].domain = "a".to_string();
+        let report = parser.validate_incremental(&data, &mut index, &["a".to_string()]);
+        assert_eq!(report.checked, vec!["p1"]);
+
+        let report = parser.validate_incremental(&data, &mut index, &["p3".to_string()]);
+        assert_eq!(report.checked, vec!["p3"]);
+        let report = parser.validate_incremental(&data, &mut index, &["a".to_string()]);
+        assert_eq!(report.checked, vec!["p1", "p3"]);
+        let report = parser.validate_incremental(&data, &mut index, &["c".to_string()]);
+        assert_eq!(report.checked, vec!["p2", "p3"]);
+
+        // Appended entries are indexed, removed ones trigger a rebuild
+        let mut class = data.classes[0].clone();
+        class.id = "d".to_string();
+        data.classes.push(class);
+        let mut property = data.properties[0].clone();
+        (property.id, property.domain) = ("p4".to_string(), "d".to_string());
+        data.properties.push(property);
+        let report = parser.validate_incremental(&data, &mut index, &["p4".to_string()]);
+        assert_eq!(report.checked, vec!["p4"]);
+        assert!(report.is_valid());
+
+        data.classes.retain(|c| c.id != "a");
+        let report = parser.validate_incremental(&data, &mut index, &["a".to_string()]);
+        assert_eq!(report.checked, vec!["p1", "p3"]);
+        assert_eq!(report.errors.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_incremental_matches_full_validation() {
+        let mut data = create_dependency_ontology();
+        data.classes.retain(|c| c.id != "b");
+        let parser = StandardParser::new();
+
+        let mut index = ValidationIndex::new(&data);
+        let incremental = parser.validate_incremental(&data, &mut index, &["b".to_string()]);
+        let full = parser.validation_report(&data);
+
+        assert_eq!(incremental.checked, vec!["p1", "p2"]);
+        assert_eq!(incremental.errors.len(), 1);
+        assert_eq!(incremental.warnings.len(), 1);
+
+        let full_for_dependents: Vec<&String> = full
+            .errors
+            .iter()
+            .chain(&full.warnings)
+            .filter(|msg| msg.contains("'p1'") || msg.contains("'p2'"))
+            .collect();
+        let incremental_all: Vec<&String> =
+            incremental.errors.iter().chain(&incremental.warnings).collect();
+        assert_eq!(incremental_all, full_for_dependents);
+    }
+
     #[test]
     fn test_parser_config() {
         let config = ParserConfig {
             validate_iris: false,
             allow_empty_labels: true,
             max_classes: 10,
+            skip_invalid_entries: true,
+            preferred_language: Some("de".to_string()),
+            strict_schema: false,
+            expand_prefixes: false,
         };
 
         let parser = StandardParser::with_config(config.clone());
@@ -0,0 +1,305 @@
+//! Canvas renderer producing replayable draw commands
+
+use super::{theme::VowlTheme, viewport::Viewport, Renderer};
+use crate::graph::{Edge, Node, VowlGraph};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+
+/// A single 2D drawing operation, replayed by JS onto a canvas context
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum DrawCommand {
+    /// Filled and stroked circle
+    Circle {
+        /// Center X
+        x: f64,
+        /// Center Y
+        y: f64,
+        /// Radius
+        radius: f64,
+        /// Fill color
+        fill: String,
+        /// Outline color
+        stroke: String,
+    },
+
+    /// Straight line segment
+    Line {
+        /// Start X
+        x1: f64,
+        /// Start Y
+        y1: f64,
+        /// End X
+        x2: f64,
+        /// End Y
+        y2: f64,
+        /// Line color
+        stroke: String,
+        /// Line width
+        width: f64,
+    },
+
+    /// Cubic Bézier curve, used for self-loops
+    Bezier {
+        /// Start X
+        x1: f64,
+        /// Start Y
+        y1: f64,
+        /// First control point X
+        cx1: f64,
+        /// First control point Y
+        cy1: f64,
+        /// Second control point X
+        cx2: f64,
+        /// Second control point Y
+        cy2: f64,
+        /// End X
+        x2: f64,
+        /// End Y
+        y2: f64,
+        /// Line color
+        stroke: String,
+        /// Line width
+        width: f64,
+    },
+
+    /// Centered text
+    Text {
+        /// Anchor X (text is centered on it)
+        x: f64,
+        /// Baseline Y
+        y: f64,
+        /// Content
+        text: String,
+        /// Text color
+        color: String,
+        /// Font size in pixels
+        size: f64,
+    },
+}
+
+/// Renderer emitting draw commands instead of markup
+///
+/// Commands are in canvas pixel coordinates: the graph is fitted into the
+/// canvas with the same math as the SVG overview.
+pub struct CanvasRenderer {
+    viewport: Viewport,
+    theme: VowlTheme,
+}
+
+impl CanvasRenderer {
+    /// Create a canvas renderer for a canvas of the given size
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            viewport: Viewport::new(width, height, 20.0),
+            theme: VowlTheme::default(),
+        }
+    }
+
+    /// Set padding
+    pub fn with_padding(mut self, padding: f64) -> Self {
+        self.viewport.padding = padding;
+        self
+    }
+
+    /// Set the theme sizing node circles
+    pub fn with_theme(mut self, theme: VowlTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Build the command list for the whole graph, edges before nodes
+    pub fn draw_commands(&self, graph: &VowlGraph) -> Vec<DrawCommand> {
+        let transform = self.viewport.fit(graph);
+        let mut commands = Vec::new();
+
+        for (from, _, to) in graph.edge_endpoints() {
+            let (Some(from), Some(to)) = (graph.get_node(from), graph.get_node(to)) else {
+                continue;
+            };
+            if from.visual.hidden || to.visual.hidden {
+                continue;
+            }
+            let start = transform.apply(from.visual.x, from.visual.y);
+            if from.id == to.id {
+                commands.push(self.self_loop_command(from, start));
+            } else {
+                commands.push(Self::edge_command(
+                    start,
+                    transform.apply(to.visual.x, to.visual.y),
+                ));
+            }
+        }
+
+        for node in graph.nodes().into_iter().filter(|n| !n.visual.hidden) {
+            let (x, y) = transform.apply(node.visual.x, node.visual.y);
+            commands.extend(self.node_commands(node, x, y));
+        }
+
+        commands
+    }
+
+    fn node_commands(&self, node: &Node, x: f64, y: f64) -> [DrawCommand; 2] {
+        let fill = node.visual.color.as_deref().unwrap_or("#4CAF50");
+        let radius = self.theme.node_radius(node);
+        [
+            DrawCommand::Circle {
+                x,
+                y,
+                radius,
+                fill: fill.to_string(),
+                stroke: "#333".to_string(),
+            },
+            DrawCommand::Text {
+                x,
+                y: y + radius + 15.0,
+                text: node.label.clone(),
+                color: "#333".to_string(),
+                size: 12.0,
+            },
+        ]
+    }
+
+    /// Loop leaving the top of `node`, drawn at `(x, y)`, and returning to it
+    fn self_loop_command(&self, node: &Node, (x, y): (f64, f64)) -> DrawCommand {
+        // Anchors at +-30 degrees from the top of the node outline
+        let radius = self.theme.node_radius(node);
+        let (dx, dy) = (radius * 0.5, radius * 0.87);
+        DrawCommand::Bezier {
+            x1: x - dx,
+            y1: y - dy,
+            cx1: x - radius * 1.2,
+            cy1: y - radius * 2.5,
+            cx2: x + radius * 1.2,
+            cy2: y - radius * 2.5,
+            x2: x + dx,
+            y2: y - dy,
+            stroke: "#999".to_string(),
+            width: 1.5,
+        }
+    }
+
+    fn edge_command(from: (f64, f64), to: (f64, f64)) -> DrawCommand {
+        DrawCommand::Line {
+            x1: from.0,
+            y1: from.1,
+            x2: to.0,
+            y2: to.1,
+            stroke: "#999".to_string(),
+            width: 1.5,
+        }
+    }
+}
+
+impl Renderer for CanvasRenderer {
+    fn render(&self, graph: &VowlGraph) -> Result<String> {
+        Ok(serde_json::to_string(&self.draw_commands(graph))?)
+    }
+
+    fn render_node(&self, node: &Node) -> Result<String> {
+        let commands = self.node_commands(node, node.visual.x, node.visual.y);
+        Ok(serde_json::to_string(&commands)?)
+    }
+
+    fn render_edge(&self, _edge: &Edge, from: &Node, to: &Node) -> Result<String> {
+        let command = if from.id == to.id {
+            self.self_loop_command(from, (from.visual.x, from.visual.y))
+        } else {
+            Self::edge_command((from.visual.x, from.visual.y), (to.visual.x, to.visual.y))
+        };
+        Ok(serde_json::to_string(&[command])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    fn create_test_graph() -> VowlGraph {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(100.0, 50.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("c").position(50.0, 100.0).build())
+            .unwrap();
+        graph
+            .add_edge("a", "b", EdgeBuilder::new("e").build())
+            .unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_one_circle_per_node() {
+        let renderer = CanvasRenderer::new(800.0, 600.0);
+        let commands = renderer.draw_commands(&create_test_graph());
+
+        let circles = commands
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::Circle { .. }))
+            .count();
+        assert_eq!(circles, 3);
+
+        // Edges come first so they are drawn behind nodes
+        assert!(matches!(commands[0], DrawCommand::Line { .. }));
+    }
+
+    #[test]
+    fn test_self_loop_is_curve() {
+        let mut graph = create_test_graph();
+        graph
+            .add_edge("c", "c", EdgeBuilder::new("knows").build())
+            .unwrap();
+
+        let commands = CanvasRenderer::new(800.0, 600.0).draw_commands(&graph);
+        let loops: Vec<&DrawCommand> = commands
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::Bezier { .. }))
+            .collect();
+        assert_eq!(loops.len(), 1);
+        let lines = commands
+            .iter()
+            .filter(|c| matches!(c, DrawCommand::Line { .. }))
+            .count();
+        assert_eq!(lines, 1);
+
+        // Both ends sit on the node and the curve rises above it
+        if let DrawCommand::Bezier { y1, y2, cy1, .. } = loops[0] {
+            assert_eq!(y1, y2);
+            assert!(cy1 < y1);
+        }
+    }
+
+    #[test]
+    fn test_sizes_follow_theme() {
+        let renderer = CanvasRenderer::new(800.0, 600.0);
+        let mut node = NodeBuilder::new("a").position(0.0, 0.0).build();
+        node.semantic.individuals = Some(15);
+
+        let [circle, label] = renderer.node_commands(&node, 0.0, 0.0);
+        assert!(matches!(circle, DrawCommand::Circle { radius, .. } if radius == 40.0));
+        assert!(matches!(label, DrawCommand::Text { y, .. } if y == 55.0));
+
+        let DrawCommand::Bezier { y1, cy1, .. } = renderer.self_loop_command(&node, (0.0, 0.0))
+        else {
+            panic!("self-loop is not a curve");
+        };
+        assert!(y1 < -34.0);
+        assert_eq!(cy1, -100.0);
+    }
+
+    #[test]
+    fn test_render_json_is_tagged() {
+        let renderer = CanvasRenderer::new(800.0, 600.0);
+        let json = renderer.render(&create_test_graph()).unwrap();
+
+        let parsed: Vec<DrawCommand> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.len(), 7);
+        assert!(json.contains(r#""op":"circle""#));
+        assert!(json.contains(r#""op":"line""#));
+    }
+}
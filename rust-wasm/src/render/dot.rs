@@ -0,0 +1,138 @@
+//! Graphviz DOT renderer for documentation pipelines
+
+use super::{theme::VowlTheme, Renderer};
+use crate::graph::{Edge, EdgeType, Node, NodeType, VowlGraph};
+use crate::Result;
+
+/// Renders the graph as a Graphviz `digraph`
+///
+/// Positions are left to Graphviz. Edges point from a property's domain to
+/// its range.
+#[derive(Debug, Clone, Default)]
+pub struct DotRenderer {
+    theme: VowlTheme,
+}
+
+impl DotRenderer {
+    /// Create a DOT renderer with the default VOWL colors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the colors used for node fills
+    pub fn with_theme(mut self, theme: VowlTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Graphviz shape for a node type
+    fn shape(node_type: &NodeType) -> &'static str {
+        match node_type {
+            NodeType::Class => "ellipse",
+            NodeType::Datatype => "box",
+            NodeType::Special(_) => "circle",
+        }
+    }
+
+    /// Graphviz line style for an edge type
+    fn style(edge_type: &EdgeType) -> &'static str {
+        match edge_type {
+            EdgeType::SubClass | EdgeType::SameAs => "dashed",
+            EdgeType::Annotation => "dotted",
+            _ => "solid",
+        }
+    }
+}
+
+/// Quote a string as a DOT ID
+fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+impl Renderer for DotRenderer {
+    fn render(&self, graph: &VowlGraph) -> Result<String> {
+        let mut dot = String::from("digraph vowl {\n");
+
+        for node in graph.nodes().into_iter().filter(|n| !n.visual.hidden) {
+            dot.push_str(&self.render_node(node)?);
+        }
+
+        for (from, edge, to) in graph.edge_endpoints() {
+            let (Some(from), Some(to)) = (graph.get_node(from), graph.get_node(to)) else {
+                continue;
+            };
+            if from.visual.hidden || to.visual.hidden {
+                continue;
+            }
+            dot.push_str(&self.render_edge(edge, from, to)?);
+        }
+
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    fn render_node(&self, node: &Node) -> Result<String> {
+        Ok(format!(
+            "  {} [label={}, shape={}, style=filled, fillcolor={}];\n",
+            quote(&node.id),
+            quote(&node.label),
+            Self::shape(&node.node_type),
+            quote(self.theme.node_color(node))
+        ))
+    }
+
+    fn render_edge(&self, edge: &Edge, from: &Node, to: &Node) -> Result<String> {
+        let dir = if edge.characteristics.bidirectional {
+            ", dir=both"
+        } else {
+            ""
+        };
+
+        Ok(format!(
+            "  {} -> {} [label={}, style={}{}];\n",
+            quote(&from.id),
+            quote(&to.id),
+            quote(&edge.label),
+            Self::style(&edge.edge_type),
+            dir
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
+
+    #[test]
+    fn test_render_dot() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("person").label("Person").build())
+            .unwrap();
+        graph
+            .add_node(
+                NodeBuilder::new("xsd:string")
+                    .label("string")
+                    .node_type(NodeType::Datatype)
+                    .build(),
+            )
+            .unwrap();
+        let edge = EdgeBuilder::new("name").label("has \"name\"").build();
+        graph.add_edge("person", "xsd:string", edge).unwrap();
+
+        let dot = DotRenderer::new().render(&graph).unwrap();
+
+        assert!(dot.starts_with("digraph vowl {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches(" [label=").count(), 3);
+        assert!(dot.contains(r#""person" [label="Person", shape=ellipse"#));
+        assert!(dot.contains(r#""xsd:string" [label="string", shape=box"#));
+        assert_eq!(dot.matches(" -> ").count(), 1);
+        assert!(dot.contains(r#""person" -> "xsd:string" [label="has \"name\"", style=solid];"#));
+    }
+}
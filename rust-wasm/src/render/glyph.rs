@@ -0,0 +1,108 @@
+//! VOWL property characteristic glyphs
+//!
+//! Shared by the edge renderer and legends so every place that shows
+//! property characteristics draws them identically.
+
+use crate::graph::EdgeCharacteristics;
+
+/// Width reserved for each glyph, including spacing
+const GLYPH_WIDTH: f64 = 16.0;
+
+/// Identifiers and symbols of the characteristics set on an edge
+///
+/// Returned in a stable order: functional, inverse functional, transitive,
+/// symmetric.
+pub fn characteristic_glyph_ids(
+    characteristics: &EdgeCharacteristics,
+) -> Vec<(&'static str, &'static str)> {
+    [
+        (characteristics.functional, ("functional", "f")),
+        (characteristics.inverse_functional, ("inverse-functional", "if")),
+        (characteristics.transitive, ("transitive", "t")),
+        (characteristics.symmetric, ("symmetric", "s")),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, glyph)| glyph)
+    .collect()
+}
+
+/// Render the characteristic glyphs of an edge centered at `(x, y)`
+///
+/// Returns an empty string when no characteristics are set.
+pub fn characteristic_glyphs(characteristics: &EdgeCharacteristics, x: f64, y: f64) -> String {
+    let glyphs = characteristic_glyph_ids(characteristics);
+    if glyphs.is_empty() {
+        return String::new();
+    }
+
+    let start = x - GLYPH_WIDTH * glyphs.len() as f64 / 2.0;
+    let mut svg = String::from(r#"<g class="characteristics">"#);
+
+    for (i, (id, symbol)) in glyphs.into_iter().enumerate() {
+        let gx = start + GLYPH_WIDTH * i as f64;
+        svg.push_str(&format!(
+            r##"<g class="glyph glyph-{}"><rect x="{}" y="{}" width="14" height="12" fill="#fff" stroke="#333"/><text x="{}" y="{}" text-anchor="middle" font-size="9" fill="#333">{}</text></g>"##,
+            id,
+            gx,
+            y - 6.0,
+            gx + 7.0,
+            y + 3.0,
+            symbol
+        ));
+    }
+
+    svg.push_str("</g>");
+    svg
+}
+
+/// Format a `(min, max)` cardinality in VOWL notation
+///
+/// An unbounded maximum is written `*` and a missing minimum `0`; equal
+/// bounds collapse to a single number. Returns `None` when neither bound is
+/// set.
+pub fn cardinality_text(cardinality: (Option<u32>, Option<u32>)) -> Option<String> {
+    match cardinality {
+        (None, None) => None,
+        (Some(min), Some(max)) if min == max => Some(min.to_string()),
+        (min, max) => Some(format!(
+            "{}..{}",
+            min.unwrap_or(0),
+            max.map_or_else(|| "*".to_string(), |m| m.to_string())
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_functional_transitive_glyphs() {
+        let characteristics = EdgeCharacteristics {
+            functional: true,
+            transitive: true,
+            ..Default::default()
+        };
+
+        let svg = characteristic_glyphs(&characteristics, 50.0, 50.0);
+        assert!(svg.contains("glyph-functional"));
+        assert!(svg.contains("glyph-transitive"));
+        assert!(!svg.contains("glyph-symmetric"));
+        assert_eq!(svg.matches("<rect").count(), 2);
+    }
+
+    #[test]
+    fn test_cardinality_text() {
+        assert_eq!(cardinality_text((Some(1), None)).as_deref(), Some("1..*"));
+        assert_eq!(cardinality_text((None, Some(3))).as_deref(), Some("0..3"));
+        assert_eq!(cardinality_text((Some(2), Some(2))).as_deref(), Some("2"));
+        assert_eq!(cardinality_text((None, None)), None);
+    }
+
+    #[test]
+    fn test_no_characteristics_no_glyphs() {
+        let svg = characteristic_glyphs(&EdgeCharacteristics::default(), 0.0, 0.0);
+        assert!(svg.is_empty());
+    }
+}
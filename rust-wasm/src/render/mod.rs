@@ -1,7 +1,19 @@
-//! Rendering utilities for SVG and Canvas output
+//! Rendering utilities for SVG, Canvas and Graphviz DOT output
+
+pub mod bundling;
+pub mod canvas;
+pub mod dot;
+pub mod glyph;
+pub mod theme;
+pub mod viewport;
 
 use crate::Result;
-use crate::graph::{VowlGraph, Node, Edge};
+use crate::graph::{VowlGraph, Node, NodeType, Edge, EdgeType};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use theme::{EdgeStyle, VowlTheme};
+use viewport::Viewport;
 
 /// Trait for rendering graphs
 #[cfg_attr(test, mockall::automock)]
@@ -16,11 +28,150 @@ pub trait Renderer {
     fn render_edge(&self, edge: &Edge, from: &Node, to: &Node) -> Result<String>;
 }
 
+/// Position change of a single node between two renders
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeDelta {
+    /// Node identifier
+    pub id: String,
+
+    /// New X coordinate
+    pub x: f64,
+
+    /// New Y coordinate
+    pub y: f64,
+}
+
+/// Changes between a previous render and the current graph state
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DeltaUpdate {
+    /// Nodes that moved further than the delta threshold
+    pub moved: Vec<NodeDelta>,
+
+    /// Nodes not present in the previous state
+    pub added: Vec<String>,
+
+    /// Nodes present in the previous state but no longer in the graph
+    pub removed: Vec<String>,
+}
+
+impl DeltaUpdate {
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Radius of a node circle in the default theme
+const NODE_RADIUS: f64 = 20.0;
+
+/// Arrowhead marker drawn at the ends of an edge
+///
+/// The tip sits on the path end, which edges place on the node outline.
+/// Markers do not inherit the stroke or opacity of the edges referencing
+/// them, so each color and opacity gets a marker of its own.
+#[derive(Debug, Clone, PartialEq)]
+struct Marker {
+    /// Draw an outline only, as VOWL does for subclass arrows
+    hollow: bool,
+    /// Color of the arrowhead, that of its edge's stroke
+    color: String,
+    /// Opacity of the arrowhead
+    opacity: f64,
+}
+
+impl Marker {
+    /// Marker drawn on an edge with the given stroke
+    fn of(edge: &Edge, style: &EdgeStyle) -> Self {
+        Self {
+            hollow: edge.edge_type == EdgeType::SubClass,
+            color: style.color.clone(),
+            opacity: edge.opacity,
+        }
+    }
+
+    /// Marker ID, `arrow` or `arrow-subclass` suffixed with the color when
+    /// not the default one and with the opacity in percent when dimmed
+    fn id(&self) -> String {
+        let mut id = String::from(if self.hollow { "arrow-subclass" } else { "arrow" });
+        if self.color != EdgeStyle::default().color {
+            id.push('-');
+            id.extend(self.color.chars().filter(|c| c.is_ascii_alphanumeric()));
+        }
+        if self.opacity < 1.0 {
+            id.push_str(&format!("-o{}", (self.opacity * 100.0).round()));
+        }
+        id
+    }
+
+    /// `<marker>` element, oriented with `auto-start-reverse` so
+    /// `marker-start` points backwards
+    fn svg(&self) -> String {
+        format!(
+            r##"
+    <marker id="{}" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="10" markerHeight="10" markerUnits="userSpaceOnUse" orient="auto-start-reverse">
+      <path d="M 0 0 L 10 5 L 0 10 z" fill="{}" stroke="{}"{}/>
+    </marker>"##,
+            self.id(),
+            escape(if self.hollow { "#fff" } else { &self.color }),
+            escape(&self.color),
+            opacity_attrs(self.opacity)
+        )
+    }
+}
+
+/// ID of the hatch pattern filling deprecated classes
+const DEPRECATED_HATCH: &str = "deprecated-hatch";
+
+/// Escape text for SVG element content and quoted attribute values
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Opacity attributes for a dimmed element, empty at full opacity
+///
+/// Fill and stroke opacity are inherited, so setting them on a group also
+/// dims its labels and glyphs.
+fn opacity_attrs(opacity: f64) -> String {
+    if opacity >= 1.0 {
+        String::new()
+    } else {
+        format!(r#" fill-opacity="{0}" stroke-opacity="{0}""#, opacity)
+    }
+}
+
+/// Wrap a rendered edge in a group carrying its opacity
+fn with_edge_opacity(svg: String, edge: &Edge) -> String {
+    if edge.opacity >= 1.0 {
+        svg
+    } else {
+        format!("<g{}>{}</g>", opacity_attrs(edge.opacity), svg)
+    }
+}
+
+/// Unit vector pointing from `from` towards `to`
+fn direction(from: (f64, f64), to: (f64, f64)) -> (f64, f64) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = dx.hypot(dy).max(f64::EPSILON);
+    (dx / length, dy / length)
+}
+
 /// SVG renderer for graphs
 pub struct SvgRenderer {
     width: f64,
     height: f64,
     padding: f64,
+    delta_threshold: f64,
+    max_elements: Option<usize>,
+    self_loop_radius: f64,
+    parallel_spacing: f64,
+    bundling_strength: f64,
+    theme: VowlTheme,
+    edge_styles: HashMap<EdgeType, EdgeStyle>,
 }
 
 impl SvgRenderer {
@@ -30,6 +181,13 @@ impl SvgRenderer {
             width,
             height,
             padding: 20.0,
+            delta_threshold: 0.5,
+            max_elements: None,
+            self_loop_radius: NODE_RADIUS * 0.75,
+            parallel_spacing: 24.0,
+            bundling_strength: 0.0,
+            theme: VowlTheme::default(),
+            edge_styles: EdgeStyle::defaults(),
         }
     }
 
@@ -39,6 +197,389 @@ impl SvgRenderer {
         self
     }
 
+    /// Set the minimum distance a node must move to appear in a delta
+    pub fn with_delta_threshold(mut self, threshold: f64) -> Self {
+        self.delta_threshold = threshold;
+        self
+    }
+
+    /// Limit the number of individually drawn nodes
+    ///
+    /// When the graph has more nodes than `max`, only the `max` highest-degree
+    /// nodes are drawn; the rest are summarized as cluster glyphs attached to
+    /// the nearest drawn node.
+    pub fn with_max_elements(mut self, max: usize) -> Self {
+        self.max_elements = Some(max);
+        self
+    }
+
+    /// Set the radius of the loop drawn for self-referential edges
+    pub fn with_self_loop_radius(mut self, radius: f64) -> Self {
+        self.self_loop_radius = radius;
+        self
+    }
+
+    /// Set the distance between the arcs of edges sharing a node pair
+    pub fn with_parallel_spacing(mut self, spacing: f64) -> Self {
+        self.parallel_spacing = spacing;
+        self
+    }
+
+    /// Set how strongly nearby, roughly parallel edges are bent together
+    ///
+    /// 0 (the default) keeps edges straight; see [`bundling::bundle`].
+    pub fn with_bundling_strength(mut self, strength: f64) -> Self {
+        self.bundling_strength = strength;
+        self
+    }
+
+    /// Set the colors and sizes used for nodes
+    pub fn with_theme(mut self, theme: VowlTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Set the stroke of edges by type
+    ///
+    /// Replaces the style of each given type; other types keep theirs, see
+    /// [`EdgeStyle::defaults`]. Types without a style use
+    /// [`EdgeStyle::default`].
+    pub fn with_edge_styles(mut self, styles: HashMap<EdgeType, EdgeStyle>) -> Self {
+        self.edge_styles.extend(styles);
+        self
+    }
+
+    /// Distance from a node's center to its outline in direction `(ux, uy)`
+    ///
+    /// Datatypes are drawn as rectangles three base radii wide and one high,
+    /// everything else as circles sized by the theme.
+    fn outline_distance(&self, node: &Node, (ux, uy): (f64, f64)) -> f64 {
+        if node.node_type == NodeType::Datatype {
+            let radius = self.theme.base_radius;
+            (1.5 * radius / ux.abs()).min(0.5 * radius / uy.abs())
+        } else {
+            self.theme.node_radius(node)
+        }
+    }
+
+    /// Style of an edge's type, [`EdgeStyle::default`] if it has none
+    fn edge_style(&self, edge: &Edge) -> Cow<'_, EdgeStyle> {
+        self.edge_styles
+            .get(&edge.edge_type)
+            .map_or_else(|| Cow::Owned(EdgeStyle::default()), Cow::Borrowed)
+    }
+
+    /// Arrowhead marker of an edge, colored like its stroke
+    fn marker(&self, edge: &Edge) -> Marker {
+        Marker::of(edge, &self.edge_style(edge))
+    }
+
+    /// Stroke attributes of an edge
+    ///
+    /// Chained properties are derived and drawn dashed unless their type's
+    /// style sets a dash of its own.
+    fn stroke_attrs(&self, edge: &Edge) -> String {
+        let style = self.edge_style(edge);
+        let dash = style
+            .dash
+            .as_deref()
+            .or((!edge.chain.is_empty()).then_some("4,2"))
+            .map(|dash| format!(r#" stroke-dasharray="{}""#, dash))
+            .unwrap_or_default();
+
+        format!(r#"stroke="{}" stroke-width="{}"{}"#, style.color, style.width, dash)
+    }
+
+    /// Render an edge whose domain and range are the same node
+    ///
+    /// The loop sits on top of the node, away from the node label below it,
+    /// and the edge label is placed just outside the loop.
+    fn render_self_loop(&self, edge: &Edge, node: &Node) -> String {
+        let r = self.self_loop_radius;
+        let (x, y) = (node.visual.x, node.visual.y);
+        let radius = self.outline_distance(node, (0.0, -1.0));
+
+        // Anchor both ends on the node outline, no wider than the loop itself
+        let half_width = r.min(radius) * 0.7;
+        let anchor_y = y - (radius * radius - half_width * half_width).sqrt();
+        let top = anchor_y - r - (r * r - half_width * half_width).sqrt();
+
+        format!(
+            r##"<path class="self-loop" data-node="{}" d="M {} {} A {} {} 0 1 1 {} {}" fill="none" {} marker-end="url(#{})"/>
+      <text x="{}" y="{}" text-anchor="middle" font-size="10" fill="#666">{}</text>"##,
+            escape(&node.id),
+            x - half_width,
+            anchor_y,
+            r,
+            r,
+            x + half_width,
+            anchor_y,
+            self.stroke_attrs(edge),
+            self.marker(edge).id(),
+            x,
+            top - 5.0,
+            escape(&edge.label)
+        ) + &glyph::characteristic_glyphs(&edge.characteristics, x, top)
+    }
+
+    /// Offset of each edge from the straight line between its endpoints
+    ///
+    /// Edges sharing an unordered node pair are fanned out symmetrically, so
+    /// with an odd count the middle one stays straight. Offsets are relative
+    /// to the pair's canonical direction, which keeps edges in opposite
+    /// directions on separate arcs.
+    fn parallel_offsets(&self, edges: &[(&str, &Edge, &str)]) -> Vec<f64> {
+        fn pair<'a>(from: &'a str, to: &'a str) -> (&'a str, &'a str) {
+            if from <= to {
+                (from, to)
+            } else {
+                (to, from)
+            }
+        }
+
+        let mut counts: HashMap<(&str, &str), usize> = HashMap::new();
+        for &(from, _, to) in edges {
+            *counts.entry(pair(from, to)).or_default() += 1;
+        }
+
+        let mut seen: HashMap<(&str, &str), usize> = HashMap::new();
+        edges
+            .iter()
+            .map(|&(from, _, to)| {
+                let key = pair(from, to);
+                let index = seen.entry(key).or_default();
+                let slot = *index as f64 - (counts[&key] - 1) as f64 / 2.0;
+                *index += 1;
+
+                let offset = slot * self.parallel_spacing;
+                if from <= to {
+                    offset
+                } else {
+                    -offset
+                }
+            })
+            .collect()
+    }
+
+    /// Render an edge bent `offset` units to the left of its direction
+    ///
+    /// A zero offset draws a straight line; otherwise a quadratic curve whose
+    /// apex lies `offset` away from the midpoint. The apex is further moved by
+    /// `shift`, the displacement from edge bundling. Label and glyphs sit at
+    /// the apex.
+    fn render_edge_with_offset(
+        &self,
+        edge: &Edge,
+        from: &Node,
+        to: &Node,
+        offset: f64,
+        shift: (f64, f64),
+    ) -> String {
+        let stroke = self.stroke_attrs(edge);
+
+        // A merged inverse pair points both ways
+        let marker = self.marker(edge).id();
+        let start = if edge.characteristics.bidirectional {
+            format!(r##" marker-start="url(#{})""##, marker)
+        } else {
+            String::new()
+        };
+
+        let (source, target) = ((from.visual.x, from.visual.y), (to.visual.x, to.visual.y));
+        let (ux, uy) = direction(source, target);
+        let (nx, ny) = (uy, -ux);
+
+        let mid_x = (from.visual.x + to.visual.x) / 2.0;
+        let mid_y = (from.visual.y + to.visual.y) / 2.0;
+
+        // The curve's apex is half way between the chord and the control point
+        let (apex_x, apex_y) = (mid_x + nx * offset + shift.0, mid_y + ny * offset + shift.1);
+        let control = (2.0 * apex_x - mid_x, 2.0 * apex_y - mid_y);
+
+        // Ends carrying a marker stop on the node outline, where the tip goes
+        let end = direction(control, target);
+        let end_radius = self.outline_distance(to, end);
+        let (x2, y2) = (target.0 - end.0 * end_radius, target.1 - end.1 * end_radius);
+        let (x1, y1) = if edge.characteristics.bidirectional {
+            let begin = direction(source, control);
+            let begin_radius = self.outline_distance(from, begin);
+            (source.0 + begin.0 * begin_radius, source.1 + begin.1 * begin_radius)
+        } else {
+            source
+        };
+
+        let mut svg = if offset == 0.0 && shift == (0.0, 0.0) {
+            format!(
+                r##"<line x1="{}" y1="{}" x2="{}" y2="{}" {}{} marker-end="url(#{})"/>"##,
+                x1, y1, x2, y2, stroke, start, marker
+            )
+        } else {
+            format!(
+                r##"<path class="parallel-edge" d="M {} {} Q {} {} {} {}" fill="none" {}{} marker-end="url(#{})"/>"##,
+                x1,
+                y1,
+                control.0,
+                control.1,
+                x2,
+                y2,
+                stroke,
+                start,
+                marker
+            )
+        };
+
+        // The label sits above the characteristic glyphs at the apex
+        if !edge.label.is_empty() {
+            svg.push_str(&format!(
+                r##"
+      <text class="edge-label" x="{}" y="{}" text-anchor="middle" font-size="10" fill="#666">{}</text>"##,
+                apex_x,
+                apex_y - 10.0,
+                escape(&edge.label)
+            ));
+        }
+
+        // Cardinality goes next to the arrowhead, offset to one side of the
+        // curve's final direction
+        if let Some(text) = edge.characteristics.cardinality.and_then(glyph::cardinality_text) {
+            let back = end_radius + 10.0;
+            svg.push_str(&format!(
+                r##"
+      <text class="cardinality" x="{}" y="{}" text-anchor="middle" font-size="9" fill="#333">{}</text>"##,
+                target.0 - end.0 * back - end.1 * 8.0,
+                target.1 - end.1 * back + end.0 * 8.0,
+                escape(&text)
+            ));
+        }
+
+        svg + &glyph::characteristic_glyphs(&edge.characteristics, apex_x, apex_y)
+    }
+
+    /// Bundling displacement of each edge's apex, in input order
+    ///
+    /// Self-loops and edges with unknown endpoints are left out of bundling.
+    fn bundle_shifts(&self, graph: &VowlGraph, edges: &[(&str, &Edge, &str)]) -> Vec<(f64, f64)> {
+        let segment = |from: &str, to: &str| -> Option<bundling::Segment> {
+            let (from, to) = (graph.get_node(from)?, graph.get_node(to)?);
+            (from.id != to.id)
+                .then_some(((from.visual.x, from.visual.y), (to.visual.x, to.visual.y)))
+        };
+
+        let bundled: Vec<(usize, bundling::Segment)> = edges
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &(from, _, to))| Some((i, segment(from, to)?)))
+            .collect();
+        let segments: Vec<bundling::Segment> = bundled.iter().map(|&(_, s)| s).collect();
+
+        let mut shifts = vec![(0.0, 0.0); edges.len()];
+        let bundled_shifts = bundling::bundle(&segments, self.bundling_strength);
+        for (&(i, _), shift) in bundled.iter().zip(bundled_shifts) {
+            shifts[i] = shift;
+        }
+        shifts
+    }
+
+    /// Split nodes into those drawn individually and per-node cluster counts
+    fn select_visible<'a>(&self, graph: &'a VowlGraph) -> (Vec<&'a Node>, Vec<usize>) {
+        let nodes: Vec<&Node> = graph.nodes().into_iter().filter(|n| !n.visual.hidden).collect();
+        let budget = match self.max_elements {
+            Some(max) if nodes.len() > max => max,
+            _ => {
+                let len = nodes.len();
+                return (nodes, vec![0; len]);
+            }
+        };
+
+        let mut ranked: Vec<(&Node, usize)> = nodes
+            .into_iter()
+            .map(|n| (n, graph.degree(&n.id).unwrap_or(0)))
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then(b.0.visual.weight.total_cmp(&a.0.visual.weight))
+        });
+
+        let hidden = ranked.split_off(budget);
+        let visible: Vec<&Node> = ranked.into_iter().map(|(n, _)| n).collect();
+        let mut counts = vec![0; visible.len()];
+
+        if !visible.is_empty() {
+            for (node, _) in hidden {
+                let nearest = visible
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        let dx = v.visual.x - node.visual.x;
+                        let dy = v.visual.y - node.visual.y;
+                        (i, dx * dx + dy * dy)
+                    })
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                counts[nearest] += 1;
+            }
+        }
+
+        (visible, counts)
+    }
+
+    /// Render an aggregate glyph for nodes summarized into `node`
+    fn render_cluster(&self, node: &Node, count: usize) -> String {
+        let x = node.visual.x + 20.0;
+        let y = node.visual.y - 20.0;
+        format!(
+            r##"<g class="cluster" data-node="{}">
+      <rect x="{}" y="{}" width="28" height="16" rx="8" fill="#ddd" stroke="#333"/>
+      <text x="{}" y="{}" text-anchor="middle" font-size="10" fill="#333">+{}</text>
+    </g>"##,
+            escape(&node.id),
+            x - 14.0,
+            y - 8.0,
+            x,
+            y + 3.0,
+            count
+        )
+    }
+
+    /// Compute which nodes changed since a previous render
+    ///
+    /// `previous` maps node IDs to the positions they were last rendered at,
+    /// as returned by [`VowlGraph::positions`].
+    pub fn render_delta(
+        &self,
+        graph: &VowlGraph,
+        previous: &HashMap<String, (f64, f64)>,
+    ) -> DeltaUpdate {
+        let mut delta = DeltaUpdate::default();
+
+        for node in graph.nodes().into_iter().filter(|n| !n.visual.hidden) {
+            match previous.get(&node.id) {
+                Some(&(px, py)) => {
+                    let dx = node.visual.x - px;
+                    let dy = node.visual.y - py;
+                    if (dx * dx + dy * dy).sqrt() > self.delta_threshold {
+                        delta.moved.push(NodeDelta {
+                            id: node.id.clone(),
+                            x: node.visual.x,
+                            y: node.visual.y,
+                        });
+                    }
+                }
+                None => delta.added.push(node.id.clone()),
+            }
+        }
+
+        delta.removed = previous
+            .keys()
+            .filter(|id| graph.get_node(id).is_none_or(|n| n.visual.hidden))
+            .cloned()
+            .collect();
+        delta.removed.sort();
+
+        delta
+    }
+
     /// Generate SVG header
     fn svg_header(&self) -> String {
         format!(
@@ -47,99 +588,223 @@ impl SvgRenderer {
         )
     }
 
+    /// Generate the `<defs>` block with the arrowhead markers of the given
+    /// edges and the hatch pattern of deprecated classes
+    fn svg_defs<'a>(&self, edges: impl IntoIterator<Item = &'a Edge>) -> String {
+        let mut defs = String::from("\n  <defs>");
+        let mut defined = HashSet::new();
+        for marker in edges.into_iter().map(|edge| self.marker(edge)) {
+            if defined.insert(marker.id()) {
+                defs.push_str(&marker.svg());
+            }
+        }
+        defs.push_str(&format!(
+            r##"
+    <pattern id="{}" width="6" height="6" patternUnits="userSpaceOnUse" patternTransform="rotate(45)">
+      <rect width="6" height="6" fill="{}"/>
+      <path d="M 0 0 L 0 6" stroke="#999" stroke-width="2"/>
+    </pattern>"##,
+            DEPRECATED_HATCH, self.theme.deprecated_color
+        ));
+        defs.push_str("\n  </defs>");
+        defs
+    }
+
     /// Generate SVG footer
     fn svg_footer(&self) -> &str {
         "</svg>"
     }
 
-    /// Normalize coordinates to SVG viewport
-    fn normalize_coords(&self, x: f64, y: f64, graph: &VowlGraph) -> (f64, f64) {
-        // Find bounding box
-        let nodes = graph.nodes();
-        if nodes.is_empty() {
-            return (self.width / 2.0, self.height / 2.0);
-        }
+    /// Viewport covering the SVG canvas
+    fn viewport(&self) -> Viewport {
+        Viewport::new(self.width, self.height, self.padding)
+    }
 
-        let min_x = nodes.iter().map(|n| n.visual.x).fold(f64::INFINITY, f64::min);
-        let max_x = nodes.iter().map(|n| n.visual.x).fold(f64::NEG_INFINITY, f64::max);
-        let min_y = nodes.iter().map(|n| n.visual.y).fold(f64::INFINITY, f64::min);
-        let max_y = nodes.iter().map(|n| n.visual.y).fold(f64::NEG_INFINITY, f64::max);
+    /// Render a small-scale overview of the whole graph
+    ///
+    /// `viewport` is the `(x, y, width, height)` of the current detail view in
+    /// graph coordinates; it is drawn as a rectangle over the overview.
+    pub fn render_overview(&self, graph: &VowlGraph, viewport: (f64, f64, f64, f64)) -> String {
+        let transform = self.viewport().fit(graph);
+        let mut svg = String::new();
+        svg.push_str(&self.svg_header());
+        svg.push_str("\n  <g id=\"overview\">\n");
 
-        let range_x = max_x - min_x;
-        let range_y = max_y - min_y;
+        for (from, _, to) in graph.edge_endpoints() {
+            let (Some(from), Some(to)) = (graph.get_node(from), graph.get_node(to)) else {
+                continue;
+            };
+            if from.visual.hidden || to.visual.hidden {
+                continue;
+            }
+            let (x1, y1) = transform.apply(from.visual.x, from.visual.y);
+            let (x2, y2) = transform.apply(to.visual.x, to.visual.y);
+            svg.push_str(&format!(
+                "    <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#ccc\" stroke-width=\"0.5\"/>\n",
+                x1, y1, x2, y2
+            ));
+        }
 
-        let scale_x = (self.width - 2.0 * self.padding) / range_x.max(1.0);
-        let scale_y = (self.height - 2.0 * self.padding) / range_y.max(1.0);
-        let scale = scale_x.min(scale_y);
+        for node in graph.nodes().into_iter().filter(|n| !n.visual.hidden) {
+            let (x, y) = transform.apply(node.visual.x, node.visual.y);
+            let color = self.theme.node_color(node);
+            svg.push_str(&format!(
+                "    <circle cx=\"{}\" cy=\"{}\" r=\"2\" fill=\"{}\"/>\n",
+                x, y, color
+            ));
+        }
 
-        let norm_x = (x - min_x) * scale + self.padding;
-        let norm_y = (y - min_y) * scale + self.padding;
+        let (vx, vy, vw, vh) = viewport;
+        let (x1, y1) = transform.apply(vx, vy);
+        let (x2, y2) = transform.apply(vx + vw, vy + vh);
+        svg.push_str(&format!(
+            "    <rect class=\"viewport\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"none\" stroke=\"#f44336\" stroke-width=\"1\"/>\n",
+            x1,
+            y1,
+            x2 - x1,
+            y2 - y1
+        ));
 
-        (norm_x, norm_y)
+        svg.push_str("  </g>\n");
+        svg.push_str(self.svg_footer());
+        svg
     }
 }
 
 impl Renderer for SvgRenderer {
     fn render(&self, graph: &VowlGraph) -> Result<String> {
         let mut svg = String::new();
+        let (visible, clusters) = self.select_visible(graph);
+        let visible_ids: HashSet<&str> = visible.iter().map(|n| n.id.as_str()).collect();
+
+        // Render edges (behind nodes), fanning out those sharing a node pair
+        let edges: Vec<(&str, &Edge, &str)> = graph
+            .edge_endpoints()
+            .into_iter()
+            .filter(|(from, _, to)| visible_ids.contains(from) && visible_ids.contains(to))
+            .collect();
 
         svg.push_str(&self.svg_header());
+        svg.push_str(&self.svg_defs(edges.iter().map(|&(_, edge, _)| edge)));
         svg.push_str("\n  <g id=\"edges\">\n");
-
-        // Render edges (behind nodes)
-        // Note: This is simplified - proper implementation would need edge-node mapping
-        for edge in graph.edges() {
-            svg.push_str("    <!-- Edge: ");
-            svg.push_str(&edge.label);
-            svg.push_str(" -->\n");
+        let offsets = self.parallel_offsets(&edges);
+        let shifts = self.bundle_shifts(graph, &edges);
+        for (((from, edge, to), offset), shift) in edges.into_iter().zip(offsets).zip(shifts) {
+            let (Some(from), Some(to)) = (graph.get_node(from), graph.get_node(to)) else {
+                continue;
+            };
+            let rendered = if from.id == to.id {
+                self.render_self_loop(edge, from)
+            } else {
+                self.render_edge_with_offset(edge, from, to, offset, shift)
+            };
+            svg.push_str(&format!("    {}\n", with_edge_opacity(rendered, edge)));
         }
 
         svg.push_str("  </g>\n  <g id=\"nodes\">\n");
 
         // Render nodes
-        for node in graph.nodes() {
+        for node in &visible {
             svg.push_str(&format!("    {}\n", self.render_node(node)?));
         }
 
         svg.push_str("  </g>\n");
+
+        // Summarize nodes left out by the element budget
+        let hidden: usize = clusters.iter().sum();
+        if hidden > 0 {
+            svg.push_str("  <g id=\"clusters\">\n");
+            for (node, &count) in visible.iter().zip(&clusters) {
+                if count > 0 {
+                    svg.push_str(&format!("    {}\n", self.render_cluster(node, count)));
+                }
+            }
+            svg.push_str("  </g>\n");
+            svg.push_str(&format!(
+                "  <text id=\"summary\" x=\"{}\" y=\"{}\" font-size=\"12\" fill=\"#333\">{} of {} nodes shown</text>\n",
+                self.padding,
+                self.padding,
+                visible.len(),
+                visible.len() + hidden
+            ));
+        }
+
         svg.push_str(self.svg_footer());
 
         Ok(svg)
     }
 
     fn render_node(&self, node: &Node) -> Result<String> {
-        // Simplified rendering - actual implementation would have more styling
-        let radius = 20.0;
-        let color = node.visual.color.as_deref().unwrap_or("#4CAF50");
+        // Deprecated classes are hatched in the theme's gray unless the node
+        // has its own color
+        let deprecated = node.semantic.deprecated && node.visual.color.is_none();
+        let (class, color) = if deprecated {
+            (r#" class="deprecated""#, format!("url(#{})", DEPRECATED_HATCH))
+        } else {
+            ("", self.theme.node_color(node).to_string())
+        };
+
+        // owl:Thing and owl:Nothing are drawn with a dashed outline, as in VOWL
+        let dash = match &node.node_type {
+            NodeType::Special(name) if name == "Thing" || name == "Nothing" => {
+                r#" stroke-dasharray="4,2""#
+            }
+            _ => "",
+        };
+
+        // Datatypes are drawn as fixed-size rectangles, everything else as
+        // circles sized by the theme
+        let radius = if node.node_type == NodeType::Datatype {
+            self.theme.base_radius
+        } else {
+            self.theme.node_radius(node)
+        };
+        let shape = if node.node_type == NodeType::Datatype {
+            format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+                node.visual.x - radius * 1.5,
+                node.visual.y - radius / 2.0,
+                radius * 3.0,
+                radius
+            )
+        } else {
+            format!(
+                r#"<circle cx="{}" cy="{}" r="{}""#,
+                node.visual.x, node.visual.y, radius
+            )
+        };
 
         Ok(format!(
-            r##"<g id="{}">
-      <circle cx="{}" cy="{}" r="{}" fill="{}" stroke="{{0}}" stroke-width="2"/>
-      <text x="{}" y="{}" text-anchor="middle" dy="{{1}}" font-size="12" fill="{{0}}">{}</text>
+            r##"<g id="{}"{}{}>
+      {} fill="{}" stroke="#333" stroke-width="2"{}/>
+      <text x="{}" y="{}" text-anchor="middle" dy=".3em" font-size="12" fill="#333">{}</text>
     </g>"##,
-            node.id,
-            node.visual.x,
-            node.visual.y,
-            radius,
-            color,
+            escape(&node.id),
+            class,
+            opacity_attrs(node.visual.opacity),
+            shape,
+            escape(&color),
+            dash,
             node.visual.x,
             node.visual.y + radius + 15.0,
-            node.label
-        ).replace("{0}", "#333").replace("{1}", ".3em"))
+            escape(&node.label)
+        ))
     }
 
     fn render_edge(&self, edge: &Edge, from: &Node, to: &Node) -> Result<String> {
-        Ok(format!(
-            r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{{0}}" stroke-width="1.5" marker-end="url({{1}})"/>"##,
-            from.visual.x, from.visual.y, to.visual.x, to.visual.y
-        ).replace("{0}", "#999").replace("{1}", "#arrow"))
+        if from.id == to.id {
+            return Ok(with_edge_opacity(self.render_self_loop(edge, from), edge));
+        }
+
+        let svg = self.render_edge_with_offset(edge, from, to, 0.0, (0.0, 0.0));
+        Ok(with_edge_opacity(svg, edge))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::node::NodeBuilder;
+    use crate::graph::{edge::EdgeBuilder, node::NodeBuilder};
 
     #[test]
     fn test_svg_renderer_creation() {
@@ -169,6 +834,484 @@ mod tests {
         assert!(svg.contains("circle"));
     }
 
+    #[test]
+    fn test_render_datatype_node_as_rect() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let node = NodeBuilder::new("xsd:string")
+            .label("string")
+            .node_type(NodeType::Datatype)
+            .position(100.0, 100.0)
+            .build();
+
+        let svg = renderer.render_node(&node).unwrap();
+        assert!(svg.contains(r##"<rect x="70" y="90" width="60" height="20" fill="#FFCC33""##));
+        assert!(!svg.contains("circle"));
+    }
+
+    #[test]
+    fn test_render_deprecated_class() {
+        use crate::graph::builder::GraphBuilder;
+        use crate::ontology::{parser::StandardParser, OntologyParser};
+
+        let json = r#"
+        {
+            "class": [
+                {"id": "old", "deprecated": true},
+                {"id": "current"}
+            ],
+            "property": []
+        }
+        "#;
+        let data = StandardParser::new().parse(json).unwrap();
+        let graph = GraphBuilder::from_ontology(&data).unwrap();
+        assert!(graph.get_node("old").unwrap().semantic.deprecated);
+
+        let svg = SvgRenderer::new(800.0, 600.0).render(&graph).unwrap();
+
+        assert!(svg.contains(r#"<pattern id="deprecated-hatch""#));
+        assert!(svg.contains(r#"<g id="old" class="deprecated">"#));
+        assert!(svg.contains(r##"fill="url(#deprecated-hatch)""##));
+        assert!(svg.contains(r#"<g id="current">"#));
+        assert_eq!(svg.matches("url(#deprecated-hatch)").count(), 1);
+    }
+
+    #[test]
+    fn test_render_node_uses_theme() {
+        let theme = VowlTheme {
+            datatype_color: "#ABCDEF".to_string(),
+            ..Default::default()
+        };
+        let renderer = SvgRenderer::new(800.0, 600.0).with_theme(theme);
+        let datatype = NodeBuilder::new("xsd:int")
+            .node_type(NodeType::Datatype)
+            .build();
+
+        let svg = renderer.render_node(&datatype).unwrap();
+        assert!(svg.contains(r##"<rect x="-30" y="-10" width="60" height="20" fill="#ABCDEF""##));
+    }
+
+    #[test]
+    fn test_render_class_radius_by_individuals() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let radius = |node: &Node| -> f64 {
+            let svg = renderer.render_node(node).unwrap();
+            let start = svg.find(" r=\"").unwrap() + 4;
+            let end = start + svg[start..].find('"').unwrap();
+            svg[start..end].parse().unwrap()
+        };
+
+        let empty = NodeBuilder::new("empty").build();
+        let mut populated = NodeBuilder::new("populated").build();
+        populated.semantic.individuals = Some(100);
+
+        assert!(radius(&populated) > radius(&empty));
+        assert_eq!(radius(&empty), 20.0);
+    }
+
+    #[test]
+    fn test_render_edge_with_characteristics() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+        let edge = EdgeBuilder::new("e").functional().build();
+
+        let svg = renderer.render_edge(&edge, &from, &to).unwrap();
+        assert!(svg.contains("<line"));
+        assert!(svg.contains("glyph-functional"));
+    }
+
+    #[test]
+    fn test_render_edge_label_and_cardinality() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("person").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("company").position(200.0, 0.0).build();
+        let edge = EdgeBuilder::new("worksFor")
+            .label("worksFor")
+            .cardinality(Some(1), None)
+            .build();
+
+        let svg = renderer.render_edge(&edge, &from, &to).unwrap();
+
+        assert!(svg.contains(r#"<text class="edge-label" x="100" y="-10""#));
+        assert!(svg.contains(">worksFor</text>"));
+        // 30 units back from the target, 8 units to the side
+        assert!(svg.contains(r#"<text class="cardinality" x="170" y="8""#));
+        assert!(svg.contains(">1..*</text>"));
+    }
+
+    #[test]
+    fn test_render_self_loop() {
+        let renderer = SvgRenderer::new(800.0, 600.0).with_self_loop_radius(10.0);
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("person").position(100.0, 100.0).build())
+            .unwrap();
+        graph
+            .add_edge("person", "person", EdgeBuilder::new("knows").build())
+            .unwrap();
+
+        let (from, edge, to) = graph.edge_endpoints()[0];
+        let svg = renderer
+            .render_edge(edge, graph.get_node(from).unwrap(), graph.get_node(to).unwrap())
+            .unwrap();
+
+        assert!(svg.contains(r#"<path class="self-loop" data-node="person""#));
+        assert!(svg.contains("A 10 10"));
+        assert!(!svg.contains("<line"));
+        assert!(svg.contains(">knows</text>"));
+    }
+
+    #[test]
+    fn test_render_self_loop_escapes_id_and_label() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let node = NodeBuilder::new("a\"b").position(0.0, 0.0).build();
+        let edge = EdgeBuilder::new("loop").label("<self>").build();
+
+        let svg = renderer.render_edge(&edge, &node, &node).unwrap();
+        assert!(svg.contains(r#"data-node="a&quot;b""#));
+        assert!(svg.contains(">&lt;self&gt;</text>"));
+    }
+
+    #[test]
+    fn test_render_chained_edge_is_dashed() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+
+        let plain = EdgeBuilder::new("p").build();
+        let chained = EdgeBuilder::new("c").chain(vec!["p".to_string(), "p".to_string()]).build();
+
+        assert!(!renderer.render_edge(&plain, &from, &to).unwrap().contains("stroke-dasharray"));
+        assert!(renderer.render_edge(&chained, &from, &to).unwrap().contains("stroke-dasharray"));
+    }
+
+    #[test]
+    fn test_render_escapes_labels() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").label("<&\">").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+        let edge = EdgeBuilder::new("ab").label("<&\">").build();
+
+        let escaped = "&lt;&amp;&quot;&gt;";
+        let node = renderer.render_node(&from).unwrap();
+        assert!(node.contains(&format!(">{}</text>", escaped)));
+        assert!(!node.contains("<&"));
+
+        let svg = renderer.render_edge(&edge, &from, &to).unwrap();
+        assert!(svg.contains(&format!(">{}</text>", escaped)));
+        assert!(!svg.contains("<&"));
+    }
+
+    #[test]
+    fn test_render_annotation_edge_is_dashed() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+
+        let annotation = EdgeBuilder::new("seeAlso").edge_type(EdgeType::Annotation).build();
+
+        let svg = renderer.render_edge(&annotation, &from, &to).unwrap();
+        assert!(svg.contains(r##"stroke="#999""##));
+        assert!(svg.contains(r#"stroke-dasharray="2,3""#));
+
+        let same_as = EdgeBuilder::new("sameAs").edge_type(EdgeType::SameAs).build();
+        let svg = renderer.render_edge(&same_as, &from, &to).unwrap();
+        assert!(svg.contains(r#"stroke-dasharray="8,4""#));
+    }
+
+    #[test]
+    fn test_render_edge_styles_by_type() {
+        let subclass_style = EdgeStyle {
+            color: "#ddd".to_string(),
+            width: 2.0,
+            dash: None,
+        };
+        let renderer = SvgRenderer::new(800.0, 600.0)
+            .with_edge_styles(HashMap::from([(EdgeType::SubClass, subclass_style)]));
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+
+        let subclass = EdgeBuilder::new("ab").edge_type(EdgeType::SubClass).build();
+        let svg = renderer.render_edge(&subclass, &from, &to).unwrap();
+        assert!(svg.contains(r##"stroke="#ddd" stroke-width="2""##));
+        assert!(!svg.contains("stroke-dasharray"));
+        assert!(svg.contains(r#"marker-end="url(#arrow-subclass-ddd)""#));
+        let defs = renderer.svg_defs([&subclass]);
+        assert!(defs.contains(r#"<marker id="arrow-subclass-ddd""#));
+        assert!(defs.contains(r##"fill="#fff" stroke="#ddd""##));
+
+        let property = EdgeBuilder::new("knows").build();
+        let svg = renderer.render_edge(&property, &from, &to).unwrap();
+        assert!(svg.contains(r##"stroke="#999" stroke-width="1.5""##));
+
+        // Built-in styles of other types are kept
+        let annotation = EdgeBuilder::new("seeAlso").edge_type(EdgeType::Annotation).build();
+        let svg = renderer.render_edge(&annotation, &from, &to).unwrap();
+        assert!(svg.contains(r#"stroke-dasharray="2,3""#));
+    }
+
+    #[test]
+    fn test_render_parallel_edges_as_separate_arcs() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("person").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("org").position(200.0, 0.0).build())
+            .unwrap();
+        for id in ["worksFor", "memberOf"] {
+            let edge = EdgeBuilder::new(id).label(id).build();
+            graph.add_edge("person", "org", edge).unwrap();
+        }
+
+        let svg = SvgRenderer::new(800.0, 600.0).render(&graph).unwrap();
+
+        let controls: Vec<Vec<&str>> = svg
+            .match_indices(r#"class="parallel-edge" d="M 0 0 Q "#)
+            .map(|(i, m)| svg[i + m.len()..].split_whitespace().take(2).collect())
+            .collect();
+        assert_eq!(controls.len(), 2, "{}", svg);
+        assert_ne!(controls[0], controls[1]);
+        assert!(!svg.contains("<line x1="));
+
+        // Labels follow their arcs instead of overlapping
+        let label_y = |id: &str| {
+            let end = svg.find(&format!(">{}</text>", id)).unwrap();
+            let start = svg[..end].rfind(r#"y=""#).unwrap() + 3;
+            svg[start..end].split('"').next().unwrap().to_string()
+        };
+        assert_ne!(label_y("worksFor"), label_y("memberOf"));
+    }
+
+    #[test]
+    fn test_opposite_edges_use_opposite_arcs() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let forward = EdgeBuilder::new("f").build();
+        let backward = EdgeBuilder::new("b").build();
+        let edges = [("a", &forward, "b"), ("b", &backward, "a")];
+
+        // Equal offsets relative to opposite directions put the arcs on
+        // opposite sides of the line between the nodes
+        let offsets = renderer.parallel_offsets(&edges);
+        assert_eq!(offsets, vec![-12.0, -12.0]);
+    }
+
+    #[test]
+    fn test_render_bidirectional_edge_has_both_arrows() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+
+        let plain = EdgeBuilder::new("p").build();
+        let inverse = EdgeBuilder::new("q").bidirectional().build();
+
+        assert!(!renderer.render_edge(&plain, &from, &to).unwrap().contains("marker-start"));
+        let svg = renderer.render_edge(&inverse, &from, &to).unwrap();
+        assert!(svg.contains(r#"marker-start="url(#arrow)""#));
+        assert!(svg.contains(r#"marker-end="url(#arrow)""#));
+    }
+
+    #[test]
+    fn test_marked_edge_ends_stop_on_node_outline() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let to = NodeBuilder::new("b").position(100.0, 0.0).build();
+
+        let plain = EdgeBuilder::new("p").build();
+        let svg = renderer.render_edge(&plain, &from, &to).unwrap();
+        assert!(svg.contains(r#"<line x1="0" y1="0" x2="80" y2="0""#));
+
+        let inverse = EdgeBuilder::new("q").bidirectional().build();
+        let svg = renderer.render_edge(&inverse, &from, &to).unwrap();
+        assert!(svg.contains(r#"<line x1="20" y1="0" x2="80" y2="0""#));
+
+        // Marker tips sit on the path end
+        let svg = renderer.svg_defs([&plain, &inverse]);
+        assert_eq!(svg.matches("<marker").count(), svg.matches(r#"refX="10""#).count());
+    }
+
+    #[test]
+    fn test_edge_geometry_follows_themed_radius() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let from = NodeBuilder::new("a").position(0.0, 0.0).build();
+        let mut to = NodeBuilder::new("b").position(100.0, 0.0).build();
+        to.semantic.individuals = Some(15);
+        let edge = EdgeBuilder::new("p").cardinality(Some(1), None).build();
+
+        // A radius of 40 moves both the line end and the cardinality back
+        let svg = renderer.render_edge(&edge, &from, &to).unwrap();
+        assert!(svg.contains(r#"x2="60" y2="0""#));
+        assert!(svg.contains(r#"<text class="cardinality" x="50" y="8""#));
+
+        // Datatype rectangles are three base radii wide
+        let datatype = NodeBuilder::new("xsd:string")
+            .node_type(NodeType::Datatype)
+            .position(100.0, 0.0)
+            .build();
+        let svg = renderer.render_edge(&edge, &from, &datatype).unwrap();
+        assert!(svg.contains(r#"x2="70" y2="0""#));
+
+        // Self-loops are anchored outside the enlarged circle
+        let svg = renderer.render_edge(&edge, &to, &to).unwrap();
+        let start = svg.split(r#" d="M "#).nth(1).unwrap();
+        let anchor_y: f64 = start.split(' ').nth(1).unwrap().parse().unwrap();
+        assert!(anchor_y < -38.0, "loop anchored at {}", anchor_y);
+    }
+
+    #[test]
+    fn test_edges_reference_defined_markers() {
+        let mut graph = VowlGraph::new();
+        for (id, x) in [("a", 0.0), ("b", 100.0), ("c", 200.0)] {
+            graph.add_node(NodeBuilder::new(id).position(x, 0.0).build()).unwrap();
+        }
+        let subclass = EdgeBuilder::new("ab").edge_type(EdgeType::SubClass).build();
+        graph.add_edge("a", "b", subclass).unwrap();
+        graph.add_edge("b", "c", EdgeBuilder::new("bc").build()).unwrap();
+        graph.add_edge("c", "c", EdgeBuilder::new("cc").build()).unwrap();
+
+        let svg = SvgRenderer::new(800.0, 600.0).render(&graph).unwrap();
+
+        assert!(svg.contains("<defs>"));
+        assert!(svg.contains("<marker"));
+        let defined: HashSet<&str> = svg
+            .split(r#"<marker id=""#)
+            .skip(1)
+            .filter_map(|rest| rest.split('"').next())
+            .collect();
+        let referenced: Vec<&str> = svg
+            .split("url(#")
+            .skip(1)
+            .filter_map(|rest| rest.split(')').next())
+            .collect();
+        assert_eq!(referenced.len(), 3);
+        for id in &referenced {
+            assert!(defined.contains(id), "marker '{}' is not defined", id);
+        }
+        assert!(referenced.contains(&"arrow-subclass"));
+    }
+
+    #[test]
+    fn test_bundling_bends_near_parallel_edges() {
+        let mut graph = VowlGraph::new();
+        let nodes = [("a", 0.0, 0.0), ("b", 200.0, 0.0), ("c", 0.0, 20.0), ("d", 200.0, 24.0)];
+        for (id, x, y) in nodes {
+            graph.add_node(NodeBuilder::new(id).position(x, y).build()).unwrap();
+        }
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.add_edge("c", "d", EdgeBuilder::new("cd").build()).unwrap();
+
+        let svg = SvgRenderer::new(800.0, 600.0).render(&graph).unwrap();
+        assert_eq!(svg.matches("<line").count(), 2);
+
+        let svg = SvgRenderer::new(800.0, 600.0)
+            .with_bundling_strength(1.0)
+            .render(&graph)
+            .unwrap();
+        assert_eq!(svg.matches("<line").count(), 0);
+
+        // Both control points lie strictly between the two edges
+        let controls: Vec<f64> = svg
+            .split(" Q ")
+            .skip(1)
+            .filter_map(|rest| rest.split(' ').nth(1)?.parse().ok())
+            .collect();
+        assert_eq!(controls.len(), 2);
+        for y in controls {
+            assert!(y > 1.0 && y < 21.0, "control point y {}", y);
+        }
+    }
+
+    #[test]
+    fn test_dimmed_elements_render_with_opacity() {
+        let mut graph = VowlGraph::new();
+        for (id, x) in [("a", 0.0), ("b", 100.0), ("c", 200.0)] {
+            graph.add_node(NodeBuilder::new(id).position(x, 0.0).build()).unwrap();
+        }
+        graph.add_edge("a", "b", EdgeBuilder::new("ab").build()).unwrap();
+        graph.add_edge("b", "c", EdgeBuilder::new("bc").build()).unwrap();
+        let renderer = SvgRenderer::new(800.0, 600.0);
+
+        let svg = renderer.render(&graph).unwrap();
+        assert!(!svg.contains("-opacity="));
+
+        graph.highlight("a", 0.25).unwrap();
+        let svg = renderer.render(&graph).unwrap();
+        assert!(svg.contains(r#"<g id="a">"#));
+        assert!(svg.contains(r#"<g id="c" fill-opacity="0.25" stroke-opacity="0.25">"#));
+        assert_eq!(svg.matches(r#"stroke-opacity="0.25""#).count(), 3);
+
+        // Markers do not inherit opacity, so the dimmed edge has its own
+        assert!(svg.contains(r#"<marker id="arrow-o25""#));
+        assert!(svg.contains(r##"fill="#999" stroke="#999" fill-opacity="0.25""##));
+        assert_eq!(svg.matches(r#"marker-end="url(#arrow-o25)""#).count(), 1);
+        assert_eq!(svg.matches(r#"marker-end="url(#arrow)""#).count(), 1);
+    }
+
+    #[test]
+    fn test_render_overview_viewport_indicator() {
+        let renderer = SvgRenderer::new(220.0, 220.0).with_padding(10.0);
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(100.0, 100.0).build())
+            .unwrap();
+
+        let svg = renderer.render_overview(&graph, (25.0, 25.0, 50.0, 50.0));
+
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert!(svg.contains(
+            r#"<rect class="viewport" x="60" y="60" width="100" height="100""#
+        ));
+    }
+
+    #[test]
+    fn test_render_draws_edge_lines() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(10.0, 20.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(110.0, 20.0).build())
+            .unwrap();
+        graph
+            .add_edge("a", "b", EdgeBuilder::new("e").label("links").build())
+            .unwrap();
+
+        let svg = renderer.render(&graph).unwrap();
+
+        // The line stops on the outline of the target
+        assert!(svg.contains(r#"<line x1="10" y1="20" x2="90" y2="20""#));
+        assert!(!svg.contains("<!-- Edge"));
+
+        // Edges are emitted before nodes so they render behind them
+        assert!(svg.find("<line").unwrap() < svg.find("<circle").unwrap());
+    }
+
+    #[test]
+    fn test_render_skips_hidden_nodes() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(100.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_edge("a", "b", EdgeBuilder::new("e").build())
+            .unwrap();
+        graph.get_node_mut("b").unwrap().visual.hidden = true;
+
+        let svg = renderer.render(&graph).unwrap();
+
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert!(!svg.contains("<line"));
+
+        let previous = HashMap::from([("b".to_string(), (100.0, 0.0))]);
+        assert_eq!(renderer.render_delta(&graph, &previous).removed, vec!["b"]);
+    }
+
     #[test]
     fn test_render_empty_graph() {
         let renderer = SvgRenderer::new(800.0, 600.0);
@@ -181,4 +1324,90 @@ mod tests {
         assert!(svg.contains("<svg"));
         assert!(svg.contains("</svg>"));
     }
+
+    #[test]
+    fn test_render_max_elements_summarizes() {
+        let renderer = SvgRenderer::new(800.0, 600.0).with_max_elements(10);
+        let mut graph = VowlGraph::new();
+        for i in 0..100 {
+            graph
+                .add_node(
+                    NodeBuilder::new(format!("n{}", i))
+                        .position(i as f64 * 3.0, (i % 7) as f64 * 5.0)
+                        .build(),
+                )
+                .unwrap();
+        }
+        for i in 1..100 {
+            graph
+                .add_edge("n0", &format!("n{}", i), EdgeBuilder::new(format!("e{}", i)).build())
+                .unwrap();
+        }
+
+        let svg = renderer.render(&graph).unwrap();
+
+        assert!(svg.matches("<circle").count() <= 10);
+        assert!(svg.contains(r#"<g id="n0">"#), "hub should be drawn");
+        assert!(svg.contains(r#"id="summary""#));
+        assert!(svg.contains("10 of 100 nodes shown"));
+        assert!(svg.contains(r#"class="cluster""#));
+    }
+
+    #[test]
+    fn test_render_cluster_escapes_id() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let node = NodeBuilder::new("a\"b").build();
+
+        let cluster = renderer.render_cluster(&node, 3);
+        assert!(cluster.contains(r#"data-node="a&quot;b""#));
+    }
+
+    #[test]
+    fn test_render_under_budget_has_no_summary() {
+        let renderer = SvgRenderer::new(800.0, 600.0).with_max_elements(10);
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("a").build()).unwrap();
+
+        let svg = renderer.render(&graph).unwrap();
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert!(!svg.contains("summary"));
+    }
+
+    #[test]
+    fn test_render_delta_only_moved_node() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(0.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(10.0, 10.0).build())
+            .unwrap();
+
+        let previous = graph.positions();
+        graph.get_node_mut("b").unwrap().visual.x = 50.0;
+        graph.get_node_mut("a").unwrap().visual.x = 0.1;
+
+        let delta = renderer.render_delta(&graph, &previous);
+        assert_eq!(delta.moved.len(), 1);
+        assert_eq!(delta.moved[0].id, "b");
+        assert_eq!(delta.moved[0].x, 50.0);
+        assert!(delta.added.is_empty());
+        assert!(delta.removed.is_empty());
+    }
+
+    #[test]
+    fn test_render_delta_added_and_removed() {
+        let renderer = SvgRenderer::new(800.0, 600.0);
+        let mut graph = VowlGraph::new();
+        graph.add_node(NodeBuilder::new("new").build()).unwrap();
+
+        let mut previous = HashMap::new();
+        previous.insert("gone".to_string(), (1.0, 1.0));
+
+        let delta = renderer.render_delta(&graph, &previous);
+        assert!(delta.moved.is_empty());
+        assert_eq!(delta.added, vec!["new".to_string()]);
+        assert_eq!(delta.removed, vec!["gone".to_string()]);
+    }
 }
@@ -0,0 +1,158 @@
+//! VOWL node colors and sizes, and edge strokes
+
+use crate::graph::{EdgeType, Node, NodeType};
+use std::collections::HashMap;
+
+/// Colors and sizes used to draw nodes
+///
+/// Defaults follow the VOWL 2 specification. A node's own `visual.color`
+/// always takes precedence over the theme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VowlTheme {
+    /// Fill of OWL classes
+    pub class_color: String,
+
+    /// Fill of classes from other ontologies
+    pub external_color: String,
+
+    /// Fill of datatypes
+    pub datatype_color: String,
+
+    /// Fill of `owl:Thing` and `owl:Nothing`
+    pub thing_color: String,
+
+    /// Fill of deprecated classes
+    pub deprecated_color: String,
+
+    /// Radius of a class without individuals
+    pub base_radius: f64,
+
+    /// Upper bound for the radius of large classes
+    pub max_radius: f64,
+}
+
+impl Default for VowlTheme {
+    fn default() -> Self {
+        Self {
+            class_color: "#AACCFF".to_string(),
+            external_color: "#3366CC".to_string(),
+            datatype_color: "#FFCC33".to_string(),
+            thing_color: "#FFFFFF".to_string(),
+            deprecated_color: "#CCCCCC".to_string(),
+            base_radius: 20.0,
+            max_radius: 50.0,
+        }
+    }
+}
+
+impl VowlTheme {
+    /// Get the fill color of a node
+    pub fn node_color<'a>(&'a self, node: &'a Node) -> &'a str {
+        if let Some(color) = node.visual.color.as_deref() {
+            return color;
+        }
+
+        match &node.node_type {
+            NodeType::Datatype => &self.datatype_color,
+            NodeType::Special(name) if name == "Thing" || name == "Nothing" => &self.thing_color,
+            _ if node.semantic.deprecated => &self.deprecated_color,
+            _ if node.semantic.external => &self.external_color,
+            _ => &self.class_color,
+        }
+    }
+
+    /// Get the radius of a node circle
+    ///
+    /// Grows logarithmically with the individual count, so a class with 1000
+    /// individuals is not drawn 1000 times larger, plus a linear term for
+    /// `visual.weight` (e.g. normalized centrality). Capped at `max_radius`.
+    pub fn node_radius(&self, node: &Node) -> f64 {
+        let individuals = node.semantic.individuals.unwrap_or(0) as f64;
+        let scale = 1.0 + 0.25 * (1.0 + individuals).log2() + node.visual.weight.max(0.0);
+
+        (self.base_radius * scale).min(self.max_radius)
+    }
+}
+
+/// Stroke of an edge line
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeStyle {
+    /// Stroke color, also used for the arrowhead
+    pub color: String,
+
+    /// Stroke width
+    pub width: f64,
+
+    /// SVG `stroke-dasharray`, e.g. `"4,2"`; `None` draws a solid line
+    pub dash: Option<String>,
+}
+
+impl Default for EdgeStyle {
+    fn default() -> Self {
+        Self {
+            color: "#999".to_string(),
+            width: 1.5,
+            dash: None,
+        }
+    }
+}
+
+impl EdgeStyle {
+    /// Default stroke with the given dash pattern
+    pub fn dashed(dash: &str) -> Self {
+        Self {
+            dash: Some(dash.to_string()),
+            ..Self::default()
+        }
+    }
+
+    /// Built-in styles per edge type
+    ///
+    /// Annotations carry no logical meaning and are drawn with a short dash,
+    /// equivalences with a long one. Other types use [`EdgeStyle::default`].
+    pub fn defaults() -> HashMap<EdgeType, EdgeStyle> {
+        HashMap::from([
+            (EdgeType::Annotation, Self::dashed("2,3")),
+            (EdgeType::SameAs, Self::dashed("8,4")),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::node::NodeBuilder;
+
+    #[test]
+    fn test_node_colors() {
+        let theme = VowlTheme::default();
+
+        let class = NodeBuilder::new("a").build();
+        let external = NodeBuilder::new("b").external(true).build();
+        let thing = NodeBuilder::new("c")
+            .node_type(NodeType::Special("Thing".to_string()))
+            .build();
+        let deprecated = NodeBuilder::new("e").external(true).deprecated(true).build();
+        let mut colored = NodeBuilder::new("d").build();
+        colored.visual.color = Some("#123456".to_string());
+
+        assert_eq!(theme.node_color(&class), "#AACCFF");
+        assert_eq!(theme.node_color(&external), "#3366CC");
+        assert_eq!(theme.node_color(&thing), "#FFFFFF");
+        assert_eq!(theme.node_color(&deprecated), "#CCCCCC");
+        assert_eq!(theme.node_color(&colored), "#123456");
+    }
+
+    #[test]
+    fn test_node_radius_grows_with_individuals() {
+        let theme = VowlTheme::default();
+        let mut node = NodeBuilder::new("a").build();
+        assert_eq!(theme.node_radius(&node), 20.0);
+
+        node.semantic.individuals = Some(15);
+        assert_eq!(theme.node_radius(&node), 40.0);
+
+        node.semantic.individuals = Some(1_000_000);
+        assert_eq!(theme.node_radius(&node), 50.0);
+    }
+}
@@ -0,0 +1,97 @@
+//! Mapping from graph coordinates to a padded output area
+
+use crate::graph::VowlGraph;
+
+/// Output area a graph is fitted into
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// Width in output units
+    pub width: f64,
+
+    /// Height in output units
+    pub height: f64,
+
+    /// Margin kept free on every side
+    pub padding: f64,
+}
+
+/// Uniform scale and offset fitting a graph into a [`Viewport`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewTransform {
+    origin: (f64, f64),
+    scale: f64,
+    offset: (f64, f64),
+}
+
+impl Viewport {
+    /// Create a viewport
+    pub fn new(width: f64, height: f64, padding: f64) -> Self {
+        Self {
+            width,
+            height,
+            padding,
+        }
+    }
+
+    /// Compute the transform fitting the graph's bounding box into this viewport
+    ///
+    /// An empty graph maps everything to the viewport center.
+    pub fn fit(&self, graph: &VowlGraph) -> ViewTransform {
+        match graph.bounding_box() {
+            Some((min_x, min_y, _, _)) => ViewTransform {
+                origin: (min_x, min_y),
+                scale: graph.fit_scale(self.width, self.height, self.padding),
+                offset: (self.padding, self.padding),
+            },
+            None => ViewTransform {
+                origin: (0.0, 0.0),
+                scale: 0.0,
+                offset: (self.width / 2.0, self.height / 2.0),
+            },
+        }
+    }
+}
+
+impl ViewTransform {
+    /// Map a point from graph to viewport coordinates
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            (x - self.origin.0) * self.scale + self.offset.0,
+            (y - self.origin.1) * self.scale + self.offset.1,
+        )
+    }
+
+    /// Scale factor from graph to viewport units
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::node::NodeBuilder;
+
+    #[test]
+    fn test_fit_maps_bounding_box_into_padding() {
+        let mut graph = VowlGraph::new();
+        graph
+            .add_node(NodeBuilder::new("a").position(-50.0, 0.0).build())
+            .unwrap();
+        graph
+            .add_node(NodeBuilder::new("b").position(50.0, 100.0).build())
+            .unwrap();
+
+        let transform = Viewport::new(220.0, 220.0, 10.0).fit(&graph);
+
+        assert_eq!(transform.scale(), 2.0);
+        assert_eq!(transform.apply(-50.0, 0.0), (10.0, 10.0));
+        assert_eq!(transform.apply(50.0, 100.0), (210.0, 210.0));
+    }
+
+    #[test]
+    fn test_fit_empty_graph_centers() {
+        let transform = Viewport::new(200.0, 100.0, 10.0).fit(&VowlGraph::new());
+        assert_eq!(transform.apply(123.0, -4.0), (100.0, 50.0));
+    }
+}
@@ -1,7 +1,7 @@
 //! Integration tests for WebVOWL WASM
 
 use webvowl_wasm::{
-    graph::{builder::GraphBuilder, VowlGraph},
+    graph::builder::GraphBuilder,
     layout::{simulation::ForceSimulation, LayoutAlgorithm},
     ontology::{parser::StandardParser, OntologyParser},
 };
@@ -27,8 +27,26 @@ export interface NodeData {
     x: number;
     /** Y coordinate */
     y: number;
-    /** Node type (Class, Datatype, etc.) */
-    node_type: string;
+    /** Node type */
+    node_type: "class" | "datatype" | "thing" | "special";
+    /** Name of a special node (e.g. "Nothing"), present only for "special" */
+    special_name?: string;
+    /** Whether the node is hidden inside a collapsed subtree */
+    hidden: boolean;
+    /** Opacity from 0 to 1, below 1 when dimmed by setHighlight */
+    opacity: number;
+}
+
+/**
+ * Single node as returned by getNode
+ */
+export interface NodeDetails extends NodeData {
+    /** IRI of the class */
+    iri: string;
+    /** Whether the class is external to the ontology */
+    external: boolean;
+    /** Number of individuals, if known */
+    individuals?: number;
 }
 
 /**
@@ -43,8 +61,24 @@ export interface EdgeData {
     source: string;
     /** Target node ID */
     target: string;
-    /** Edge type (ObjectProperty, DatatypeProperty, etc.) */
-    edge_type: string;
+    /** Edge type */
+    edge_type:
+        | "objectProperty"
+        | "datatypeProperty"
+        | "subClass"
+        | "annotation"
+        | "sameAs"
+        | "special";
+    /** Name of a special edge (e.g. "disjoint"), present only for "special" */
+    special_name?: string;
+    /** IDs of the properties in this property's chain (empty if none) */
+    chain: string[];
+    /** ID of the inverse property (owl:inverseOf), if declared */
+    inverse_of?: string;
+    /** True if this edge stands for a merged inverse pair */
+    bidirectional: boolean;
+    /** Opacity from 0 to 1, below 1 when dimmed by setHighlight */
+    opacity: number;
 }
 
 /**
@@ -73,6 +107,125 @@ export interface Statistics {
     max_degree: number;
     /** Graph density (0-1) */
     density: number;
+    /** Number of weakly connected components */
+    component_count: number;
+    /** Longest shortest path in the largest component, ignoring direction */
+    diameter?: number;
+}
+
+/**
+ * Position change of a single node
+ */
+export interface NodeDelta {
+    /** Node identifier */
+    id: string;
+    /** New X coordinate */
+    x: number;
+    /** New Y coordinate */
+    y: number;
+}
+
+/**
+ * Changes since the previous render
+ */
+export interface DeltaUpdate {
+    /** Nodes that moved further than the threshold */
+    moved: NodeDelta[];
+    /** IDs of newly added nodes */
+    added: string[];
+    /** IDs of removed nodes */
+    removed: string[];
+}
+
+/**
+ * Namespace prefixes used by the ontology compared with those declared
+ */
+export interface NamespaceUsage {
+    /** Prefixes referenced by at least one class or property IRI */
+    used: string[];
+    /** Declared prefixes no IRI refers to */
+    declared_unused: string[];
+    /** Prefixes used in CURIEs without a declaration */
+    used_undeclared: string[];
+}
+
+/**
+ * A 2D drawing operation to replay onto a canvas context
+ */
+export type DrawCommand =
+    | { op: "circle"; x: number; y: number; radius: number; fill: string; stroke: string }
+    | { op: "line"; x1: number; y1: number; x2: number; y2: number; stroke: string; width: number }
+    | {
+          op: "bezier";
+          x1: number;
+          y1: number;
+          cx1: number;
+          cy1: number;
+          cx2: number;
+          cy2: number;
+          x2: number;
+          y2: number;
+          stroke: string;
+          width: number;
+      }
+    | { op: "text"; x: number; y: number; text: string; color: string; size: number };
+
+/**
+ * Neighbor node paired with the connecting edge
+ */
+export interface NeighborData {
+    /** Neighbor node ID */
+    node_id: string;
+    /** Neighbor node label */
+    node_label: string;
+    /** Connecting edge ID */
+    edge_id: string;
+    /** Connecting edge label */
+    edge_label: string;
+}
+
+/**
+ * Attraction exerted by one linked neighbor
+ */
+export interface NeighborForce {
+    /** Neighbor node ID */
+    neighbor: string;
+    /** Force vector [x, y] */
+    force: [number, number];
+}
+
+/**
+ * Breakdown of the forces acting on a node
+ */
+export interface NodeExplanation {
+    /** Node ID */
+    id: string;
+    /** Total repulsion [x, y] */
+    repulsion: [number, number];
+    /** Attraction per linked neighbor */
+    attraction: NeighborForce[];
+    /** Centering force [x, y] */
+    centering: [number, number];
+    /** Sum of custom forces [x, y] */
+    custom: [number, number];
+    /** Net force [x, y] */
+    total: [number, number];
+    /** Current velocity [x, y] */
+    velocity: [number, number];
+}
+
+/**
+ * Error thrown by every failing `WebVowl` method, including calls made
+ * before a graph is loaded
+ */
+export interface WebVowlError {
+    /**
+     * Stable error kind: "parse_error", "invalid_data", "graph_error",
+     * "layout_error", "render_error", "binding_error" or "limit_exceeded"
+     */
+    code: string;
+    /** Human-readable description */
+    message: string;
 }
 
 /**
@@ -98,6 +251,47 @@ export class WebVowl {
      */
     loadOntology(json: string): void;
 
+    /**
+     * Add a single class to the loaded graph without reloading
+     * New nodes start near the simulation center; existing positions are kept
+     *
+     * @param json - One class object, in the format of the ontology's class array
+     * @throws Error if no graph is loaded, the JSON is invalid or the ID already exists
+     */
+    addClass(json: string): void;
+
+    /**
+     * Add a single property to the loaded graph without reloading
+     *
+     * @param json - One property object, in the format of the ontology's property array
+     * @throws Error if no graph is loaded, the JSON is invalid or its domain/range is unknown
+     */
+    addProperty(json: string): void;
+
+    /**
+     * Get non-fatal warnings from validating the last loaded ontology
+     * (e.g. properties whose range is not a known class, or subclass cycles)
+     *
+     * @returns Warning messages, empty if none
+     */
+    getValidationWarnings(): string[];
+
+    /**
+     * Get the header of the loaded ontology, e.g. for "Ontology: Pizza v2.0"
+     *
+     * @returns IRI, title, version and description, or null if the graph has none
+     * @throws Error if no graph is loaded
+     */
+    getOntologyMetadata(): OntologyMetadata | null;
+
+    /**
+     * Compare namespace prefixes used by class/property IRIs with those declared
+     *
+     * @returns Used, unused and undeclared prefixes
+     * @throws Error if no ontology is loaded
+     */
+    getNamespaceUsage(): NamespaceUsage;
+
     /**
      * Initialize the force-directed layout simulation
      * Must be called after loading ontology and before running simulation
@@ -120,6 +314,40 @@ export class WebVowl {
      */
     runSimulation(iterations: number): void;
 
+    /**
+     * Continue the simulation for N iterations and return the final positions
+     * Saves a separate `getGraphData` call for the "advance and read" pattern.
+     * Unlike `runSimulation`, positions are not re-initialized.
+     *
+     * @param iterations - Maximum number of simulation steps to run
+     * @returns Flat `[x0, y0, x1, y1, ...]` array in `getGraphData` node order
+     * @throws Error if no graph is loaded
+     */
+    advance(iterations: number): Float64Array;
+
+    /**
+     * Run one animation frame and pass the positions to a callback
+     * Scheduling the next frame is left to the caller
+     *
+     * @param onFrame - Receives the flat `[x0, y0, x1, y1, ...]` position array
+     * @param options - Ticks per frame and stop threshold
+     * @returns true while the layout is still moving
+     * @throws Error if no graph is loaded
+     *
+     * @example
+     * ```typescript
+     * function frame() {
+     *   if (webvowl.animateLayout(draw, { ticksPerFrame: 5 })) {
+     *     requestAnimationFrame(frame);
+     *   }
+     * }
+     * ```
+     */
+    animateLayout(
+        onFrame: (positions: Float64Array) => void,
+        options?: AnimationOptions
+    ): boolean;
+
     /**
      * Perform one simulation tick
      * Use this for animated layouts
@@ -138,6 +366,28 @@ export class WebVowl {
      */
     tick(): void;
 
+    /**
+     * Set a callback invoked after every simulation tick, including ticks run
+     * by `runSimulation`, `advance` and `animateLayout`
+     *
+     * Only moved nodes are reported, which avoids serializing the whole graph
+     * on every frame.
+     *
+     * @param callback - Receives the alpha after the tick and a flat
+     *   `[index, x, y, ...]` array of moved nodes in `getGraphData` node order;
+     *   pass `undefined` to remove the callback
+     */
+    onTick(callback?: (alpha: number, moved: Float64Array) => void): void;
+
+    /**
+     * Explain the forces currently acting on a node
+     *
+     * @param id - Node ID
+     * @returns Per-component force breakdown
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    explainNode(id: string): NodeExplanation;
+
     /**
      * Check if the simulation has converged
      *
@@ -153,6 +403,52 @@ export class WebVowl {
      */
     getAlpha(): number;
 
+    /**
+     * Restart a finished simulation, e.g. after dragging a node or adding data
+     * Positions are kept; subsequent ticks move nodes again
+     *
+     * @param alpha - New alpha, clamped to [alphaMin, 1]
+     */
+    reheat(alpha: number): void;
+
+    /**
+     * Keep nodes in place during layout, e.g. the area the user is reading
+     * Unlike setNodeFixed this is transient and does not change the nodes
+     *
+     * @param ids - IDs of the nodes to freeze, added to those already frozen
+     */
+    freezeNodes(ids: string[]): void;
+
+    /**
+     * Let all nodes frozen with freezeNodes move again
+     */
+    unfreezeAll(): void;
+
+    /**
+     * Save node positions, velocities and the simulation alpha
+     *
+     * @returns Snapshot as JSON, for `restoreLayout`
+     * @throws Error if no graph is loaded
+     */
+    snapshotLayout(): string;
+
+    /**
+     * Restore a layout saved with `snapshotLayout`, e.g. to undo a drag
+     *
+     * @param json - Snapshot from `snapshotLayout`
+     * @throws Error if no graph is loaded or the snapshot is malformed
+     */
+    restoreLayout(json: string): void;
+
+    /**
+     * Set the alpha the simulation decays toward, as D3's alphaTarget
+     * Keep it at e.g. 0.3 while dragging so the layout stays live, and reset
+     * it to 0 on release. A finished simulation needs `reheat` first.
+     *
+     * @param target - Target alpha, clamped to [0, 1]
+     */
+    setAlphaTarget(target: number): void;
+
     /**
      * Set the center position for the centering force
      *
@@ -176,6 +472,97 @@ export class WebVowl {
      */
     setChargeStrength(strength: number): void;
 
+    /**
+     * Translate the layout so a node sits at the simulation center
+     * Relative positions are preserved
+     *
+     * @param id - Node ID to focus
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    centerOnNode(id: string): void;
+
+    /**
+     * Pin or release a node
+     * Pinned nodes keep their exact position while the simulation runs
+     *
+     * @param id - Node ID
+     * @param fixed - True to pin, false to release
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    setNodeFixed(id: string, fixed: boolean): void;
+
+    /**
+     * Get the node drawn at a point, e.g. for click handling
+     * Overlapping nodes resolve to the one with the closest center
+     *
+     * @param x - X coordinate in graph space
+     * @param y - Y coordinate in graph space
+     * @returns Node ID, or undefined if no node is hit
+     * @throws Error if no graph is loaded
+     */
+    nodeAtPoint(x: number, y: number): string | undefined;
+
+    /**
+     * Move a node to a position and clear its velocity
+     * Combine with setNodeFixed for drag-to-pin interactions
+     *
+     * @param id - Node ID
+     * @param x - X coordinate
+     * @param y - Y coordinate
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    setNodePosition(id: string, x: number, y: number): void;
+
+    /**
+     * Hide all direct and indirect subclasses of a node
+     * Hidden nodes are skipped by rendering and the simulation
+     *
+     * @param id - Node ID
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    collapseNode(id: string): void;
+
+    /**
+     * Show the subclasses hidden by `collapseNode` again
+     *
+     * @param id - Node ID
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    expandNode(id: string): void;
+
+    /**
+     * Remove nodes that have no edges, e.g. after filtering
+     * With a filter active only the filtered view is pruned
+     *
+     * @returns Number of removed nodes
+     * @throws Error if no graph is loaded
+     */
+    pruneIsolated(): number;
+
+    /**
+     * Dim everything except a node, its neighbors and its edges
+     *
+     * @param nodeId - Node to highlight
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    setHighlight(nodeId: string): void;
+
+    /**
+     * Restore full opacity after setHighlight
+     *
+     * @throws Error if no graph is loaded
+     */
+    clearHighlight(): void;
+
+    /**
+     * Push apart nodes closer than the given distance
+     * Cheap cleanup pass to call after layout
+     *
+     * @param distance - Minimum distance between node centers
+     * @throws Error if no graph is loaded
+     */
+    enforceMinSeparation(distance: number): void;
+
     /**
      * Get the current graph data with node positions
      *
@@ -184,6 +571,225 @@ export class WebVowl {
      */
     getGraphData(): GraphData;
 
+    /**
+     * Get node positions without serializing the full graph data
+     * Cheap enough to call every animation frame.
+     *
+     * @returns Flat `[x0, y0, x1, y1, ...]` array in `getNodeOrder` order
+     * @throws Error if no graph is loaded
+     */
+    getPositions(): Float64Array;
+
+    /**
+     * Get the node IDs matching the `getPositions` layout
+     * The order only changes when nodes are added or removed.
+     *
+     * @returns Node IDs
+     * @throws Error if no graph is loaded
+     */
+    getNodeOrder(): string[];
+
+    /**
+     * Get the k-hop neighborhood of a node for focus-and-context views
+     * Edges are followed in either direction.
+     *
+     * @param id - Node ID at the center
+     * @param depth - Maximum number of hops (0 returns just the node)
+     * @returns Graph data with the reached nodes and the edges among them
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    extractNeighborhood(id: string, depth: number): GraphData;
+
+    /**
+     * Export graph structure and positions as JSON, e.g. for localStorage
+     *
+     * @returns JSON string
+     * @throws Error if no graph is loaded
+     */
+    exportGraph(): string;
+
+    /**
+     * Replace the current graph with one produced by `exportGraph`
+     *
+     * @param json - Exported graph
+     * @throws Error if the JSON is invalid or references unknown nodes
+     */
+    importGraph(json: string): void;
+
+    /**
+     * Serialize graph structure and positions to a compact binary form
+     *
+     * @returns Encoded graph
+     * @throws Error if no graph is loaded
+     */
+    serializeBinary(): Uint8Array;
+
+    /**
+     * Replace the current graph with one produced by `serializeBinary`
+     *
+     * @param bytes - Encoded graph
+     * @throws Error if the bytes cannot be decoded
+     */
+    restoreBinary(bytes: Uint8Array): void;
+
+    /**
+     * Get the uniform scale fitting the graph into a viewport
+     *
+     * @param width - Viewport width in pixels
+     * @param height - Viewport height in pixels
+     * @returns Scale factor from graph to viewport coordinates
+     * @throws Error if no graph is loaded
+     */
+    getFitScale(width: number, height: number): number;
+
+    /**
+     * Get draw commands for rendering the graph onto a 2D canvas
+     * Edges come before nodes; coordinates are fitted into the canvas
+     *
+     * @param width - Canvas width in pixels
+     * @param height - Canvas height in pixels
+     * @returns Commands in drawing order
+     * @throws Error if no graph is loaded
+     */
+    renderCanvasCommands(width: number, height: number): DrawCommand[];
+
+    /**
+     * Render the graph as an SVG document
+     *
+     * @param width - SVG width in pixels
+     * @param height - SVG height in pixels
+     * @returns SVG markup
+     * @throws Error if no graph is loaded
+     */
+    renderSvg(width: number, height: number): string;
+
+    /**
+     * Render a minimap SVG of the whole graph with the detail viewport outlined
+     *
+     * @param width - Overview width in pixels
+     * @param height - Overview height in pixels
+     * @param viewportX - Detail viewport X in graph coordinates
+     * @param viewportY - Detail viewport Y in graph coordinates
+     * @param viewportWidth - Detail viewport width in graph coordinates
+     * @param viewportHeight - Detail viewport height in graph coordinates
+     * @returns SVG markup
+     * @throws Error if no graph is loaded
+     */
+    renderOverview(
+        width: number,
+        height: number,
+        viewportX: number,
+        viewportY: number,
+        viewportWidth: number,
+        viewportHeight: number
+    ): string;
+
+    /**
+     * Get nodes changed since the previous call
+     *
+     * @param threshold - Minimum distance a node must move to be reported
+     * @returns Moved, added and removed nodes
+     * @throws Error if no graph is loaded
+     */
+    getRenderDelta(threshold: number): DeltaUpdate;
+
+    /**
+     * Export node positions as CSV with `id,label,x,y,type,degree` columns
+     * The type is the same as `NodeData.node_type`.
+     *
+     * @returns CSV text including a header row
+     * @throws Error if no graph is loaded
+     */
+    exportPositionsCsv(): string;
+
+    /**
+     * List classes lacking a label in the given language
+     *
+     * @param lang - Language tag, e.g. "fr"
+     * @returns IDs of untranslated classes
+     * @throws Error if no graph is loaded
+     */
+    findUntranslated(lang: string): string[];
+
+    /**
+     * Find the shortest hop path between two nodes
+     * Edges are followed in either direction
+     *
+     * @param from - Start node ID
+     * @param to - End node ID
+     * @returns Node IDs along the path including both ends, or undefined if unreachable
+     * @throws Error if no graph is loaded or either node does not exist
+     */
+    findPath(from: string, to: string): string[] | undefined;
+
+    /**
+     * Find the cheapest path between two nodes
+     * Subclass edges are cheap and annotation edges expensive; edges are
+     * followed in either direction
+     *
+     * @param from - Start node ID
+     * @param to - End node ID
+     * @returns Node IDs along the path including both ends, or undefined if unreachable
+     * @throws Error if no graph is loaded or either node does not exist
+     */
+    findWeightedPath(from: string, to: string): string[] | undefined;
+
+    /**
+     * Hide kinds of nodes and edges from rendering and simulation
+     * The filter also applies to graphs loaded later; hidden nodes keep their
+     * last positions and reappear there once the filter is removed.
+     *
+     * @param options - Flags to enable, or undefined to show everything
+     * @throws Error if the options object is malformed
+     */
+    setFilter(options?: FilterOptions): void;
+
+    /**
+     * Compute node centrality and store it as each node's weight
+     *
+     * @param kind - "degree" (normalized degree) or "betweenness" (Brandes, undirected)
+     * @returns Centrality in [0, 1] keyed by node ID
+     * @throws Error if no graph is loaded or the kind is unknown
+     */
+    computeCentrality(kind: "degree" | "betweenness"): Record<string, number>;
+
+    /**
+     * Find nodes by label, case-insensitively and by substring
+     *
+     * @param query - Text to look for
+     * @param options - Also match IRIs, or cap the number of results
+     * @returns Matching node IDs
+     * @throws Error if no graph is loaded or the options object is malformed
+     */
+    search(query: string, options?: SearchOptions): string[];
+
+    /**
+     * Get the IDs of a node's neighbors along outgoing edges
+     *
+     * @param id - Node ID
+     * @returns Neighbor node IDs
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    getNeighbors(id: string): string[];
+
+    /**
+     * Get a single node's details
+     *
+     * @param id - Node ID
+     * @returns The node with its IRI and individual count
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    getNode(id: string): NodeDetails;
+
+    /**
+     * Get a node's neighbors together with the connecting edges
+     *
+     * @param id - Node ID
+     * @returns Neighbor/edge pairs following outgoing edges
+     * @throws Error if no graph is loaded or the node does not exist
+     */
+    getNeighborsWithEdges(id: string): NeighborData[];
+
     /**
      * Get the number of nodes in the graph
      *
@@ -212,6 +818,56 @@ export class WebVowl {
     free(): void;
 }
 
+/**
+ * Options for `WebVowl.setFilter`; omitted flags are off
+ */
+export interface FilterOptions {
+    /** Hide datatype nodes and datatype properties */
+    hideDatatypes?: boolean;
+    /** Hide subclass edges */
+    hideSubclass?: boolean;
+    /** Hide external classes */
+    hideExternal?: boolean;
+    /** Hide annotation property edges */
+    hideAnnotations?: boolean;
+    /** Hide sameAs equivalence edges */
+    hideSameAs?: boolean;
+}
+
+/**
+ * Ontology header as returned by getOntologyMetadata
+ */
+export interface OntologyMetadata {
+    /** Ontology IRI */
+    iri: string;
+    /** Version information */
+    version?: string;
+    /** Title */
+    title?: string;
+    /** Description */
+    description?: string;
+}
+
+/**
+ * Options for `WebVowl.search`
+ */
+export interface SearchOptions {
+    /** Also match against node IRIs (default false) */
+    matchIri?: boolean;
+    /** Maximum number of results (default unlimited) */
+    limit?: number;
+}
+
+/**
+ * Options for `WebVowl.animateLayout`
+ */
+export interface AnimationOptions {
+    /** Simulation ticks per frame (default: 1) */
+    ticksPerFrame?: number;
+    /** Stop once alpha drops below this value (default: 0, so only when the simulation finishes) */
+    stopAlpha?: number;
+}
+
 /**
  * Configuration options for WebVOWL
  */
@@ -255,10 +911,42 @@ export interface OntologyData {
     class?: ClassDefinition[];
     /** Array of property definitions */
     property?: PropertyDefinition[];
+    /** rdfs:subClassOf relations, from the subclass to its superclass */
+    subClassOf?: ClassRelation[];
+    /** owl:disjointWith relations */
+    disjoint?: ClassRelation[];
+    /** owl:sameAs or skos:exactMatch equivalences, drawn as sameAs edges */
+    sameAs?: ClassRelation[];
+    /** Union, intersection and complement nodes */
+    setOperators?: SetOperatorDefinition[];
     /** Namespace definitions */
     namespace?: Record<string, string>;
 }
 
+/**
+ * Structural relation between two classes
+ */
+export interface ClassRelation {
+    /** Source class ID */
+    from: string;
+    /** Target class ID */
+    to: string;
+}
+
+/**
+ * Set operator node connected to its member classes
+ */
+export interface SetOperatorDefinition {
+    /** Unique identifier of the operator node */
+    id: string;
+    /** IRI (defaults to the ID) */
+    iri?: string;
+    /** Operator kind; the OWL names (e.g. "owl:unionOf") are accepted too */
+    operator: "union" | "intersection" | "complement" | "disjointUnion";
+    /** Member class IDs */
+    members: string[];
+}
+
 /**
  * OWL class definition
  */
@@ -267,8 +955,8 @@ export interface ClassDefinition {
     id: string;
     /** IRI */
     iri?: string;
-    /** Display label */
-    label?: string;
+    /** Display label, or labels keyed by language tag */
+    label?: string | Record<string, string>;
     /** Class type (e.g., "owl:Class") */
     type?: string;
     /** Equivalent classes */
@@ -289,14 +977,14 @@ export interface PropertyDefinition {
     id: string;
     /** IRI */
     iri?: string;
-    /** Display label */
-    label?: string;
+    /** Display label, or labels keyed by language tag */
+    label?: string | Record<string, string>;
     /** Property type (e.g., "owl:ObjectProperty") */
     type?: string;
-    /** Domain class ID */
-    domain: string;
-    /** Range class/datatype ID */
-    range: string;
+    /** Domain class ID (defaults to owl:Thing) */
+    domain?: string;
+    /** Range class/datatype ID (defaults to owl:Thing) */
+    range?: string;
     /** Is functional? */
     functional?: boolean;
     /** Is inverse functional? */
@@ -311,4 +999,10 @@ export interface PropertyDefinition {
     maxCardinality?: number;
     /** Exact cardinality */
     cardinality?: number;
+    /** Property chain (owl:propertyChainAxiom) as property IDs */
+    propertyChain?: string[];
+    /** ID of the inverse property (owl:inverseOf) */
+    inverseOf?: string;
+    /** Super-property ID(s) (rdfs:subPropertyOf) */
+    subPropertyOf?: string | string[];
 }